@@ -0,0 +1,156 @@
+//! Ages and anniversaries, counted in whole years, months, and days.
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Year};
+use cal::period::Period;
+
+
+/// When the anniversary of a date on the 29th of February falls in years
+/// that aren’t leap years.
+///
+/// Which one is correct depends on the law that applies: in the United
+/// Kingdom, for example, someone born on the 29th of February comes of age
+/// on the 1st of March.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum LeapDayPolicy {
+
+    /// The anniversary falls on the 28th of February. This is the default.
+    February28,
+
+    /// The anniversary falls on the 1st of March.
+    March1,
+}
+
+impl Default for LeapDayPolicy {
+    fn default() -> Self {
+        LeapDayPolicy::February28
+    }
+}
+
+
+impl LocalDate {
+
+    /// Returns the date of the anniversary of this date the given number
+    /// of years later, or earlier if the number is negative, using the
+    /// given policy for the 29th of February.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, LeapDayPolicy};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::February, 29).unwrap();
+    /// assert_eq!(date.anniversary(1, LeapDayPolicy::February28), LocalDate::ymd(2017, Month::February, 28).unwrap());
+    /// assert_eq!(date.anniversary(1, LeapDayPolicy::March1), LocalDate::ymd(2017, Month::March, 1).unwrap());
+    /// assert_eq!(date.anniversary(4, LeapDayPolicy::March1), LocalDate::ymd(2020, Month::February, 29).unwrap());
+    /// ```
+    pub fn anniversary(&self, years: i64, policy: LeapDayPolicy) -> Self {
+        let year = self.year() + years;
+
+        // The only date that doesn’t exist in every year is the 29th of
+        // February.
+        LocalDate::ymd(year, self.month(), self.day()).unwrap_or_else(|_| {
+            match policy {
+                LeapDayPolicy::February28  => LocalDate::ymd(year, Month::February, 28).unwrap(),
+                LeapDayPolicy::March1      => LocalDate::ymd(year, Month::March, 1).unwrap(),
+            }
+        })
+    }
+
+    /// Returns the age of something that started on this date, such as a
+    /// person born on it, on the given date, as a period of whole years,
+    /// months, and days. Anniversaries of the 29th of February fall on
+    /// the 28th in years that aren’t leap years.
+    ///
+    /// This returns `None` if the given date is before this one.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Period};
+    ///
+    /// let birth = LocalDate::ymd(1990, Month::August, 31).unwrap();
+    /// let today = LocalDate::ymd(2017, Month::August, 30).unwrap();
+    /// assert_eq!(birth.age_on(today), Some(Period { years: 26, months: 11, days: 30, ..Period::default() }));
+    /// ```
+    pub fn age_on(&self, other: Self) -> Option<Period> {
+        self.age_on_with(other, LeapDayPolicy::default())
+    }
+
+    /// Returns the age of something that started on this date on the given
+    /// date, as with `age_on`, using the given policy for anniversaries of
+    /// the 29th of February.
+    ///
+    /// Months are counted from the day of the month of this date, using the
+    /// last day of any month that’s too short.
+    pub fn age_on_with(&self, other: Self, policy: LeapDayPolicy) -> Option<Period> {
+        if other < *self {
+            return None;
+        }
+
+        let mut years = other.year() - self.year();
+        if self.anniversary(years, policy) > other {
+            years -= 1;
+        }
+
+        // Step forward from the last anniversary one month at a time. There
+        // can be at most eleven months before the next anniversary.
+        let anniversary = self.anniversary(years, policy);
+        let ym = Year(self.year() + years).month(self.month());
+        let month_date = |months| {
+            let ym = ym + months;
+            ym.day(self.day().min(ym.day_count())).unwrap()
+        };
+
+        let months = (1 ..= 11).take_while(|&m| month_date(m) <= other).last().unwrap_or(0);
+        let start = if months == 0 { anniversary } else { month_date(months) };
+
+        Some(Period { years, months, days: start.days_until(other), ..Period::default() })
+    }
+
+    /// Returns an iterator over the anniversaries of this date that fall
+    /// after the given date, using the given policy for the 29th of
+    /// February.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, LeapDayPolicy};
+    ///
+    /// let date = LocalDate::ymd(2000, Month::February, 29).unwrap();
+    /// let after = LocalDate::ymd(2003, Month::January, 1).unwrap();
+    ///
+    /// let dates: Vec<_> = date.anniversaries_after(after, LeapDayPolicy::March1).take(2).collect();
+    /// assert_eq!(dates, vec![ LocalDate::ymd(2003, Month::March, 1).unwrap(), LocalDate::ymd(2004, Month::February, 29).unwrap() ]);
+    /// ```
+    pub fn anniversaries_after(&self, after: Self, policy: LeapDayPolicy) -> Anniversaries {
+        let mut years = (after.year() - self.year()).max(1);
+        if self.anniversary(years, policy) <= after {
+            years += 1;
+        }
+
+        Anniversaries { date: *self, policy, years }
+    }
+}
+
+
+/// An iterator over the anniversaries of a date, which never ends.
+///
+/// Use the `anniversaries_after` method on `LocalDate` to create instances
+/// of this iterator.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Anniversaries {
+    date: LocalDate,
+    policy: LeapDayPolicy,
+    years: i64,
+}
+
+impl Iterator for Anniversaries {
+    type Item = LocalDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let anniversary = self.date.anniversary(self.years, self.policy);
+        self.years += 1;
+        Some(anniversary)
+    }
+}
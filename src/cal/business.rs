@@ -0,0 +1,415 @@
+//! Business days, which skip over weekends and public holidays.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Weekday, Year, Error};
+use cal::easter::{Computus, MovableFeast};
+
+
+/// A **holiday** is a rule for finding a day in each year that isn’t a
+/// business day, along with the rule for when it’s observed if it falls on
+/// a weekend.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{Holiday, Observance, LocalDate, Month, Weekday};
+///
+/// let independence_day = Holiday::fixed(Month::July, 4).unwrap().observed(Observance::NearestWeekday);
+/// assert_eq!(independence_day.observed_in(2020), LocalDate::ymd(2020, Month::July, 3).ok());
+///
+/// let thanksgiving = Holiday::nth_weekday(Month::November, Weekday::Thursday, 4).unwrap();
+/// assert_eq!(thanksgiving.observed_in(2017), LocalDate::ymd(2017, Month::November, 23).ok());
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Holiday {
+    date: HolidayDate,
+    observance: Observance,
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum HolidayDate {
+    Fixed(Month, i8),
+    NthWeekday(Month, Weekday, i8),
+    Feast(MovableFeast, Computus),
+    Once(LocalDate),
+}
+
+/// When a holiday that falls on a weekend is observed instead.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Observance {
+
+    /// The holiday is only observed on the day it falls on.
+    Actual,
+
+    /// A holiday on a weekend day is observed on the nearest day that
+    /// isn’t, or the day after if there’s one as near on either side. With
+    /// a Saturday and Sunday weekend, a Saturday holiday is observed on the
+    /// Friday before, and a Sunday holiday on the Monday after, as with
+    /// United States federal holidays.
+    NearestWeekday,
+
+    /// A holiday on a weekend day is observed on the first day after the
+    /// weekend, which is the Monday after with a Saturday and Sunday
+    /// weekend, as with bank holidays in the United Kingdom. In a business
+    /// calendar, if another holiday is already observed on that day, it
+    /// moves on to the next free day instead, so when Christmas Day falls
+    /// on a Saturday, Boxing Day is observed on the Tuesday.
+    FollowingMonday,
+}
+
+impl Holiday {
+
+    /// Creates a holiday that falls on the same date every year, such as
+    /// the 25th of December. The day is checked against the number of days
+    /// in the month in a leap year, so the 29th of February is allowed,
+    /// and is only a holiday in leap years.
+    pub fn fixed(month: Month, day: i8) -> Result<Self, Error> {
+        if (1 ..= month.days_in_month(true)).contains(&day) {
+            Ok(Self { date: HolidayDate::Fixed(month, day), observance: Observance::Actual })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Creates a holiday that falls on the *n*th occurrence of a weekday in
+    /// a month, such as the fourth Thursday of November. A negative number
+    /// counts back from the end of the month, so -1 is the last one.
+    ///
+    /// The number must be between 1 and 5, or -1 and -5. The fifth
+    /// occurrence doesn’t exist in every month, and in those years there’s
+    /// no holiday.
+    pub fn nth_weekday(month: Month, weekday: Weekday, n: i8) -> Result<Self, Error> {
+        if n != 0 && (-5 ..= 5).contains(&n) {
+            Ok(Self { date: HolidayDate::NthWeekday(month, weekday, n), observance: Observance::Actual })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Creates a holiday that falls on a feast that moves along with
+    /// Easter, such as Good Friday or Whit Monday.
+    pub fn feast(feast: MovableFeast, computus: Computus) -> Self {
+        Self { date: HolidayDate::Feast(feast, computus), observance: Observance::Actual }
+    }
+
+    /// Creates a holiday that only happens once, on the given date.
+    pub fn once(date: LocalDate) -> Self {
+        Self { date: HolidayDate::Once(date), observance: Observance::Actual }
+    }
+
+    /// Returns this holiday, observed according to the given rule when it
+    /// falls on a weekend.
+    pub fn observed(self, observance: Observance) -> Self {
+        Self { observance, ..self }
+    }
+
+    /// Returns the date this holiday falls on in the given year, if it
+    /// happens that year.
+    pub fn date_in(&self, year: i64) -> Option<LocalDate> {
+        match self.date {
+            HolidayDate::Fixed(month, day)              => LocalDate::ymd(year, month, day).ok(),
+            HolidayDate::NthWeekday(month, weekday, n)  => Year(year).month(month).nth_weekday(weekday, n).ok(),
+            HolidayDate::Feast(feast, computus)         => Some(Year(year).feast(feast, computus)),
+            HolidayDate::Once(date)                     => Some(date).filter(|d| d.year() == year),
+        }
+    }
+
+    /// Returns the date this holiday is observed on for the given year, if
+    /// it happens that year. This can be in the year before or after, such
+    /// as when the 1st of January is a Saturday and observed on the 31st
+    /// of December.
+    ///
+    /// This only looks at this holiday on its own, with a Saturday and
+    /// Sunday weekend, so it doesn’t move a holiday that gets observed on
+    /// the same day as another one. A business calendar uses its own
+    /// weekend instead.
+    pub fn observed_in(&self, year: i64) -> Option<LocalDate> {
+        self.observed_with_weekend(year, SATURDAY_AND_SUNDAY)
+    }
+
+    /// Returns the date this holiday is observed on for the given year,
+    /// with the days in the given mask as the weekend. The mask must have
+    /// at least one day that isn’t part of the weekend.
+    fn observed_with_weekend(&self, year: i64, weekend: u8) -> Option<LocalDate> {
+        let date = self.date_in(year)?;
+        let is_weekend = |d: LocalDate| weekend & (1 << d.weekday() as u8) != 0;
+        if !is_weekend(date) {
+            return Some(date);
+        }
+
+        let after = (1 ..).find(|&n| !is_weekend(date.add_days(n))).unwrap();
+        let shift = match self.observance {
+            Observance::Actual           => 0,
+            Observance::FollowingMonday  => after,
+            Observance::NearestWeekday   => {
+                let before = (1 ..).find(|&n| !is_weekend(date.add_days(-n))).unwrap();
+                if before < after { -before } else { after }
+            },
+        };
+
+        Some(date.add_days(shift))
+    }
+}
+
+
+/// The weekend mask for Saturday and Sunday, with one bit for each day.
+const SATURDAY_AND_SUNDAY: u8 = 1 << Weekday::Saturday as u8 | 1 << Weekday::Sunday as u8;
+
+
+/// A **business calendar** knows which days are business days, given
+/// which days of the week make up the weekend, and a list of holidays.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{BusinessCalendar, Holiday, Observance, LocalDate, Month};
+///
+/// let mut calendar = BusinessCalendar::new();
+/// calendar.add_holiday(Holiday::fixed(Month::December, 25).unwrap().observed(Observance::NearestWeekday));
+///
+/// // Christmas 2021 was a Saturday, so it was observed on Friday the 24th.
+/// let thursday = LocalDate::ymd(2021, Month::December, 23).unwrap();
+/// assert_eq!(calendar.add_business_days(thursday, 1), LocalDate::ymd(2021, Month::December, 27).unwrap());
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BusinessCalendar {
+    weekend: u8,
+    holidays: Vec<Holiday>,
+}
+
+impl BusinessCalendar {
+
+    /// Creates a new business calendar with a Saturday and Sunday weekend,
+    /// and no holidays.
+    pub fn new() -> Self {
+        Self { weekend: SATURDAY_AND_SUNDAY, holidays: Vec::new() }
+    }
+
+    /// Creates a new business calendar with the given days as the
+    /// weekend, and no holidays.
+    ///
+    /// This returns an error if every day of the week is part of the
+    /// weekend, as there would be no business days at all.
+    pub fn with_weekend(weekend: &[Weekday]) -> Result<Self, Error> {
+        let weekend = weekend.iter().fold(0, |mask, &day| mask | 1 << day as u8);
+        if weekend == 0x7F {
+            return Err(Error::OutOfRange);
+        }
+
+        Ok(Self { weekend, holidays: Vec::new() })
+    }
+
+    /// Adds a holiday to this calendar.
+    pub fn add_holiday(&mut self, holiday: Holiday) {
+        self.holidays.push(holiday);
+    }
+
+    /// Returns whether the given day is part of the weekend.
+    pub fn is_weekend(&self, date: LocalDate) -> bool {
+        self.weekend & (1 << date.weekday() as u8) != 0
+    }
+
+    /// Returns whether a holiday is observed on the given day.
+    pub fn is_holiday(&self, date: LocalDate) -> bool {
+        ObservedHolidays::new(self).contains(date)
+    }
+
+    /// Returns whether the given day is a business day, meaning it’s
+    /// neither part of the weekend nor a holiday.
+    pub fn is_business_day(&self, date: LocalDate) -> bool {
+        ObservedHolidays::new(self).is_business_day(date)
+    }
+
+    /// Returns the dates in the given year that holidays are observed on,
+    /// in order, with no duplicates.
+    pub fn holidays_in(&self, year: i64) -> Vec<LocalDate> {
+        self.observed_between(LocalDate::yd(year, 1).unwrap(), LocalDate::yd(year + 1, 1).unwrap())
+            .into_iter().collect()
+    }
+
+    /// Returns the set of dates from the start date up to, but not
+    /// including, the end date, that holidays are observed on.
+    fn observed_between(&self, start: LocalDate, end: LocalDate) -> BTreeSet<LocalDate> {
+        let years = start.year() - 1 ..= end.year() + 1;
+
+        years.flat_map(|year| self.observed_for(year))
+             .filter(|date| *date >= start && *date < end)
+             .collect()
+    }
+
+    /// Returns the set of dates that the holidays falling in the given
+    /// year are observed on.
+    ///
+    /// Holidays that get moved to the day after a weekend are dealt with
+    /// last, in the order they fall in, and each one moves on past any
+    /// business day that already has a holiday observed on it. The
+    /// holidays in the years either side are taken into account too, as
+    /// one can be observed on a day in the next or previous year.
+    fn observed_for(&self, year: i64) -> BTreeSet<LocalDate> {
+        let mut taken = BTreeSet::new();
+        let mut observed = BTreeSet::new();
+        let mut moved = Vec::new();
+
+        for holiday_year in year - 1 ..= year + 1 {
+            for holiday in &self.holidays {
+                let (date, observed_date) = match (holiday.date_in(holiday_year), holiday.observed_with_weekend(holiday_year, self.weekend)) {
+                    (Some(date), Some(observed_date))  => (date, observed_date),
+                    _                                  => continue,
+                };
+
+                if holiday.observance == Observance::FollowingMonday && date != observed_date {
+                    moved.push((date, observed_date, holiday_year));
+                }
+                else {
+                    taken.insert(observed_date);
+                    if holiday_year == year {
+                        observed.insert(observed_date);
+                    }
+                }
+            }
+        }
+
+        moved.sort();
+        for (_, mut date, holiday_year) in moved {
+            while taken.contains(&date) || self.is_weekend(date) {
+                date = date.add_days(1);
+            }
+
+            taken.insert(date);
+            if holiday_year == year {
+                observed.insert(date);
+            }
+        }
+
+        observed
+    }
+
+    /// Returns the first business day after the given date.
+    pub fn next_business_day(&self, date: LocalDate) -> LocalDate {
+        ObservedHolidays::new(self).next_business_day(date)
+    }
+
+    /// Returns the last business day before the given date.
+    pub fn previous_business_day(&self, date: LocalDate) -> LocalDate {
+        ObservedHolidays::new(self).previous_business_day(date)
+    }
+
+    /// Returns the date that’s the given number of business days after the
+    /// given date, or before it if the number is negative. The date itself
+    /// doesn’t have to be a business day, and adding zero returns it
+    /// unchanged.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{BusinessCalendar, LocalDate, Month};
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let friday = LocalDate::ymd(2017, Month::August, 18).unwrap();
+    ///
+    /// assert_eq!(calendar.add_business_days(friday, 2), LocalDate::ymd(2017, Month::August, 22).unwrap());
+    /// assert_eq!(calendar.add_business_days(friday, -5), LocalDate::ymd(2017, Month::August, 11).unwrap());
+    /// ```
+    pub fn add_business_days(&self, date: LocalDate, days: i64) -> LocalDate {
+        let mut holidays = ObservedHolidays::new(self);
+        let mut date = date;
+
+        for _ in 0 .. days.abs() {
+            date = if days > 0 { holidays.next_business_day(date) }
+                          else { holidays.previous_business_day(date) };
+        }
+
+        date
+    }
+
+    /// Returns the number of business days from the start date up to, but
+    /// not including, the end date. If the end date is before the start
+    /// date, the number is negative.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{BusinessCalendar, LocalDate, Month};
+    ///
+    /// let calendar = BusinessCalendar::new();
+    /// let monday = LocalDate::ymd(2017, Month::August, 14).unwrap();
+    /// let next_monday = LocalDate::ymd(2017, Month::August, 21).unwrap();
+    ///
+    /// assert_eq!(calendar.business_days_between(monday, next_monday), 5);
+    /// assert_eq!(calendar.business_days_between(next_monday, monday), -5);
+    /// ```
+    pub fn business_days_between(&self, start: LocalDate, end: LocalDate) -> i64 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+
+        // Count the whole weeks all at once, then the days left over.
+        let days = start.days_until(end);
+        let working_days = 7 - self.weekend.count_ones() as i64;
+        let leftover = (days - days % 7 .. days).filter(|&d| !self.is_weekend(start.add_days(d))).count();
+
+        let holidays = self.observed_between(start, end).into_iter().filter(|d| !self.is_weekend(*d)).count();
+        days / 7 * working_days + leftover as i64 - holidays as i64
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// The holidays observed in a business calendar, worked out a year at a
+/// time as they’re needed, so that checking a run of days doesn’t work
+/// out the same year over and over again.
+struct ObservedHolidays<'cal> {
+    calendar: &'cal BusinessCalendar,
+    years: BTreeMap<i64, BTreeSet<LocalDate>>,
+}
+
+impl<'cal> ObservedHolidays<'cal> {
+    fn new(calendar: &'cal BusinessCalendar) -> Self {
+        Self { calendar, years: BTreeMap::new() }
+    }
+
+    fn contains(&mut self, date: LocalDate) -> bool {
+        let calendar = self.calendar;
+
+        // Observed dates can move across the start or end of a year.
+        for year in date.year() - 1 ..= date.year() + 1 {
+            let observed = self.years.entry(year).or_insert_with(|| calendar.observed_for(year));
+            if observed.contains(&date) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_business_day(&mut self, date: LocalDate) -> bool {
+        !self.calendar.is_weekend(date) && !self.contains(date)
+    }
+
+    fn next_business_day(&mut self, date: LocalDate) -> LocalDate {
+        let mut date = date.add_days(1);
+        while !self.is_business_day(date) {
+            date = date.add_days(1);
+        }
+
+        date
+    }
+
+    fn previous_business_day(&mut self, date: LocalDate) -> LocalDate {
+        let mut date = date.add_days(-1);
+        while !self.is_business_day(date) {
+            date = date.add_days(-1);
+        }
+
+        date
+    }
+}
@@ -22,7 +22,7 @@ use self::Weekday::*;
 /// A single year.
 ///
 /// This is just a wrapper around `i64` that performs year-related tests.
-#[derive(PartialEq, Debug, Copy, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
 pub struct Year(pub i64);
 
 impl Year {
@@ -94,6 +94,63 @@ impl Year {
         }
     }
 
+    /// Returns a year-quarter, pairing this year with the given quarter.
+    pub fn quarter(self, quarter: Quarter) -> YearQuarter {
+        YearQuarter { year: self, quarter }
+    }
+
+    /// Returns the year after this one.
+    pub fn succ(self) -> Self {
+        Year(self.0 + 1)
+    }
+
+    /// Returns the year before this one.
+    pub fn pred(self) -> Self {
+        Year(self.0 - 1)
+    }
+
+    /// Returns a year-week, pairing this ISO-8601 week-numbering year with
+    /// the given week number.
+    ///
+    /// The week number is checked for validity, so passing in a number
+    /// less than 1, or greater than the number of weeks in this year (52
+    /// or 53), will return an error.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    ///
+    /// assert!(Year(2015).week(53).is_ok());
+    /// assert!(Year(2016).week(53).is_err());
+    /// ```
+    pub fn week(self, week: i8) -> Result<YearWeek, Error> {
+        if week >= 1 && week <= iso_weeks_in_year(self.0) {
+            Ok(YearWeek { year: self, week })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns an iterator over every ISO-8601 week in this week-numbering
+    /// year, returning year-week pairs.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    ///
+    /// assert_eq!(Year(2015).weeks().count(), 53);
+    /// assert_eq!(Year(2016).weeks().count(), 52);
+    /// ```
+    pub fn weeks(self) -> YearWeeks {
+        YearWeeks {
+            year: self,
+            range: 1 .. iso_weeks_in_year(self.0) + 1,
+        }
+    }
+
     /// Performs two related calculations for leap years, returning the
     /// results as a two-part tuple:
     ///
@@ -204,7 +261,7 @@ impl fmt::Debug for YearMonths {
 }
 
 /// A month-year pair.
-#[derive(PartialEq, Debug, Copy, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
 pub struct YearMonth {
     pub year: Year,
     pub month: Month,
@@ -257,6 +314,411 @@ impl YearMonth {
     pub fn day(&self, day: i8) -> Result<LocalDate, Error> {
         LocalDate::ymd(self.year.0, self.month, day)
     }
+
+    /// Returns the date of the *n*th occurrence of the given weekday in
+    /// this month, counting from 1. A negative number counts back from the
+    /// end of the month instead, so -1 is the last one.
+    ///
+    /// Passing in zero, or a number past the occurrences in this month,
+    /// will return an error.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, Weekday, LocalDate};
+    /// use datetime::Month::November;
+    ///
+    /// let ym = Year(2017).month(November);
+    /// assert_eq!(ym.nth_weekday(Weekday::Thursday, 4), LocalDate::ymd(2017, November, 23));
+    /// assert_eq!(ym.nth_weekday(Weekday::Friday, -1), LocalDate::ymd(2017, November, 24));
+    /// assert!(ym.nth_weekday(Weekday::Friday, 5).is_err());
+    /// ```
+    pub fn nth_weekday(&self, weekday: Weekday, n: i8) -> Result<LocalDate, Error> {
+        // No month has more than five of any weekday, and checking this
+        // first stops the arithmetic below from overflowing.
+        if n == 0 || !(-5 ..= 5).contains(&n) {
+            return Err(Error::OutOfRange);
+        }
+
+        let day = if n > 0 {
+            let first = self.day(1)?;
+            1 + days_between_weekdays(first.weekday, weekday) + (n - 1) * 7
+        }
+        else {
+            let last = self.day(self.day_count())?;
+            self.day_count() - days_between_weekdays(weekday, last.weekday) + (n + 1) * 7
+        };
+
+        self.day(day)
+    }
+
+    /// Returns the number of months from this year-month until the given
+    /// one, which is negative if the other one is earlier.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    /// use datetime::Month::{November, February};
+    ///
+    /// let start = Year(2016).month(November);
+    /// assert_eq!(start.months_until(Year(2017).month(February)), 3);
+    /// assert_eq!(start.months_until(Year(2015).month(November)), -12);
+    /// ```
+    pub fn months_until(&self, other: Self) -> i64 {
+        other.months_since_year_zero() - self.months_since_year_zero()
+    }
+
+    /// Returns an iterator over consecutive year-months, starting with this
+    /// one and stopping at *but not including* the given one, crossing
+    /// over year boundaries as necessary.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    /// use datetime::Month::{November, January, February};
+    ///
+    /// let start = Year(2016).month(November);
+    /// let months: Vec<_> = start.until(Year(2017).month(February)).collect();
+    /// assert_eq!(months.len(), 3);
+    /// assert_eq!(months[2], Year(2017).month(January));
+    /// ```
+    pub fn until(&self, end: Self) -> YearMonthRange {
+        YearMonthRange {
+            range: self.months_since_year_zero() .. end.months_since_year_zero(),
+        }
+    }
+
+    /// Returns the number of months between January of the year 0 and this
+    /// year-month, which makes month arithmetic a matter of addition.
+    fn months_since_year_zero(&self) -> i64 {
+        self.year.0 * 12 + self.month.months_from_january() as i64
+    }
+
+    /// Creates a year-month from a number of months since January of the
+    /// year 0, which is the inverse of `months_since_year_zero`.
+    fn from_months_since_year_zero(months: i64) -> Self {
+        let (years, months) = split_cycles(months, 12);
+
+        // The remainder is always between 0 and 11, so this can’t fail.
+        let month = Month::from_zero(months as i8).unwrap();
+        Year(years).month(month)
+    }
+}
+
+/// Adding a number of months to a year-month moves forward that many
+/// months, continuing into the next year if necessary.
+impl Add<i64> for YearMonth {
+    type Output = Self;
+
+    fn add(self, months: i64) -> Self {
+        Self::from_months_since_year_zero(self.months_since_year_zero() + months)
+    }
+}
+
+/// Subtracting a number of months from a year-month moves back that many
+/// months, continuing into the previous year if necessary.
+impl Sub<i64> for YearMonth {
+    type Output = Self;
+
+    fn sub(self, months: i64) -> Self {
+        Self::from_months_since_year_zero(self.months_since_year_zero() - months)
+    }
+}
+
+
+/// An iterator over consecutive year-months, which can cross over from one
+/// year to the next.
+///
+/// Use the `until` method on `YearMonth` to create instances of this
+/// iterator.
+#[derive(PartialEq, Debug, Clone)]
+pub struct YearMonthRange {
+    range: Range<i64>,
+}
+
+impl Iterator for YearMonthRange {
+    type Item = YearMonth;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(YearMonth::from_months_since_year_zero)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for YearMonthRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(YearMonth::from_months_since_year_zero)
+    }
+}
+
+
+/// A quarter of a year, which is three consecutive months starting in
+/// January, April, July, or October.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Quarter {
+    Q1 = 1, Q2 = 2, Q3 = 3, Q4 = 4,
+}
+
+impl Quarter {
+
+    /// Returns the quarter based on a number, with January to March as
+    /// **Quarter 1**, April to June as **Quarter 2**, and so on.
+    ///
+    /// ```rust
+    /// use datetime::Quarter;
+    /// assert_eq!(Quarter::from_one(3), Ok(Quarter::Q3));
+    /// assert!(Quarter::from_one(5).is_err());
+    /// ```
+    pub fn from_one(quarter: i8) -> Result<Self, Error> {
+        Ok(match quarter {
+            1 => Quarter::Q1,  2 => Quarter::Q2,
+            3 => Quarter::Q3,  4 => Quarter::Q4,
+            _ => return Err(Error::OutOfRange),
+        })
+    }
+
+    /// Returns the first month of this quarter.
+    pub fn first_month(self) -> Month {
+        // A quarter number is between 1 and 4, so this can’t fail.
+        Month::from_zero((self as i8 - 1) * 3).unwrap()
+    }
+}
+
+impl Month {
+
+    /// Returns the quarter of the year that this month falls in.
+    ///
+    /// ```rust
+    /// use datetime::{Month, Quarter};
+    /// assert_eq!(Month::May.quarter(), Quarter::Q2);
+    /// ```
+    pub fn quarter(self) -> Quarter {
+        // There are only twelve months, so this can’t fail.
+        Quarter::from_one(self.months_from_january() as i8 / 3 + 1).unwrap()
+    }
+}
+
+
+/// A quarter-year pair.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub struct YearQuarter {
+    pub year: Year,
+    pub quarter: Quarter,
+}
+
+impl YearQuarter {
+
+    /// Returns the first month of this quarter.
+    pub fn first_month(&self) -> YearMonth {
+        self.year.month(self.quarter.first_month())
+    }
+
+    /// Returns an iterator over the three months in this quarter.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, Quarter};
+    /// use datetime::Month::{October, December};
+    ///
+    /// let months: Vec<_> = Year(2016).quarter(Quarter::Q4).months().collect();
+    /// assert_eq!(months.len(), 3);
+    /// assert_eq!(months[0], Year(2016).month(October));
+    /// assert_eq!(months[2], Year(2016).month(December));
+    /// ```
+    pub fn months(&self) -> YearMonthRange {
+        let first = self.first_month();
+        first.until(first + 3)
+    }
+
+    /// Returns the first day of this quarter.
+    pub fn first_day(&self) -> LocalDate {
+        // The first day of a month always exists.
+        self.first_month().day(1).unwrap()
+    }
+
+    /// Returns the last day of this quarter.
+    pub fn last_day(&self) -> LocalDate {
+        let last = self.first_month() + 2;
+        last.day(last.day_count()).unwrap()
+    }
+
+    /// Returns the number of days in this quarter, which is between 90 and
+    /// 92 depending on the quarter and whether it’s a leap year.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, Quarter};
+    ///
+    /// assert_eq!(Year(2015).quarter(Quarter::Q1).day_count(), 90);
+    /// assert_eq!(Year(2016).quarter(Quarter::Q1).day_count(), 91);
+    /// assert_eq!(Year(2016).quarter(Quarter::Q3).day_count(), 92);
+    /// ```
+    pub fn day_count(&self) -> i16 {
+        self.months().map(|ym| ym.day_count() as i16).sum()
+    }
+
+    /// Returns the number of quarters between the year 0 and this quarter,
+    /// which makes quarter arithmetic a matter of addition.
+    fn quarters_since_year_zero(&self) -> i64 {
+        self.year.0 * 4 + self.quarter as i64 - 1
+    }
+
+    /// Creates a year-quarter from a number of quarters since the year 0,
+    /// which is the inverse of `quarters_since_year_zero`.
+    fn from_quarters_since_year_zero(quarters: i64) -> Self {
+        let (years, quarters) = split_cycles(quarters, 4);
+
+        // The remainder is always between 0 and 3, so this can’t fail.
+        let quarter = Quarter::from_one(quarters as i8 + 1).unwrap();
+        Year(years).quarter(quarter)
+    }
+}
+
+/// Adding a number of quarters to a year-quarter moves forward that many
+/// quarters, continuing into the next year if necessary.
+impl Add<i64> for YearQuarter {
+    type Output = Self;
+
+    fn add(self, quarters: i64) -> Self {
+        Self::from_quarters_since_year_zero(self.quarters_since_year_zero() + quarters)
+    }
+}
+
+/// Subtracting a number of quarters from a year-quarter moves back that
+/// many quarters, continuing into the previous year if necessary.
+impl Sub<i64> for YearQuarter {
+    type Output = Self;
+
+    fn sub(self, quarters: i64) -> Self {
+        Self::from_quarters_since_year_zero(self.quarters_since_year_zero() - quarters)
+    }
+}
+
+
+/// An iterator over the weeks in an ISO-8601 week-numbering year.
+///
+/// Use the `weeks` method on `Year` to create instances of this iterator.
+#[derive(PartialEq, Debug, Clone)]
+pub struct YearWeeks {
+    year: Year,
+    range: Range<i8>,
+}
+
+impl Iterator for YearWeeks {
+    type Item = YearWeek;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|week| YearWeek { year: self.year, week })
+    }
+}
+
+impl DoubleEndedIterator for YearWeeks {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|week| YearWeek { year: self.year, week })
+    }
+}
+
+
+/// A week-year pair, using ISO-8601 week numbering: weeks start on a
+/// Monday, and week 1 is the week with the year’s first Thursday in it.
+///
+/// This means that the week-numbering year can differ from the calendar
+/// year for a few days at either end of it.
+///
+/// Use the `week` method on `Year` to create instances of this type, which
+/// checks that the week exists in that year.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub struct YearWeek {
+    year: Year,
+    week: i8,
+}
+
+impl YearWeek {
+
+    /// The ISO-8601 week-numbering year.
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    /// The week number, from 1 to 53.
+    pub fn week(&self) -> i8 {
+        self.week
+    }
+
+    /// Returns the number of weeks in this week’s year, which is either 52
+    /// or 53.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    ///
+    /// assert_eq!(Year(2020).week(1).unwrap().weeks_in_year(), 53);
+    /// assert_eq!(Year(2021).week(1).unwrap().weeks_in_year(), 52);
+    /// ```
+    pub fn weeks_in_year(&self) -> i8 {
+        iso_weeks_in_year(self.year.0)
+    }
+
+    /// Returns an iterator over the seven days in this week, from Monday
+    /// to Sunday, returning `LocalDate` values.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, LocalDate, Month};
+    ///
+    /// let days: Vec<_> = Year(2009).week(1).unwrap().days().collect();
+    /// assert_eq!(days.len(), 7);
+    /// assert_eq!(days[0], LocalDate::ymd(2008, Month::December, 29).unwrap());
+    /// assert_eq!(days[6], LocalDate::ymd(2009, Month::January, 4).unwrap());
+    /// ```
+    pub fn days(&self) -> WeekDays {
+        WeekDays {
+            // The week was checked when this value was created, so it
+            // always has a Monday.
+            monday: self.day(Monday).unwrap(),
+            range: 0 .. 7,
+        }
+    }
+
+    /// Returns a `LocalDate` based on the day of this week.
+    ///
+    /// This is just a short-cut for the `LocalDate::ywd` constructor.
+    pub fn day(&self, weekday: Weekday) -> Result<LocalDate, Error> {
+        LocalDate::ywd(self.year.0, self.week as i64, weekday)
+    }
+}
+
+
+/// An iterator over the days in a week.
+///
+/// Use the `days` method on `YearWeek` to create instances of this iterator.
+#[derive(PartialEq, Debug, Clone)]
+pub struct WeekDays {
+    monday: LocalDate,
+    range: Range<i64>,
+}
+
+impl Iterator for WeekDays {
+    type Item = LocalDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|d| self.monday.add_days(d))
+    }
+}
+
+impl DoubleEndedIterator for WeekDays {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|d| self.monday.add_days(d))
+    }
 }
 
 
@@ -325,8 +787,9 @@ const DAYS_IN_100Y: i64 = 365 * 100 + 24;
 /// Number of days guaranteed to be in four hundred years.
 const DAYS_IN_400Y: i64 = 365 * 400 + 97;
 
-/// Number of seconds in a day. As everywhere in this library, leap seconds
-/// are simply ignored.
+/// Number of seconds in a day. Leap seconds are ignored here, just as they
+/// are in Unix time; the `LeapSecondTable` type is the place that knows
+/// about them.
 const SECONDS_IN_DAY: i64 = 86400;
 
 
@@ -355,9 +818,9 @@ const SECONDS_IN_DAY: i64 = 86400;
 ///
 /// [^win32]: http://blogs.msdn.com/b/oldnewthing/archive/2009/03/06/9461176.aspx
 ///
-const EPOCH_DIFFERENCE: i64 = 30 * 365   // 30 years between 2000 and 1970...
-                            + 7          // plus seven days for leap years...
-                            + 31 + 29;   // plus all the days in January and February in 2000.
+pub(crate) const EPOCH_DIFFERENCE: i64 = 30 * 365   // 30 years between 2000 and 1970...
+                                       + 7          // plus seven days for leap years...
+                                       + 31 + 29;   // plus all the days in January and February in 2000.
 
 
 /// This rather strange triangle is an array of the number of days elapsed
@@ -553,7 +1016,7 @@ impl LocalDate {
     /// assert_eq!(date.month(), Month::September);
     /// assert_eq!(date.day(), 24);  // not the 25th!
     /// ```
-    fn from_days_since_epoch(days: i64) -> Self {
+    pub(crate) fn from_days_since_epoch(days: i64) -> Self {
 
         // The Gregorian calendar works in 400-year cycles, which repeat
         // themselves ever after.
@@ -566,7 +1029,9 @@ impl LocalDate {
         // Calculate the numbers of 100-year cycles, 4-year cycles, and
         // leftover years, continually reducing the number of days left to
         // think about.
-        let num_100y_cycles = remainder / DAYS_IN_100Y;
+        // The last day of a 400-year cycle is the leap day that ends its
+        // fourth 100-year cycle, rather than the start of a fifth one.
+        let num_100y_cycles = std::cmp::min(remainder / DAYS_IN_100Y, 3);
         remainder -= num_100y_cycles * DAYS_IN_100Y;  // remainder is now days left in this 100-year cycle
 
         let num_4y_cycles = remainder / DAYS_IN_4Y;
@@ -640,6 +1105,162 @@ impl LocalDate {
         }
     }
 
+    /// Returns the ISO-8601 week that this date falls in.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, Year};
+    ///
+    /// let date = LocalDate::ymd(2010, Month::January, 3).unwrap();
+    /// assert_eq!(date.year_week(), Year(2009).week(53).unwrap());
+    /// ```
+    pub fn year_week(&self) -> YearWeek {
+        let (year, week) = iso_week_date(self.ymd.year, self.yearday, self.weekday);
+        YearWeek { year: Year(year), week }
+    }
+
+    /// Returns the quarter of the year that this date falls in.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, Year, Quarter};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::August, 8).unwrap();
+    /// assert_eq!(date.year_quarter(), Year(2017).quarter(Quarter::Q3));
+    /// ```
+    pub fn year_quarter(&self) -> YearQuarter {
+        Year(self.ymd.year).quarter(self.ymd.month.quarter())
+    }
+
+    /// Returns the number of days that have passed since the EPOCH, which
+    /// is the inverse of `from_days_since_epoch`.
+    pub(crate) fn days_since_epoch(&self) -> i64 {
+        // The fields of a LocalDate have already been checked, so this
+        // can’t fail.
+        self.ymd.to_days_since_epoch().unwrap() - EPOCH_DIFFERENCE
+    }
+
+    /// Returns the date the given number of days after this one, or
+    /// *before* it if the number is negative.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::February, 28).unwrap();
+    /// assert_eq!(date.add_days(2), LocalDate::ymd(2016, Month::March, 1).unwrap());
+    /// assert_eq!(date.add_days(-59), LocalDate::ymd(2015, Month::December, 31).unwrap());
+    /// ```
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Returns the date the given number of weeks after this one, or
+    /// *before* it if the number is negative. The weekday stays the same.
+    pub fn add_weeks(&self, weeks: i64) -> Self {
+        self.add_days(weeks * 7)
+    }
+
+    /// Returns the number of days from this date until the given one,
+    /// which is negative if the other date is earlier.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let start = LocalDate::ymd(1999, Month::December, 25).unwrap();
+    /// let end   = LocalDate::ymd(2000, Month::January, 1).unwrap();
+    /// assert_eq!(start.days_until(end), 7);
+    /// assert_eq!(end.days_until(start), -7);
+    /// ```
+    pub fn days_until(&self, other: Self) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// Returns the first date *after* this one that falls on the given
+    /// weekday, which is always between one and seven days later.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, Weekday};
+    ///
+    /// // The 6th of March 2017 was a Monday.
+    /// let date = LocalDate::ymd(2017, Month::March, 6).unwrap();
+    /// assert_eq!(date.next(Weekday::Wednesday), LocalDate::ymd(2017, Month::March, 8).unwrap());
+    /// assert_eq!(date.next(Weekday::Monday), LocalDate::ymd(2017, Month::March, 13).unwrap());
+    /// ```
+    pub fn next(&self, weekday: Weekday) -> Self {
+        match days_between_weekdays(self.weekday, weekday) {
+            0    => self.add_weeks(1),
+            days => self.add_days(days as i64),
+        }
+    }
+
+    /// Returns this date if it falls on the given weekday, or the first
+    /// date after it that does.
+    pub fn next_or_same(&self, weekday: Weekday) -> Self {
+        self.add_days(days_between_weekdays(self.weekday, weekday) as i64)
+    }
+
+    /// Returns the last date *before* this one that fell on the given
+    /// weekday, which is always between one and seven days earlier.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, Weekday};
+    ///
+    /// // The 6th of March 2017 was a Monday.
+    /// let date = LocalDate::ymd(2017, Month::March, 6).unwrap();
+    /// assert_eq!(date.previous(Weekday::Friday), LocalDate::ymd(2017, Month::March, 3).unwrap());
+    /// assert_eq!(date.previous(Weekday::Monday), LocalDate::ymd(2017, Month::February, 27).unwrap());
+    /// ```
+    pub fn previous(&self, weekday: Weekday) -> Self {
+        match days_between_weekdays(weekday, self.weekday) {
+            0    => self.add_weeks(-1),
+            days => self.add_days(-(days as i64)),
+        }
+    }
+
+    /// Returns this date if it falls on the given weekday, or the last
+    /// date before it that did.
+    pub fn previous_or_same(&self, weekday: Weekday) -> Self {
+        self.add_days(-(days_between_weekdays(weekday, self.weekday) as i64))
+    }
+
+    /// Returns which occurrence of its weekday in the month this date is,
+    /// from 1 to 5, such as 2 for the second Tuesday of a month.
+    ///
+    /// This is the inverse of `YearMonth::nth_weekday`. To number the weeks
+    /// of a month instead, use `week_of_month` with a `WeekRule`.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let thursday = LocalDate::ymd(2017, Month::November, 23).unwrap();
+    /// assert_eq!(thursday.weekday_of_month(), 4);
+    /// assert!(!thursday.is_last_weekday_of_month());
+    ///
+    /// let friday = LocalDate::ymd(2017, Month::November, 24).unwrap();
+    /// assert!(friday.is_last_weekday_of_month());
+    /// ```
+    pub fn weekday_of_month(&self) -> i8 {
+        (self.ymd.day - 1) / 7 + 1
+    }
+
+    /// Returns whether this date is the last occurrence of its weekday in
+    /// the month, such as the last Monday of May.
+    pub fn is_last_weekday_of_month(&self) -> bool {
+        self.ymd.day + 7 > self.ymd.month.days_in_month(Year(self.ymd.year).is_leap_year())
+    }
+
     /// Creates a new datestamp instance with the given year, month, day,
     /// weekday, and yearday fields.
     ///
@@ -695,6 +1316,16 @@ impl Ord for LocalDate {
     }
 }
 
+/// Subtracting one date from another gives the number of days between
+/// them.
+impl Sub<LocalDate> for LocalDate {
+    type Output = i64;
+
+    fn sub(self, other: Self) -> i64 {
+        other.days_until(self)
+    }
+}
+
 impl LocalTime {
 
     /// Computes the number of hours, minutes, and seconds, based on the
@@ -739,9 +1370,24 @@ impl LocalTime {
     ///
     /// The values are checked for validity before instantiation, and
     /// passing in values out of range will return an `Err`.
+    ///
+    /// A second value of 60 is allowed at 23:59, to represent a leap
+    /// second. Whether a leap second actually occurred on any particular
+    /// day depends on the date, which can be checked with
+    /// `LeapSecondTable::has_leap_second_on`.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::LocalTime;
+    ///
+    /// assert!(LocalTime::hms(23, 59, 60).is_ok());
+    /// assert!(LocalTime::hms(12, 30, 60).is_err());
+    /// ```
     pub fn hms(hour: i8, minute: i8, second: i8) -> Result<Self, Error> {
         if (hour.is_within(0..24) && minute.is_within(0..60) && second.is_within(0..60))
-        || (hour == 24 && minute == 00 && second == 00) {
+        || (hour == 24 && minute == 00 && second == 00)
+        || is_leap_second(hour, minute, second) {
             Ok(Self { hour, minute, second, millisecond: 0 })
         }
         else {
@@ -753,10 +1399,12 @@ impl LocalTime {
     /// second, and millisecond fields.
     ///
     /// The values are checked for validity before instantiation, and
-    /// passing in values out of range will return an `Err`.
+    /// passing in values out of range will return an `Err`. As with `hms`,
+    /// a second value of 60 is allowed at 23:59.
     pub fn hms_ms(hour: i8, minute: i8, second: i8, millisecond: i16) -> Result<Self, Error> {
-        if hour.is_within(0..24)   && minute.is_within(0..60)
-        && second.is_within(0..60) && millisecond.is_within(0..1000)
+        if ((hour.is_within(0..24) && minute.is_within(0..60) && second.is_within(0..60))
+            || is_leap_second(hour, minute, second))
+        && millisecond.is_within(0..1000)
         {
             Ok(Self { hour, minute, second, millisecond })
         }
@@ -765,6 +1413,11 @@ impl LocalTime {
         }
     }
 
+    /// Returns whether this time is during a leap second, 23:59:60.
+    pub fn is_leap_second(self) -> bool {
+        is_leap_second(self.hour, self.minute, self.second)
+    }
+
     /// Calculate the number of seconds since midnight this time is at,
     /// ignoring milliseconds.
     pub fn to_seconds(self) -> i64 {
@@ -774,6 +1427,12 @@ impl LocalTime {
     }
 }
 
+/// Returns whether the given time fields are those of a leap second, which
+/// gets inserted as an extra second at the end of the day’s last minute.
+fn is_leap_second(hour: i8, minute: i8, second: i8) -> bool {
+    hour == 23 && minute == 59 && second == 60
+}
+
 impl TimePiece for LocalTime {
     fn hour(&self) -> i8 { self.hour }
     fn minute(&self) -> i8 { self.minute }
@@ -819,6 +1478,10 @@ impl LocalDateTime {
     }
 
     /// Creates a new local date time from a local date and a local time.
+    ///
+    /// This does not check whether a leap second time of 23:59:60 falls on
+    /// a day that actually had one; use `LeapSecondTable::has_leap_second_on`
+    /// for that.
     pub fn new(date: LocalDate, time: LocalTime) -> Self {
         Self {
             date,
@@ -843,8 +1506,17 @@ impl LocalDateTime {
         Self::at_ms(s, ms)
     }
 
+    /// Converts this date-time to an instant, treating it as UTC.
+    ///
+    /// Instants have no way to represent a leap second, so a time of
+    /// 23:59:60 gets converted to the same instant as 23:59:59, in the
+    /// same way that a Unix clock repeats that second.
     pub fn to_instant(&self) -> Instant {
-        let seconds = self.date.ymd.to_days_since_epoch().unwrap() * SECONDS_IN_DAY + self.time.to_seconds();
+        let mut seconds = self.date.ymd.to_days_since_epoch().unwrap() * SECONDS_IN_DAY + self.time.to_seconds();
+        if self.time.is_leap_second() {
+            seconds -= 1;
+        }
+
         Instant::at_ms(seconds, self.time.millisecond)
     }
 
@@ -962,6 +1634,46 @@ impl YMD {
     }
 }
 
+/// Computes the ISO-8601 week-numbering year and week number of a date,
+/// given its year, day of the year, and weekday.
+pub(crate) fn iso_week_date(year: i64, yearday: i16, weekday: Weekday) -> (i64, i8) {
+    // This is the week number counting the week containing the first
+    // Thursday of the year as week 1, which can be 0 or one too many for
+    // days at either end of the year.
+    let week = (yearday as i64 - weekday.days_from_monday_as_one() as i64 + 10) / 7;
+
+    if week < 1 {
+        (year - 1, iso_weeks_in_year(year - 1))
+    }
+    else if week > iso_weeks_in_year(year) as i64 {
+        (year + 1, 1)
+    }
+    else {
+        (year, week as i8)
+    }
+}
+
+/// Returns the number of ISO-8601 weeks in the given year. A year has 53
+/// weeks if it starts on a Thursday, or if it’s a leap year that starts on
+/// a Wednesday, so that it has 53 Thursdays in it; every other year has 52.
+fn iso_weeks_in_year(year: i64) -> i8 {
+    let jan_1 = YMD { year, month: January, day: 1 };
+    let weekday = days_to_weekday(jan_1.to_days_since_epoch().unwrap() - EPOCH_DIFFERENCE);
+
+    if weekday == Thursday || (weekday == Wednesday && Year(year).is_leap_year()) {
+        53
+    }
+    else {
+        52
+    }
+}
+
+/// Returns the number of days from one weekday forward to the next
+/// occurrence of another, from 0 to 6.
+fn days_between_weekdays(from: Weekday, to: Weekday) -> i8 {
+    (to as i8 - from as i8 + 7) % 7
+}
+
 /// Computes the weekday, given the number of days that have passed
 /// since the EPOCH.
 fn days_to_weekday(days: i64) -> Weekday {
@@ -1113,7 +1825,7 @@ pub enum Weekday {
 // ignored when comparing LocalDates.
 
 impl Weekday {
-    fn days_from_monday_as_one(self) -> i8 {
+    pub(crate) fn days_from_monday_as_one(self) -> i8 {
         match self {
             Sunday   => 7,  Monday    => 1,
             Tuesday  => 2,  Wednesday => 3,
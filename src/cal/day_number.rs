@@ -0,0 +1,161 @@
+//! Day numbers, which count whole days from a fixed epoch, and Julian Dates,
+//! which count fractional days.
+
+use cal::datetime::{LocalDate, LocalDateTime, EPOCH_DIFFERENCE};
+use instant::Instant;
+
+
+/// The Julian Day Number of **1st January, 1970**.
+const JULIAN_DAY_NUMBER_EPOCH: i64 = 2_440_588;
+
+/// The Modified Julian Day of **1st January, 1970**.
+const MODIFIED_JULIAN_DAY_EPOCH: i64 = 40_587;
+
+/// The Rata Die day number of **1st January, 1970**.
+const RATA_DIE_EPOCH: i64 = 719_163;
+
+/// The Lilian day number of **1st January, 1970**.
+const LILIAN_DAY_NUMBER_EPOCH: i64 = 141_428;
+
+
+/// Conversions to and from day numbers used in astronomy, chronology, and
+/// elsewhere. Each one is a count of days from its own epoch.
+impl LocalDate {
+
+    /// Creates a new date from a Julian Day Number, which counts days from
+    /// **24th November, 4714 BC** in the proleptic Gregorian calendar.
+    ///
+    /// Strictly, a Julian Day starts at noon, so this is the date on which
+    /// that Julian Day *starts*.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// let date = LocalDate::ymd(2000, Month::January, 1).unwrap();
+    /// assert_eq!(date.to_julian_day_number(), 2_451_545);
+    /// assert_eq!(LocalDate::from_julian_day_number(2_451_545), date);
+    /// ```
+    pub fn from_julian_day_number(days: i64) -> Self {
+        Self::from_days_since(days, JULIAN_DAY_NUMBER_EPOCH)
+    }
+
+    /// Returns the Julian Day Number of this date.
+    pub fn to_julian_day_number(&self) -> i64 {
+        self.days_since(JULIAN_DAY_NUMBER_EPOCH)
+    }
+
+    /// Creates a new date from a Modified Julian Day, which counts days
+    /// from **17th November, 1858**.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// assert_eq!(LocalDate::from_modified_julian_day(0), LocalDate::ymd(1858, Month::November, 17).unwrap());
+    /// ```
+    pub fn from_modified_julian_day(days: i64) -> Self {
+        Self::from_days_since(days, MODIFIED_JULIAN_DAY_EPOCH)
+    }
+
+    /// Returns the Modified Julian Day of this date.
+    pub fn to_modified_julian_day(&self) -> i64 {
+        self.days_since(MODIFIED_JULIAN_DAY_EPOCH)
+    }
+
+    /// Creates a new date from a Rata Die day number, which counts days
+    /// from **1st January, 1**, in the proleptic Gregorian calendar, with
+    /// that day being day 1.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// assert_eq!(LocalDate::from_rata_die(1), LocalDate::ymd(1, Month::January, 1).unwrap());
+    /// ```
+    pub fn from_rata_die(days: i64) -> Self {
+        Self::from_days_since(days, RATA_DIE_EPOCH)
+    }
+
+    /// Returns the Rata Die day number of this date.
+    pub fn to_rata_die(&self) -> i64 {
+        self.days_since(RATA_DIE_EPOCH)
+    }
+
+    /// Creates a new date from a Lilian day number, which counts days from
+    /// the start of the Gregorian calendar, with **15th October, 1582**
+    /// being day 1.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month};
+    ///
+    /// assert_eq!(LocalDate::from_lilian_day_number(1), LocalDate::ymd(1582, Month::October, 15).unwrap());
+    /// ```
+    pub fn from_lilian_day_number(days: i64) -> Self {
+        Self::from_days_since(days, LILIAN_DAY_NUMBER_EPOCH)
+    }
+
+    /// Returns the Lilian day number of this date.
+    pub fn to_lilian_day_number(&self) -> i64 {
+        self.days_since(LILIAN_DAY_NUMBER_EPOCH)
+    }
+
+    /// Creates a new date from a number of days, given the number that
+    /// the 1st of January 1970 has.
+    fn from_days_since(days: i64, epoch: i64) -> Self {
+        Self::from_days_since_epoch(days - epoch - EPOCH_DIFFERENCE)
+    }
+
+    /// Returns the number of days of this date, given the number that the
+    /// 1st of January 1970 has.
+    fn days_since(&self, epoch: i64) -> i64 {
+        self.days_since_epoch() + EPOCH_DIFFERENCE + epoch
+    }
+}
+
+
+/// Conversions to and from Julian Dates, which treat the local datetime as
+/// though it were in UTC.
+impl LocalDateTime {
+
+    /// Creates a new local datetime from a Julian Date, which is a
+    /// fractional number of days since noon on **24th November, 4714 BC**
+    /// in the proleptic Gregorian calendar, or `None` if it isn’t finite
+    /// or is too large to fit.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, LocalDateTime, LocalTime, Month};
+    ///
+    /// let date = LocalDate::ymd(2000, Month::January, 1).unwrap();
+    /// let datetime = LocalDateTime::new(date, LocalTime::hm(18, 0).unwrap());
+    /// assert_eq!(datetime.to_julian_date(), 2_451_545.25);
+    /// assert_eq!(LocalDateTime::at_julian_date(2_451_545.25), Some(datetime));
+    /// ```
+    pub fn at_julian_date(days: f64) -> Option<Self> {
+        Instant::at_julian_date(days).map(Self::from_instant)
+    }
+
+    /// Returns the Julian Date of this local datetime.
+    pub fn to_julian_date(&self) -> f64 {
+        self.to_instant().to_julian_date()
+    }
+
+    /// Creates a new local datetime from a Modified Julian Date, which is a
+    /// fractional number of days since midnight on **17th November, 1858**,
+    /// or `None` if it isn’t finite or is too large to fit.
+    pub fn at_modified_julian_date(days: f64) -> Option<Self> {
+        Instant::at_modified_julian_date(days).map(Self::from_instant)
+    }
+
+    /// Returns the Modified Julian Date of this local datetime.
+    pub fn to_modified_julian_date(&self) -> f64 {
+        self.to_instant().to_modified_julian_date()
+    }
+}
@@ -0,0 +1,151 @@
+//! The date of Easter, and the feasts that move along with it.
+
+use cal::datetime::{LocalDate, Month, Year};
+
+
+/// The method used to work out the date of Easter, which is known as the
+/// **computus**.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Computus {
+
+    /// The method used by Western churches since 1583, based on the
+    /// Gregorian calendar.
+    Gregorian,
+
+    /// The older method still used by the Eastern Orthodox churches, based
+    /// on the Julian calendar. The dates it gives are converted to the
+    /// Gregorian calendar, so they’re usually later than the Western date.
+    Julian,
+}
+
+
+/// A **movable feast** is a day in the Christian calendar that’s a fixed
+/// number of days before or after Easter Sunday.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum MovableFeast {
+
+    /// The Monday that starts Great Lent in the Orthodox churches.
+    CleanMonday,
+
+    /// The Wednesday that starts Lent in the Western churches.
+    AshWednesday,
+
+    PalmSunday,
+    MaundyThursday,
+    GoodFriday,
+    HolySaturday,
+    EasterSunday,
+    EasterMonday,
+
+    /// Ascension Day, the fortieth day of Easter, counting Easter Sunday
+    /// itself.
+    Ascension,
+
+    /// Pentecost, or Whit Sunday, the seventh Sunday after Easter.
+    Pentecost,
+
+    WhitMonday,
+    TrinitySunday,
+    CorpusChristi,
+}
+
+impl MovableFeast {
+
+    /// Returns the number of days from Easter Sunday to this feast, which
+    /// is negative for feasts before Easter.
+    pub fn days_from_easter(self) -> i64 {
+        use self::MovableFeast::*;
+
+        match self {
+            CleanMonday     => -48,
+            AshWednesday    => -46,
+            PalmSunday      =>  -7,
+            MaundyThursday  =>  -3,
+            GoodFriday      =>  -2,
+            HolySaturday    =>  -1,
+            EasterSunday    =>   0,
+            EasterMonday    =>   1,
+            Ascension       =>  39,
+            Pentecost       =>  49,
+            WhitMonday      =>  50,
+            TrinitySunday   =>  56,
+            CorpusChristi   =>  60,
+        }
+    }
+}
+
+
+impl Year {
+
+    /// Returns the date of Easter Sunday in this year, using the given
+    /// computus.
+    ///
+    /// The Gregorian computus is used for every year, even those before the
+    /// Gregorian calendar was introduced in 1582.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, LocalDate, Month, Computus};
+    ///
+    /// assert_eq!(Year(2017).easter(Computus::Gregorian), LocalDate::ymd(2017, Month::April, 16).unwrap());
+    /// assert_eq!(Year(2021).easter(Computus::Gregorian), LocalDate::ymd(2021, Month::April, 4).unwrap());
+    /// assert_eq!(Year(2021).easter(Computus::Julian), LocalDate::ymd(2021, Month::May, 2).unwrap());
+    /// ```
+    pub fn easter(self, computus: Computus) -> LocalDate {
+        let year = self.0;
+
+        let (month, day, drift) = match computus {
+            Computus::Gregorian => {
+                // The anonymous Gregorian algorithm, from Meeus’s
+                // “Astronomical Algorithms”.
+                let a = year.rem_euclid(19);
+                let (b, c) = (year.div_euclid(100), year.rem_euclid(100));
+                let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+                let f = (b + 8).div_euclid(25);
+                let g = (b - f + 1).div_euclid(3);
+                let h = (19 * a + b - d - g + 15).rem_euclid(30);
+                let (i, k) = (c / 4, c % 4);
+                let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+                let m = (a + 11 * h + 22 * l) / 451;
+                let n = h + l - 7 * m + 114;
+                (n / 31, n % 31 + 1, 0)
+            },
+
+            Computus::Julian => {
+                // Meeus’s Julian algorithm. This gives a date in the Julian
+                // calendar, which gets moved on by the number of days the
+                // two calendars have drifted apart by in the spring of
+                // that year.
+                let a = year.rem_euclid(4);
+                let b = year.rem_euclid(7);
+                let c = year.rem_euclid(19);
+                let d = (19 * c + 15) % 30;
+                let e = (2 * a + 4 * b - d + 34) % 7;
+                let n = d + e + 114;
+                (n / 31, n % 31 + 1, year.div_euclid(100) - year.div_euclid(400) - 2)
+            },
+        };
+
+        // Both algorithms only ever give dates in March or April, which
+        // have the same number of days in every year.
+        let month = Month::from_one(month as i8).unwrap();
+        LocalDate::ymd(year, month, day as i8).unwrap().add_days(drift)
+    }
+
+    /// Returns the date of the given movable feast in this year, using the
+    /// given computus to find Easter.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Year, LocalDate, Month, Computus, MovableFeast};
+    ///
+    /// let year = Year(2017);
+    /// assert_eq!(year.feast(MovableFeast::AshWednesday, Computus::Gregorian), LocalDate::ymd(2017, Month::March, 1).unwrap());
+    /// assert_eq!(year.feast(MovableFeast::Pentecost, Computus::Gregorian), LocalDate::ymd(2017, Month::June, 4).unwrap());
+    /// ```
+    pub fn feast(self, feast: MovableFeast, computus: Computus) -> LocalDate {
+        self.easter(computus).add_days(feast.days_from_easter())
+    }
+}
@@ -0,0 +1,372 @@
+//! Fiscal years, which can start in any month, and retail calendars, which
+//! divide their years into whole weeks.
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Weekday, Year, YearMonth, Quarter, Error};
+
+
+/// A **fiscal period** is one of the twelve periods of a fiscal year,
+/// numbered from 1 in the order they occur.
+///
+/// For a fiscal year that starts in a month other than January, period 1
+/// is the starting month, rather than January. For retail calendars,
+/// periods are made up of whole weeks, so they only roughly line up with
+/// calendar months.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub struct FiscalPeriod {
+    year: i64,
+    period: i8,
+}
+
+impl FiscalPeriod {
+
+    /// Creates a new fiscal period, checking that the period number is
+    /// between 1 and 12.
+    pub fn new(year: i64, period: i8) -> Result<Self, Error> {
+        if (1 ..= 12).contains(&period) {
+            Ok(Self { year, period })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// The fiscal year that this period is in.
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// The number of this period, from 1 to 12.
+    pub fn period(&self) -> i8 {
+        self.period
+    }
+
+    /// Returns the first period of the given quarter of a fiscal year.
+    pub fn first_of_quarter(year: i64, quarter: Quarter) -> Self {
+        Self { year, period: (quarter as i8 - 1) * 3 + 1 }
+    }
+
+    /// Returns the fiscal quarter that this period falls in, with periods
+    /// 1 to 3 in **Quarter 1**, and so on.
+    pub fn quarter(&self) -> Quarter {
+        Quarter::from_one((self.period - 1) / 3 + 1).unwrap()
+    }
+
+    /// Returns the period after this one, continuing into the next fiscal
+    /// year after period 12.
+    pub fn succ(&self) -> Self {
+        if self.period >= 12 { Self { year: self.year + 1, period: 1 } }
+                        else { Self { year: self.year, period: self.period + 1 } }
+    }
+}
+
+
+/// How a fiscal year gets its number, as a fiscal year that doesn’t start
+/// in January spans two calendar years.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum FiscalYearLabel {
+
+    /// The fiscal year is numbered after the calendar year it starts in.
+    StartYear,
+
+    /// The fiscal year is numbered after the calendar year it ends in.
+    /// The United States federal government’s fiscal year 2024, for
+    /// example, runs from October 2023 to September 2024.
+    EndYear,
+}
+
+impl FiscalYearLabel {
+
+    /// Returns the calendar year that a fiscal year with this label
+    /// nominally starts in, given the calendar month it starts in.
+    fn start_year(self, year: i64, start: Month) -> i64 {
+        match self {
+            FiscalYearLabel::EndYear if start != Month::January => year - 1,
+            _ => year,
+        }
+    }
+}
+
+
+/// The **fiscal calendar** trait is used for calendars that split a year
+/// into twelve numbered periods and four quarters, which don’t necessarily
+/// line up with the months and quarters of the Gregorian calendar.
+pub trait FiscalCalendar {
+
+    /// Returns the first day of the given fiscal year.
+    fn year_start(&self, year: i64) -> LocalDate;
+
+    /// Returns the first day of the given fiscal period.
+    fn period_start(&self, period: FiscalPeriod) -> LocalDate;
+
+    /// Returns the fiscal period that the given date falls in.
+    fn period_of(&self, date: LocalDate) -> FiscalPeriod;
+
+    /// Returns the last day of the given fiscal year.
+    fn year_end(&self, year: i64) -> LocalDate {
+        self.year_start(year + 1).add_days(-1)
+    }
+
+    /// Returns the last day of the given fiscal period.
+    fn period_end(&self, period: FiscalPeriod) -> LocalDate {
+        self.period_start(period.succ()).add_days(-1)
+    }
+
+    /// Returns the first day of the given quarter of a fiscal year.
+    fn quarter_start(&self, year: i64, quarter: Quarter) -> LocalDate {
+        self.period_start(FiscalPeriod::first_of_quarter(year, quarter))
+    }
+
+    /// Returns the last day of the given quarter of a fiscal year.
+    fn quarter_end(&self, year: i64, quarter: Quarter) -> LocalDate {
+        let last = FiscalPeriod::first_of_quarter(year, quarter).period + 2;
+        self.period_end(FiscalPeriod { year, period: last })
+    }
+
+    /// Returns the fiscal year that the given date falls in.
+    fn year_of(&self, date: LocalDate) -> i64 {
+        self.period_of(date).year
+    }
+
+    /// Returns the fiscal quarter that the given date falls in.
+    fn quarter_of(&self, date: LocalDate) -> Quarter {
+        self.period_of(date).quarter()
+    }
+}
+
+
+/// A **fiscal year** that starts on the first day of any month, with each
+/// of its periods being a calendar month.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, Quarter, FiscalYear, FiscalYearLabel, FiscalCalendar};
+///
+/// let federal = FiscalYear::new(Month::October, FiscalYearLabel::EndYear);
+/// let date = LocalDate::ymd(2023, Month::November, 15).unwrap();
+/// assert_eq!(federal.year_of(date), 2024);
+/// assert_eq!(federal.quarter_of(date), Quarter::Q1);
+/// assert_eq!(federal.year_start(2024), LocalDate::ymd(2023, Month::October, 1).unwrap());
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct FiscalYear {
+    start: Month,
+    label: FiscalYearLabel,
+}
+
+impl FiscalYear {
+
+    /// Creates a new fiscal year that starts in the given month, and is
+    /// numbered using the given label.
+    pub fn new(start: Month, label: FiscalYearLabel) -> Self {
+        Self { start, label }
+    }
+
+    /// Returns the month that this fiscal year starts in.
+    pub fn start_month(&self) -> Month {
+        self.start
+    }
+
+    /// Returns the calendar month that the given fiscal period covers.
+    pub fn month_of_period(&self, period: FiscalPeriod) -> YearMonth {
+        let start_year = self.label.start_year(period.year, self.start);
+        Year(start_year).month(self.start) + (period.period as i64 - 1)
+    }
+}
+
+impl FiscalCalendar for FiscalYear {
+    fn year_start(&self, year: i64) -> LocalDate {
+        self.period_start(FiscalPeriod { year, period: 1 })
+    }
+
+    fn period_start(&self, period: FiscalPeriod) -> LocalDate {
+        // The first day of a month always exists.
+        self.month_of_period(period).day(1).unwrap()
+    }
+
+    fn period_of(&self, date: LocalDate) -> FiscalPeriod {
+        let month = Year(date.year()).month(date.month());
+        let months = self.month_of_period(FiscalPeriod { year: date.year(), period: 1 }).months_until(month);
+        let (years, period) = (months.div_euclid(12), months.rem_euclid(12));
+        FiscalPeriod { year: date.year() + years, period: period as i8 + 1 }
+    }
+}
+
+
+/// The pattern of weeks in each quarter of a retail calendar.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum RetailPattern {
+
+    /// Four weeks, four weeks, then five weeks.
+    FourFourFive,
+
+    /// Four weeks, five weeks, then four weeks.
+    FourFiveFour,
+
+    /// Five weeks, four weeks, then four weeks.
+    FiveFourFour,
+}
+
+impl RetailPattern {
+
+    /// Returns the number of weeks in each of the three periods of a
+    /// quarter.
+    fn weeks(self) -> [i64; 3] {
+        match self {
+            RetailPattern::FourFourFive => [ 4, 4, 5 ],
+            RetailPattern::FourFiveFour => [ 4, 5, 4 ],
+            RetailPattern::FiveFourFour => [ 5, 4, 4 ],
+        }
+    }
+}
+
+
+/// The rule that decides which day a retail year ends on.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum RetailYearEnd {
+
+    /// The year ends on the last of the given weekday in the given month.
+    /// Apple’s fiscal year, for example, ends on the last Saturday of
+    /// September.
+    Last(Weekday, Month),
+
+    /// The year ends on the given weekday that’s nearest to the end of the
+    /// given month, which may fall in the first few days of the month after.
+    /// The National Retail Federation’s calendar, for example, ends on the
+    /// Saturday nearest the end of January.
+    Nearest(Weekday, Month),
+}
+
+impl RetailYearEnd {
+
+    /// Returns the month that the year nominally ends in.
+    fn month(self) -> Month {
+        match self {
+            RetailYearEnd::Last(_, month) | RetailYearEnd::Nearest(_, month) => month,
+        }
+    }
+
+    /// Returns the day that the year ending in the given calendar year
+    /// ends on.
+    fn date(self, year: i64) -> LocalDate {
+        let (weekday, ym) = match self {
+            RetailYearEnd::Last(weekday, month) | RetailYearEnd::Nearest(weekday, month) => (weekday, Year(year).month(month)),
+        };
+
+        let month_end = ym.day(ym.day_count()).unwrap();
+        let back = (month_end.weekday() as i64 - weekday as i64 + 7) % 7;
+
+        match self {
+            RetailYearEnd::Nearest(..) if back > 3 => month_end.add_days(7 - back),
+            _                                      => month_end.add_days(-back),
+        }
+    }
+}
+
+
+/// A **retail calendar**, also known as a 52/53-week calendar, which
+/// divides its years into whole weeks so that each one starts and ends on
+/// the same weekday.
+///
+/// Each quarter has thirteen weeks, split up into three periods using a
+/// 4-4-5, 4-5-4, or 5-4-4 pattern. As 52 weeks are one or two days short
+/// of a year, every five or six years has a 53rd week, which gets added to
+/// the last period of the year.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, Weekday, FiscalCalendar};
+/// use datetime::{RetailCalendar, RetailPattern, RetailYearEnd, FiscalYearLabel};
+///
+/// let nrf = RetailCalendar::new(RetailPattern::FourFiveFour,
+///                               RetailYearEnd::Nearest(Weekday::Saturday, Month::January),
+///                               FiscalYearLabel::StartYear);
+///
+/// assert_eq!(nrf.year_start(2023), LocalDate::ymd(2023, Month::January, 29).unwrap());
+/// assert_eq!(nrf.year_end(2023), LocalDate::ymd(2024, Month::February, 3).unwrap());
+/// assert_eq!(nrf.weeks_in_year(2023), 53);
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct RetailCalendar {
+    pattern: RetailPattern,
+    end: RetailYearEnd,
+    label: FiscalYearLabel,
+}
+
+impl RetailCalendar {
+
+    /// Creates a new retail calendar with the given pattern of weeks,
+    /// ending on the given day, and numbered using the given label.
+    pub fn new(pattern: RetailPattern, end: RetailYearEnd, label: FiscalYearLabel) -> Self {
+        Self { pattern, end, label }
+    }
+
+    /// Returns the number of weeks in the given retail year, which is
+    /// either 52 or 53.
+    pub fn weeks_in_year(&self, year: i64) -> i8 {
+        (self.year_start(year).days_until(self.year_start(year + 1)) / 7) as i8
+    }
+
+    /// Returns the number of the week of the retail year that the given
+    /// date falls in, from 1 to 53.
+    pub fn week_of(&self, date: LocalDate) -> i8 {
+        let year = self.year_of(date);
+        (self.year_start(year).days_until(date) / 7 + 1) as i8
+    }
+
+    /// Returns the number of weeks in each of the twelve periods of the
+    /// given retail year.
+    fn period_weeks(&self, year: i64) -> [i64; 12] {
+        let mut weeks = [0; 12];
+        for (index, week) in weeks.iter_mut().enumerate() {
+            *week = self.pattern.weeks()[index % 3];
+        }
+
+        weeks[11] += self.weeks_in_year(year) as i64 - 52;
+        weeks
+    }
+
+    /// Returns the calendar year of the month that the given retail year
+    /// nominally ends in.
+    fn end_year(&self, year: i64) -> i64 {
+        let start = (Year(0).month(self.end.month()) + 1).month;
+        let start_year = self.label.start_year(year, start);
+        (Year(start_year).month(start) + 11).year.0
+    }
+}
+
+impl FiscalCalendar for RetailCalendar {
+    fn year_start(&self, year: i64) -> LocalDate {
+        self.end.date(self.end_year(year) - 1).add_days(1)
+    }
+
+    fn period_start(&self, period: FiscalPeriod) -> LocalDate {
+        let weeks: i64 = self.period_weeks(period.year)[.. period.period as usize - 1].iter().sum();
+        self.year_start(period.year).add_weeks(weeks)
+    }
+
+    fn period_of(&self, date: LocalDate) -> FiscalPeriod {
+        // A retail year is never more than a few days away from the year
+        // numbered after it, so it’s either that year or a neighbour.
+        let mut year = date.year();
+        while date < self.year_start(year)     { year -= 1; }
+        while date >= self.year_start(year + 1) { year += 1; }
+
+        // Any weeks left over after the first eleven periods are in the
+        // last one, which is the one that gets the 53rd week.
+        let mut weeks = self.year_start(year).days_until(date) / 7;
+        let mut period = 1;
+        for &count in &self.period_weeks(year)[.. 11] {
+            if weeks < count {
+                break;
+            }
+
+            weeks -= count;
+            period += 1;
+        }
+
+        FiscalPeriod { year, period }
+    }
+}
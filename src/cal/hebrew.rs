@@ -0,0 +1,277 @@
+//! Dates in the Hebrew calendar.
+
+use std::fmt;
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Weekday, Error};
+
+
+/// The Rata Die day number of **1 Tishrei, AM 1**, which was the 7th of
+/// October 3761 BC in the proleptic Julian calendar.
+const HEBREW_EPOCH: i64 = -1_373_427;
+
+/// The months of a common year, in the order they fall from Rosh Hashanah.
+const COMMON_YEAR_MONTHS: [HebrewMonth; 12] = [
+    HebrewMonth::Tishrei, HebrewMonth::Cheshvan, HebrewMonth::Kislev,
+    HebrewMonth::Tevet,   HebrewMonth::Shevat,   HebrewMonth::Adar,
+    HebrewMonth::Nisan,   HebrewMonth::Iyar,     HebrewMonth::Sivan,
+    HebrewMonth::Tammuz,  HebrewMonth::Av,       HebrewMonth::Elul,
+];
+
+/// The months of a leap year, in the order they fall from Rosh Hashanah.
+const LEAP_YEAR_MONTHS: [HebrewMonth; 13] = [
+    HebrewMonth::Tishrei, HebrewMonth::Cheshvan, HebrewMonth::Kislev,
+    HebrewMonth::Tevet,   HebrewMonth::Shevat,   HebrewMonth::AdarI,
+    HebrewMonth::AdarII,  HebrewMonth::Nisan,    HebrewMonth::Iyar,
+    HebrewMonth::Sivan,   HebrewMonth::Tammuz,   HebrewMonth::Av,
+    HebrewMonth::Elul,
+];
+
+
+/// A month of the Hebrew calendar.
+///
+/// Leap years have an extra month: instead of **Adar**, they have
+/// **Adar I** followed by **Adar II**. Each month only exists in its own
+/// kind of year, so Adar can’t be used in a leap year, and neither Adar I
+/// nor Adar II can be used in a common year.
+///
+/// The months are ordered the way they fall in the year, starting with
+/// Tishrei.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub enum HebrewMonth {
+    Tishrei, Cheshvan, Kislev, Tevet, Shevat,
+    Adar, AdarI, AdarII,
+    Nisan, Iyar, Sivan, Tammuz, Av, Elul,
+}
+
+impl HebrewMonth {
+
+    /// Returns the months of the given year, in order, starting with
+    /// Tishrei.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::HebrewMonth;
+    ///
+    /// assert_eq!(HebrewMonth::months_of_year(5784).len(), 13);
+    /// assert_eq!(HebrewMonth::months_of_year(5785).len(), 12);
+    /// ```
+    pub fn months_of_year(year: i64) -> &'static [Self] {
+        if HebrewDate::is_leap_year(year) {
+            &LEAP_YEAR_MONTHS
+        }
+        else {
+            &COMMON_YEAR_MONTHS
+        }
+    }
+
+    /// Returns the number of days in this month in the given year, or
+    /// `None` if the month isn’t part of that year.
+    ///
+    /// Cheshvan and Kislev change length from year to year, as the start
+    /// of the following year gets postponed.
+    pub fn days_in_month(self, year: i64) -> Option<i8> {
+        use self::HebrewMonth::*;
+
+        let leap_year = HebrewDate::is_leap_year(year);
+        match self {
+            Adar           if leap_year  => None,
+            AdarI | AdarII if !leap_year => None,
+            Cheshvan  => Some(if HebrewDate::days_in_year(year) % 10 == 5 { 30 } else { 29 }),
+            Kislev    => Some(if HebrewDate::days_in_year(year) % 10 == 3 { 29 } else { 30 }),
+            Tishrei | Shevat | AdarI | Nisan | Sivan | Av  => Some(30),
+            Tevet | Adar | AdarII | Iyar | Tammuz | Elul   => Some(29),
+        }
+    }
+
+    /// Returns the English name of this month.
+    pub fn name(self) -> &'static str {
+        use self::HebrewMonth::*;
+
+        match self {
+            Tishrei => "Tishrei",  Cheshvan => "Cheshvan",  Kislev => "Kislev",
+            Tevet   => "Tevet",    Shevat   => "Shevat",    Adar   => "Adar",
+            AdarI   => "Adar I",   AdarII   => "Adar II",   Nisan  => "Nisan",
+            Iyar    => "Iyar",     Sivan    => "Sivan",     Tammuz => "Tammuz",
+            Av      => "Av",       Elul     => "Elul",
+        }
+    }
+}
+
+impl fmt::Display for HebrewMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+
+/// A **Hebrew date** is a date in the Hebrew calendar, with years counted
+/// *Anno Mundi* from 1 Tishrei, AM 1.
+///
+/// Hebrew days begin at sunset, so this type is the date of the daylight
+/// hours: 1 Tishrei 5784 begins on the evening of the 15th of September
+/// 2023, and is converted to the 16th.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{HebrewDate, HebrewMonth, LocalDate, Month};
+///
+/// let purim = HebrewDate::ymd(5784, HebrewMonth::AdarII, 14).unwrap();
+/// assert_eq!(purim.to_local_date(), LocalDate::ymd(2024, Month::March, 24).unwrap());
+/// assert_eq!(purim.to_string(), "14 Adar II 5784");
+///
+/// let date = HebrewDate::from_local_date(LocalDate::ymd(2024, Month::October, 3).unwrap());
+/// assert_eq!(date, HebrewDate::ymd(5785, HebrewMonth::Tishrei, 1).unwrap());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub struct HebrewDate {
+    year: i64,
+    month: HebrewMonth,
+    day: i8,
+}
+
+impl HebrewDate {
+
+    /// Creates a new Hebrew date from the given year, month, and day.
+    ///
+    /// The values are checked for validity before instantiation, and
+    /// passing in values out of range, including a month that isn’t part
+    /// of the year, will return an error.
+    pub fn ymd(year: i64, month: HebrewMonth, day: i8) -> Result<Self, Error> {
+        match month.days_in_month(year) {
+            Some(days) if (1 ..= days).contains(&day) => Ok(Self { year, month, day }),
+            _ => Err(Error::OutOfRange),
+        }
+    }
+
+    /// Returns the Hebrew date that falls on the same day as the given
+    /// Gregorian date.
+    pub fn from_local_date(date: LocalDate) -> Self {
+        let rata_die = date.to_rata_die();
+
+        // The mean year is 35,975,351 / 98,496 days long, which gives a
+        // guess that’s at most one year out either way.
+        let mut year = (98_496 * (rata_die - HEBREW_EPOCH)).div_euclid(35_975_351);
+        while new_year(year + 1) <= rata_die {
+            year += 1;
+        }
+
+        let mut days = rata_die - new_year(year);
+        for &month in HebrewMonth::months_of_year(year) {
+            let length = i64::from(month.days_in_month(year).unwrap());
+            if days < length {
+                return Self { year, month, day: days as i8 + 1 };
+            }
+
+            days -= length;
+        }
+
+        unreachable!("day {} is past the end of year {}", rata_die, year)
+    }
+
+    /// Returns the Gregorian date that falls on the same day as this date.
+    pub fn to_local_date(&self) -> LocalDate {
+        let days_before_month: i64 = HebrewMonth::months_of_year(self.year).iter()
+            .take_while(|&&month| month != self.month)
+            .map(|month| i64::from(month.days_in_month(self.year).unwrap()))
+            .sum();
+
+        LocalDate::from_rata_die(new_year(self.year) + days_before_month + i64::from(self.day) - 1)
+    }
+
+    /// Returns whether the given year is a leap year, with thirteen
+    /// months. Seven years in every nineteen are leap years.
+    pub fn is_leap_year(year: i64) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    /// Returns the number of days in the given year, which is 353, 354,
+    /// or 355 for a common year, and 383, 384, or 385 for a leap year.
+    pub fn days_in_year(year: i64) -> i64 {
+        new_year(year + 1) - new_year(year)
+    }
+
+    /// The year, counted from the creation of the world.
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// The month of the year.
+    pub fn month(&self) -> HebrewMonth {
+        self.month
+    }
+
+    /// The day of the month, from 1 to 30.
+    pub fn day(&self) -> i8 {
+        self.day
+    }
+
+    /// The day of the week, which is the same as the Gregorian date’s.
+    pub fn weekday(&self) -> Weekday {
+        self.to_local_date().weekday()
+    }
+}
+
+impl From<LocalDate> for HebrewDate {
+    fn from(date: LocalDate) -> Self {
+        Self::from_local_date(date)
+    }
+}
+
+impl From<HebrewDate> for LocalDate {
+    fn from(date: HebrewDate) -> Self {
+        date.to_local_date()
+    }
+}
+
+impl fmt::Debug for HebrewDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HebrewDate({})", self)
+    }
+}
+
+impl fmt::Display for HebrewDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {}", self.day, self.month, self.year)
+    }
+}
+
+
+/// Returns the number of days from the epoch to the molad of Tishrei of
+/// the given year, delayed if the molad falls on a Sunday, Wednesday, or
+/// Friday so that Yom Kippur never falls next to the Sabbath and
+/// Hoshana Rabbah never falls on it.
+fn elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+
+    // Months and days are counted in parts, of which there are 1,080 in
+    // an hour. The first molad was 5 hours and 204 parts into the day,
+    // and the extra 6 hours push any molad from noon onwards into the
+    // next day.
+    let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25_920);
+
+    if (3 * (days + 1)).rem_euclid(7) < 3 { days + 1 } else { days }
+}
+
+/// Returns the Rata Die day number of Rosh Hashanah of the given year,
+/// applying the remaining postponements that stop a year from being too
+/// long or too short.
+fn new_year(year: i64) -> i64 {
+    let this_year = elapsed_days(year);
+    let next_year = elapsed_days(year + 1);
+
+    let correction = if next_year - this_year == 356 {
+        2
+    }
+    else if this_year - elapsed_days(year - 1) == 382 {
+        1
+    }
+    else {
+        0
+    };
+
+    HEBREW_EPOCH + this_year + correction
+}
@@ -0,0 +1,347 @@
+//! Time intervals and repeating intervals, as written in ISO 8601.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime};
+use cal::fmt::iso::ISO;
+use cal::offset::OffsetDateTime;
+use cal::period::{Period, Error as PeriodError};
+use duration::Duration;
+use instant::Instant;
+use interval::Interval;
+
+
+/// One end of an ISO 8601 interval, which can be a date, a datetime, or a
+/// datetime with a UTC offset.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum IntervalPoint {
+    Date(LocalDate),
+    DateTime(LocalDateTime),
+    Offset(OffsetDateTime),
+}
+
+impl IntervalPoint {
+
+    /// Returns the instant this point refers to, which is only known if it
+    /// has a UTC offset.
+    pub fn to_instant(&self) -> Option<Instant> {
+        match *self {
+            IntervalPoint::Offset(odt) => {
+                let offset = odt.offset.hours() as i64 * 60 * 60 + odt.offset.minutes() as i64 * 60 + odt.offset.seconds() as i64;
+                Some(odt.local.to_instant() - Duration::of(offset))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the local datetime of this point, using midnight for a
+    /// date, and the local time as written for a datetime with an offset.
+    fn local(&self) -> LocalDateTime {
+        match *self {
+            IntervalPoint::Date(date)      => LocalDateTime::new(date, LocalTime::midnight()),
+            IntervalPoint::DateTime(ldt)   => ldt,
+            IntervalPoint::Offset(odt)     => odt.local,
+        }
+    }
+
+    /// Returns a point of the same kind as this one at the given local
+    /// datetime. A date only stays a date if the period involved has no
+    /// hours, minutes, or seconds.
+    fn with_local(&self, local: LocalDateTime, period: Period) -> Self {
+        match *self {
+            IntervalPoint::Date(_) if !period.has_time()  => IntervalPoint::Date(local.date()),
+            IntervalPoint::Offset(odt)                    => IntervalPoint::Offset(OffsetDateTime { local, ..odt }),
+            _                                             => IntervalPoint::DateTime(local),
+        }
+    }
+}
+
+impl Add<Period> for IntervalPoint {
+    type Output = Self;
+
+    fn add(self, period: Period) -> Self {
+        self.with_local(self.local() + period, period)
+    }
+}
+
+impl Sub<Period> for IntervalPoint {
+    type Output = Self;
+
+    fn sub(self, period: Period) -> Self {
+        self.with_local(self.local() - period, period)
+    }
+}
+
+impl fmt::Display for IntervalPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IntervalPoint::Date(date)     => write!(f, "{}", date.iso()),
+            IntervalPoint::DateTime(ldt)  => write!(f, "{}", ldt.iso()),
+            IntervalPoint::Offset(odt)    => write!(f, "{}", odt.iso()),
+        }
+    }
+}
+
+impl FromStr for IntervalPoint {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let point = match input.split_once('T') {
+            None                                          => input.parse().map(IntervalPoint::Date).ok(),
+            Some((_, time)) if offset_of(time).is_some()  => input.parse().map(IntervalPoint::Offset).ok(),
+            Some(_)                                       => input.parse().map(IntervalPoint::DateTime).ok(),
+        };
+
+        point.ok_or_else(|| Error::InvalidPoint(input.into()))
+    }
+}
+
+
+/// An **ISO 8601 interval** is a span of time written in one of four
+/// forms: a start and an end, a start and a duration, a duration and an
+/// end, or just a duration with no context.
+///
+/// When parsing, the end can leave out any leading date fields and its
+/// UTC offset, which are then taken from the start, so
+/// `2024-01-01/05` ends on the 5th of January and
+/// `2024-01-01T09:00Z/17:00` ends at 5pm UTC. Intervals are always
+/// formatted in full.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{IsoInterval, IntervalPoint, LocalDate, Month};
+///
+/// let interval: IsoInterval = "2024-01-01/P1W".parse().unwrap();
+/// let end = IntervalPoint::Date(LocalDate::ymd(2024, Month::January, 8).unwrap());
+/// assert_eq!(interval.end(), Some(end));
+/// assert_eq!(interval.to_string(), "2024-01-01/P1W");
+/// ```
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum IsoInterval {
+    StartEnd(IntervalPoint, IntervalPoint),
+    StartDuration(IntervalPoint, Period),
+    DurationEnd(Period, IntervalPoint),
+    Duration(Period),
+}
+
+impl IsoInterval {
+
+    /// Returns the start of this interval, working it out from the end and
+    /// the duration if necessary. An interval that’s only a duration has
+    /// no start.
+    pub fn start(&self) -> Option<IntervalPoint> {
+        match *self {
+            IsoInterval::StartEnd(start, _)         => Some(start),
+            IsoInterval::StartDuration(start, _)    => Some(start),
+            IsoInterval::DurationEnd(period, end)   => Some(end - period),
+            IsoInterval::Duration(_)                => None,
+        }
+    }
+
+    /// Returns the end of this interval, working it out from the start and
+    /// the duration if necessary. An interval that’s only a duration has
+    /// no end.
+    pub fn end(&self) -> Option<IntervalPoint> {
+        match *self {
+            IsoInterval::StartEnd(_, end)             => Some(end),
+            IsoInterval::StartDuration(start, period) => Some(start + period),
+            IsoInterval::DurationEnd(_, end)          => Some(end),
+            IsoInterval::Duration(_)                  => None,
+        }
+    }
+
+    /// Returns the interval of instants that this interval covers, which
+    /// is only known if both ends have UTC offsets, and the end isn’t
+    /// before the start.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{IsoInterval, Instant, Duration};
+    ///
+    /// let interval: IsoInterval = "2017-08-21T16:00+01:00/PT2H".parse().unwrap();
+    /// let instants = interval.to_instants().unwrap();
+    /// assert_eq!(instants.start(), Instant::at(1503327600));
+    /// assert_eq!(instants.duration(), Duration::of(2 * 60 * 60));
+    /// ```
+    pub fn to_instants(&self) -> Option<Interval<Instant>> {
+        let start = self.start()?.to_instant()?;
+        let end = self.end()?.to_instant()?;
+        Interval::new(start, end).ok()
+    }
+}
+
+impl fmt::Display for IsoInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IsoInterval::StartEnd(start, end)           => write!(f, "{}/{}", start, end),
+            IsoInterval::StartDuration(start, period)   => write!(f, "{}/{}", start, period),
+            IsoInterval::DurationEnd(period, end)       => write!(f, "{}/{}", period, end),
+            IsoInterval::Duration(period)               => write!(f, "{}", period),
+        }
+    }
+}
+
+impl FromStr for IsoInterval {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // ISO 8601 allows a double hyphen in place of the solidus, for
+        // places where a slash can’t be used, such as file names.
+        let parts = input.split_once('/').or_else(|| input.split_once("--"));
+
+        let (first, second) = match parts {
+            Some(parts)                      => parts,
+            None if input.starts_with('P')   => return Ok(IsoInterval::Duration(input.parse()?)),
+            None                             => return Err(Error::InvalidInterval(input.into())),
+        };
+
+        match (first.starts_with('P'), second.starts_with('P')) {
+            (false, false)  => Ok(IsoInterval::StartEnd(first.parse()?, complete_end(first, second).parse()?)),
+            (false, true)   => Ok(IsoInterval::StartDuration(first.parse()?, second.parse()?)),
+            (true, false)   => Ok(IsoInterval::DurationEnd(first.parse()?, second.parse()?)),
+            (true, true)    => Err(Error::InvalidInterval(input.into())),
+        }
+    }
+}
+
+/// Fills in the parts of a reduced end point that are left out, using the
+/// start point: leading date fields in the extended calendar date format,
+/// and the UTC offset.
+fn complete_end(start: &str, end: &str) -> String {
+    let (start_date, start_time) = split_time(start);
+    let (end_date, end_time) = if end.contains('T') { split_time(end) }
+                          else if end.contains(':') { ("", Some(end)) }
+                                               else { (end, None) };
+
+    let start_fields: Vec<&str> = start_date.split('-').collect();
+    let end_fields: Vec<&str> = end_date.split('-').filter(|f| !f.is_empty()).collect();
+    let is_digits = |field: &&str| !field.is_empty() && field.bytes().all(|b| b.is_ascii_digit());
+
+    let mut date = end_date.to_owned();
+    if start_fields.len() == 3 && start_fields.iter().all(is_digits) && end_fields.len() < 3
+    && end_fields.iter().all(|f| f.len() == 2 && is_digits(f)) {
+        let mut fields = start_fields[.. 3 - end_fields.len()].to_vec();
+        fields.extend(end_fields);
+        date = fields.join("-");
+    }
+
+    match (end_time, start_time.and_then(offset_of)) {
+        (Some(time), Some(offset)) if offset_of(time).is_none()  => format!("{}T{}{}", date, time, offset),
+        (Some(time), _)                                          => format!("{}T{}", date, time),
+        (None, _)                                                => date,
+    }
+}
+
+/// Splits a point into its date and, if it has one, its time.
+fn split_time(point: &str) -> (&str, Option<&str>) {
+    match point.split_once('T') {
+        Some((date, time))  => (date, Some(time)),
+        None                => (point, None),
+    }
+}
+
+/// Returns the UTC offset at the end of a time, if it has one.
+fn offset_of(time: &str) -> Option<&str> {
+    time.find(&[ 'Z', '+', '-' ][..]).map(|index| &time[index ..])
+}
+
+
+/// A **repeating interval** is an ISO 8601 interval that happens a number
+/// of times, or forever, such as `R5/2024-01-01T00:00Z/P1D`.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{RepeatingInterval, IsoInterval};
+///
+/// let repeating: RepeatingInterval = "R5/2024-01-01/P1D".parse().unwrap();
+/// assert_eq!(repeating.repetitions, Some(5));
+/// assert_eq!(repeating.to_string(), "R5/2024-01-01/P1D");
+///
+/// let forever: RepeatingInterval = "R/P1W".parse().unwrap();
+/// assert_eq!(forever.repetitions, None);
+/// ```
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct RepeatingInterval {
+
+    /// The number of times the interval happens, or `None` if it repeats
+    /// forever.
+    pub repetitions: Option<u64>,
+
+    /// The interval that gets repeated.
+    pub interval: IsoInterval,
+}
+
+impl fmt::Display for RepeatingInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.repetitions {
+            Some(count)  => write!(f, "R{}/{}", count, self.interval),
+            None         => write!(f, "R/{}", self.interval),
+        }
+    }
+}
+
+impl FromStr for RepeatingInterval {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (count, interval) = input.strip_prefix('R')
+            .and_then(|rest| rest.split_once('/'))
+            .ok_or_else(|| Error::InvalidInterval(input.into()))?;
+
+        // ISO 8601-1:2019 also writes unbounded repetition as “R-1”.
+        let repetitions = match count {
+            "" | "-1"  => None,
+            _ if count.bytes().all(|b| b.is_ascii_digit())  => Some(count.parse().map_err(|_| Error::InvalidInterval(input.into()))?),
+            _          => return Err(Error::InvalidInterval(input.into())),
+        };
+
+        Ok(Self { repetitions, interval: interval.parse()? })
+    }
+}
+
+
+/// An error that can occur when parsing an ISO 8601 interval.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+
+    /// The text wasn’t split into a valid set of parts, such as when it
+    /// has two durations.
+    InvalidInterval(String),
+
+    /// The start or end wasn’t a valid date or datetime.
+    InvalidPoint(String),
+
+    /// The duration wasn’t valid.
+    Period(PeriodError),
+}
+
+impl From<PeriodError> for Error {
+    fn from(error: PeriodError) -> Self {
+        Error::Period(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidInterval(ref text)  => write!(f, "invalid interval ‘{}’", text),
+            Error::InvalidPoint(ref text)     => write!(f, "invalid date or datetime ‘{}’", text),
+            Error::Period(ref error)          => write!(f, "{}", error),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+    fn cause(&self) -> Option<&dyn ErrorTrait> {
+        match *self {
+            Error::Period(ref error)  => Some(error),
+            _                         => None,
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Dates in the Julian calendar, and historical dates that switch over to
+//! the Gregorian calendar at some point.
+
+use std::fmt;
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Weekday, Error};
+
+
+/// A **Julian date** is a date in the Julian calendar, which has a leap
+/// year every four years without exception. It was used across Europe
+/// until the Gregorian calendar replaced it, and this type extends it
+/// *proleptically* to every year before and after.
+///
+/// As with `LocalDate`, years are numbered astronomically, so the year
+/// before 1 AD is the year 0, and the year before that is -1.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{JulianDate, LocalDate, Month};
+///
+/// let julian = JulianDate::ymd(1582, Month::October, 4).unwrap();
+/// assert_eq!(julian.to_local_date(), LocalDate::ymd(1582, Month::October, 14).unwrap());
+///
+/// let gregorian = LocalDate::ymd(2017, Month::August, 21).unwrap();
+/// assert_eq!(JulianDate::from_local_date(gregorian), JulianDate::ymd(2017, Month::August, 8).unwrap());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub struct JulianDate {
+    year: i64,
+    month: Month,
+    day: i8,
+}
+
+impl JulianDate {
+
+    /// Creates a new Julian date from the given year, month, and day.
+    ///
+    /// The values are checked for validity before instantiation, and
+    /// passing in values out of range will return an error.
+    pub fn ymd(year: i64, month: Month, day: i8) -> Result<Self, Error> {
+        if (1 ..= month.days_in_month(is_julian_leap_year(year))).contains(&day) {
+            Ok(Self { year, month, day })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns the Julian date that falls on the same day as the given
+    /// Gregorian date.
+    pub fn from_local_date(date: LocalDate) -> Self {
+        // These are the Julian Day Number formulas from Richards’s
+        // “Mapping Time”, using floored division so they work for years
+        // before the start of the Julian period.
+        let c = date.to_julian_day_number() + 32082;
+        let d = (4 * c + 3).div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2) / 153;
+
+        let day = e - (153 * m + 2) / 5 + 1;
+        let month = m + 3 - 12 * (m / 10);
+        let year = d - 4800 + m / 10;
+
+        // The month number is always between 1 and 12.
+        Self { year, month: Month::from_one(month as i8).unwrap(), day: day as i8 }
+    }
+
+    /// Returns the Gregorian date that falls on the same day as this date.
+    pub fn to_local_date(&self) -> LocalDate {
+        let month = self.month as i64;
+        let a = (14 - month) / 12;
+        let y = self.year + 4800 - a;
+        let m = month + 12 * a - 3;
+
+        let days = self.day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+        LocalDate::from_julian_day_number(days)
+    }
+
+    /// The year, numbered astronomically.
+    pub fn year(&self) -> i64 {
+        self.year
+    }
+
+    /// The month of the year.
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    /// The day of the month, from 1 to 31.
+    pub fn day(&self) -> i8 {
+        self.day
+    }
+
+    /// The day of the week, which is the same in both calendars.
+    pub fn weekday(&self) -> Weekday {
+        self.to_local_date().weekday()
+    }
+}
+
+impl From<LocalDate> for JulianDate {
+    fn from(date: LocalDate) -> Self {
+        Self::from_local_date(date)
+    }
+}
+
+impl From<JulianDate> for LocalDate {
+    fn from(date: JulianDate) -> Self {
+        date.to_local_date()
+    }
+}
+
+impl fmt::Debug for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JulianDate({:04}-{:02}-{:02})", self.year, self.month as usize, self.day)
+    }
+}
+
+/// Returns whether the given year is a leap year in the Julian calendar.
+fn is_julian_leap_year(year: i64) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+
+/// A **cutover** is the day that a country switched from the Julian
+/// calendar to the Gregorian calendar, dropping the days in between.
+///
+/// Countries switched at different times, with the Papal States going first
+/// in October 1582, and Great Britain and its colonies following in
+/// September 1752.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Cutover {
+    first_gregorian_day: LocalDate,
+}
+
+impl Cutover {
+
+    /// Creates a cutover where the given date is the first day of the
+    /// Gregorian calendar, with every day before it in the Julian calendar.
+    pub fn new(first_gregorian_day: LocalDate) -> Self {
+        Self { first_gregorian_day }
+    }
+
+    /// The original cutover in Rome, where Thursday the 4th of October 1582
+    /// was followed by Friday the 15th.
+    pub fn rome() -> Self {
+        Self::new(LocalDate::ymd(1582, Month::October, 15).unwrap())
+    }
+
+    /// The cutover in Great Britain and its colonies, where Wednesday the
+    /// 2nd of September 1752 was followed by Thursday the 14th.
+    ///
+    /// Before 1752, the year there started on the 25th of March, rather
+    /// than the 1st of January. Historical dates don’t account for this, so
+    /// they number the years as though they began in January.
+    pub fn great_britain() -> Self {
+        Self::new(LocalDate::ymd(1752, Month::September, 14).unwrap())
+    }
+
+    /// Returns the first day of the Gregorian calendar.
+    pub fn first_gregorian_day(&self) -> LocalDate {
+        self.first_gregorian_day
+    }
+
+    /// Returns the last day of the Julian calendar, as a Julian date.
+    pub fn last_julian_day(&self) -> JulianDate {
+        JulianDate::from_local_date(self.first_gregorian_day.add_days(-1))
+    }
+}
+
+impl Default for Cutover {
+    fn default() -> Self {
+        Self::rome()
+    }
+}
+
+
+/// A **historical date** is a date in whichever calendar was in use on
+/// that day: the Julian calendar before the cutover, and the Gregorian
+/// calendar from then on.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{HistoricalDate, Cutover, LocalDate, Month};
+///
+/// // Isaac Newton was born on Christmas Day 1642 in England, which was
+/// // still using the Julian calendar.
+/// let birth = HistoricalDate::ymd(1642, Month::December, 25, Cutover::great_britain()).unwrap();
+/// assert!(birth.is_julian());
+/// assert_eq!(birth.to_local_date(), LocalDate::ymd(1643, Month::January, 4).unwrap());
+///
+/// // The same day, recorded in Rome.
+/// let rome = HistoricalDate::from_local_date(birth.to_local_date(), Cutover::rome());
+/// assert_eq!((rome.year(), rome.month(), rome.day()), (1643, Month::January, 4));
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct HistoricalDate {
+    date: LocalDate,
+    cutover: Cutover,
+}
+
+impl HistoricalDate {
+
+    /// Creates a new historical date from the given year, month, and day,
+    /// which are read as a Julian date if they’re before the cutover, and
+    /// as a Gregorian date otherwise.
+    ///
+    /// This returns an error if the date doesn’t exist in that calendar, or
+    /// if it falls in the days that were skipped at the cutover.
+    pub fn ymd(year: i64, month: Month, day: i8, cutover: Cutover) -> Result<Self, Error> {
+        let first = cutover.first_gregorian_day;
+
+        let date = if (year, month, day) >= (first.year(), first.month(), first.day()) {
+            LocalDate::ymd(year, month, day)?
+        }
+        else {
+            let date = JulianDate::ymd(year, month, day)?.to_local_date();
+            if date >= first {
+                return Err(Error::OutOfRange);
+            }
+
+            date
+        };
+
+        Ok(Self { date, cutover })
+    }
+
+    /// Returns the historical date that falls on the same day as the given
+    /// Gregorian date.
+    pub fn from_local_date(date: LocalDate, cutover: Cutover) -> Self {
+        Self { date, cutover }
+    }
+
+    /// Returns the proleptic Gregorian date that falls on the same day as
+    /// this date.
+    pub fn to_local_date(&self) -> LocalDate {
+        self.date
+    }
+
+    /// Returns the cutover that decides which calendar this date is in.
+    pub fn cutover(&self) -> Cutover {
+        self.cutover
+    }
+
+    /// Returns whether this date is in the Julian calendar, because it’s
+    /// before the cutover.
+    pub fn is_julian(&self) -> bool {
+        self.date < self.cutover.first_gregorian_day
+    }
+
+    /// Returns the year, month, and day of this date in the calendar in
+    /// use at the time.
+    fn ymd_fields(&self) -> (i64, Month, i8) {
+        if self.is_julian() {
+            let julian = JulianDate::from_local_date(self.date);
+            (julian.year, julian.month, julian.day)
+        }
+        else {
+            (self.date.year(), self.date.month(), self.date.day())
+        }
+    }
+
+    /// The year, in the calendar in use at the time.
+    pub fn year(&self) -> i64 {
+        self.ymd_fields().0
+    }
+
+    /// The month of the year, in the calendar in use at the time.
+    pub fn month(&self) -> Month {
+        self.ymd_fields().1
+    }
+
+    /// The day of the month, in the calendar in use at the time.
+    pub fn day(&self) -> i8 {
+        self.ymd_fields().2
+    }
+
+    /// The day of the week, which is the same in both calendars.
+    pub fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+
+    /// Returns the date the given number of days after this one, or
+    /// *before* it if the number is negative, moving across the cutover if
+    /// necessary.
+    pub fn add_days(&self, days: i64) -> Self {
+        Self { date: self.date.add_days(days), cutover: self.cutover }
+    }
+}
@@ -1,14 +1,27 @@
 //! ISO-8601 date and time calculations, which use years, months, days,
 //! hours, minutes, and seconds.
 
+pub(crate) mod age;
+pub(crate) mod business;
 pub(crate) mod datetime;
+pub(crate) mod day_number;
+pub(crate) mod easter;
+pub(crate) mod fiscal;
+pub(crate) mod hebrew;
 pub(crate) mod fmt;
+#[cfg(feature="parse")] pub(crate) mod iso_interval;
+pub(crate) mod julian;
 pub(crate) mod offset;
 #[cfg(feature="parse")] pub(crate) mod parse;
+pub(crate) mod period;
+pub(crate) mod range;
+pub(crate) mod spreadsheet;
+pub(crate) mod unit;
+pub(crate) mod week;
 pub mod zone;
 pub mod convenience;
 
-use self::datetime::{LocalDate, LocalTime, LocalDateTime, Weekday, Month};
+use self::datetime::{LocalDate, LocalTime, LocalDateTime, Weekday, Month, Quarter, iso_week_date};
 use self::offset::{Offset, OffsetDateTime};
 
 
@@ -42,6 +55,27 @@ pub trait DatePiece {
     /// rather than the year 0 (well, 1 BCE).
     fn years_from_2000(&self) -> i64 { self.year() - 2000 }
 
+    /// The quarter of the year, based on the month.
+    fn quarter(&self) -> Quarter { self.month().quarter() }
+
+    /// The ISO-8601 week number, from 1 to 53.
+    ///
+    /// Weeks start on a Monday, and week 1 is the week containing the first
+    /// Thursday of the year, so the first few days of January can fall in
+    /// the last week of the previous year, and the last few days of
+    /// December can fall in week 1 of the next.
+    fn iso_week(&self) -> i8 {
+        iso_week_date(self.year(), self.yearday(), self.weekday()).1
+    }
+
+    /// The ISO-8601 week-numbering year.
+    ///
+    /// This is the year that this date’s `iso_week` belongs to, which is
+    /// the same as `year` for all but a few days at either end of the year.
+    fn iso_week_year(&self) -> i64 {
+        iso_week_date(self.year(), self.yearday(), self.weekday()).0
+    }
+
     // I’d ideally like to include “century” here, but there’s some
     // discrepancy over what the result should be: the Gregorian
     // calendar calls the span from 2000 to 2099 the “21st Century”, but
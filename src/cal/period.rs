@@ -0,0 +1,259 @@
+//! Periods of calendar time, such as “one month and two days”, which are
+//! written as durations in ISO 8601.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use cal::DatePiece;
+use cal::datetime::{LocalDateTime, Year};
+use duration::Duration;
+
+
+/// A **period** is an amount of calendar time made up of separate numbers
+/// of years, months, weeks, days, hours, minutes, and seconds. It’s what
+/// ISO 8601 calls a *duration*, such as `P1Y2M10DT2H30M`.
+///
+/// Unlike a `Duration`, a period doesn’t have a fixed length: a month can
+/// have between 28 and 31 days. When a period gets added to a datetime,
+/// the years and months are added first, then the weeks and days, then the
+/// hours, minutes, and seconds. If the day doesn’t exist in the month that
+/// gets reached, such as the 31st of April, the last day of that month is
+/// used instead.
+///
+/// Each component of a period parsed from text is less than 2³², which
+/// keeps adding it to a datetime from overflowing. Periods built up by
+/// hand with larger components can overflow, which panics.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{Period, LocalDate, LocalDateTime, LocalTime, Month};
+///
+/// let period: Period = "P1M2DT12H".parse().unwrap();
+/// assert_eq!(period, Period { months: 1, days: 2, hours: 12, ..Period::default() });
+/// assert_eq!(period.to_string(), "P1M2DT12H");
+///
+/// let date = LocalDate::ymd(2017, Month::January, 31).unwrap();
+/// let start = LocalDateTime::new(date, LocalTime::midnight());
+/// assert_eq!(start + period, LocalDateTime::new(LocalDate::ymd(2017, Month::March, 2).unwrap(), LocalTime::hm(12, 0).unwrap()));
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Default)]
+pub struct Period {
+    pub years: i64,
+    pub months: i64,
+    pub weeks: i64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i16,
+}
+
+impl Period {
+
+    /// Returns whether every component of this period is zero.
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns whether this period has any hours, minutes, or seconds, as
+    /// opposed to only whole days.
+    pub fn has_time(&self) -> bool {
+        self.hours != 0 || self.minutes != 0 || self.seconds != 0 || self.milliseconds != 0
+    }
+
+    /// Returns this period with every component negated, for moving
+    /// backwards in time.
+    fn negated(self) -> Self {
+        Self {
+            years:         -self.years,
+            months:        -self.months,
+            weeks:         -self.weeks,
+            days:          -self.days,
+            hours:         -self.hours,
+            minutes:       -self.minutes,
+            seconds:       -self.seconds,
+            milliseconds:  -self.milliseconds,
+        }
+    }
+}
+
+impl Add<Period> for LocalDateTime {
+    type Output = Self;
+
+    fn add(self, period: Period) -> Self {
+        add_period(self, period)
+    }
+}
+
+impl Sub<Period> for LocalDateTime {
+    type Output = Self;
+
+    fn sub(self, period: Period) -> Self {
+        add_period(self, period.negated())
+    }
+}
+
+/// Adds the years and months of a period to a datetime, then its weeks and
+/// days, then the rest.
+fn add_period(datetime: LocalDateTime, period: Period) -> LocalDateTime {
+    let date = datetime.date();
+    let ym = Year(date.year()).month(date.month()) + (period.years * 12 + period.months);
+    let day = date.day().min(ym.day_count());
+
+    // This day was just clamped to the month, so it must exist.
+    let date = ym.day(day).unwrap().add_days(period.weeks * 7 + period.days);
+
+    let seconds = period.hours * 60 * 60 + period.minutes * 60 + period.seconds;
+    let milliseconds = seconds * 1000 + period.milliseconds as i64;
+    let duration = Duration::of_ms(milliseconds.div_euclid(1000), milliseconds.rem_euclid(1000) as i16);
+    LocalDateTime::new(date, datetime.time()) + duration
+}
+
+/// Periods are formatted as ISO 8601 durations, leaving out any components
+/// that are zero. A period of nothing at all is written as `PT0S`.
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+        for &(amount, designator) in &[ (self.years, 'Y'), (self.months, 'M'), (self.weeks, 'W'), (self.days, 'D') ] {
+            if amount != 0 {
+                write!(f, "{}{}", amount, designator)?;
+            }
+        }
+
+        if self.has_time() {
+            f.write_str("T")?;
+            for &(amount, designator) in &[ (self.hours, 'H'), (self.minutes, 'M') ] {
+                if amount != 0 {
+                    write!(f, "{}{}", amount, designator)?;
+                }
+            }
+
+            if self.milliseconds != 0 {
+                // The seconds can’t carry the sign when there aren’t any.
+                let sign = if self.seconds < 0 || (self.seconds == 0 && self.milliseconds < 0) { "-" } else { "" };
+                let fraction = format!("{:03}", self.milliseconds.abs());
+                write!(f, "{}{}.{}S", sign, self.seconds.unsigned_abs(), fraction.trim_end_matches('0'))?;
+            }
+            else if self.seconds != 0 {
+                write!(f, "{}S", self.seconds)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Periods are parsed from ISO 8601 durations, such as `P3Y6M4DT12H30M5S`
+/// or `P2W`. Only the seconds can have a fractional part, which is kept to
+/// the nearest millisecond below. A component that doesn’t fit in 32 bits
+/// makes the duration invalid.
+impl FromStr for Period {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rest = input.strip_prefix('P').ok_or_else(|| Error::Invalid(input.into()))?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time))  => (date, Some(time)),
+            None                => (rest, None),
+        };
+
+        if time == Some("") || (date.is_empty() && time.is_none()) {
+            return Err(Error::Empty);
+        }
+
+        let mut period = Self::default();
+        let components = parse_components(date, &[ 'Y', 'M', 'W', 'D' ])
+            .and_then(|mut date| {
+                date.extend(parse_components(time.unwrap_or(""), &[ 'H', 'm', 'S' ])?);
+                Some(date)
+            })
+            .ok_or_else(|| Error::Invalid(input.into()))?;
+
+        for (designator, number) in components {
+            let whole = |number: &str| number.parse::<u32>().ok().filter(|_| number.bytes().all(|b| b.is_ascii_digit())).map(i64::from);
+
+            let amount = match designator {
+                'S' => {
+                    let (seconds, fraction) = number.split_once(&[ '.', ',' ][..]).unwrap_or((number, "0"));
+                    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                        return Err(Error::Invalid(input.into()));
+                    }
+
+                    let milliseconds = format!("{:0<3}", &fraction[.. fraction.len().min(3)]);
+                    period.milliseconds = milliseconds.parse().unwrap();
+                    whole(seconds)
+                },
+                _   => whole(number),
+            };
+
+            let amount = amount.ok_or_else(|| Error::Invalid(input.into()))?;
+            match designator {
+                'Y'  => period.years = amount,
+                'M'  => period.months = amount,
+                'W'  => period.weeks = amount,
+                'D'  => period.days = amount,
+                'H'  => period.hours = amount,
+                'm'  => period.minutes = amount,
+                _    => period.seconds = amount,
+            }
+        }
+
+        Ok(period)
+    }
+}
+
+/// Splits a run of numbers followed by designator letters into pairs,
+/// checking that each designator appears at most once and in the order
+/// given. A lowercase designator is matched against its uppercase form,
+/// which lets minutes be told apart from months.
+fn parse_components<'a>(mut text: &'a str, designators: &[char]) -> Option<Vec<(char, &'a str)>> {
+    let mut remaining = designators;
+    let mut components = Vec::new();
+
+    while !text.is_empty() {
+        let index = text.find(|c: char| c.is_ascii_alphabetic())?;
+        let (number, rest) = text.split_at(index);
+        let letter = rest.chars().next()?;
+
+        let position = remaining.iter().position(|d| d.to_ascii_uppercase() == letter)?;
+        if number.is_empty() {
+            return None;
+        }
+
+        components.push((remaining[position], number));
+        remaining = &remaining[position + 1 ..];
+        text = &rest[1 ..];
+    }
+
+    Some(components)
+}
+
+
+/// An error that can occur when parsing a period.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Error {
+
+    /// The duration had no components, such as `P` or `PT`.
+    Empty,
+
+    /// The text wasn’t a valid ISO 8601 duration.
+    Invalid(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Empty              => write!(f, "duration has no components"),
+            Error::Invalid(ref text)  => write!(f, "invalid duration ‘{}’", text),
+        }
+    }
+}
+
+impl ErrorTrait for Error {}
@@ -0,0 +1,198 @@
+//! Ranges of dates that can cross over month and year boundaries, stepping
+//! by any number of days, weeks, months, or years.
+
+use std::ops::{Range, RangeInclusive};
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Year, Error};
+
+
+/// A span of dates, which gets used to construct a `DateRange` iterator.
+///
+/// This is implemented for both half-open ranges (`a .. b`), which stop
+/// *before* the end date, and inclusive ranges (`a ..= b`), which stop *at*
+/// it.
+pub trait DateSpan {
+
+    /// Returns the first date in this span, and the date just *after* the
+    /// last one.
+    fn get_bounds(&self) -> (LocalDate, LocalDate);
+}
+
+impl DateSpan for Range<LocalDate> {
+    fn get_bounds(&self) -> (LocalDate, LocalDate) {
+        (self.start, self.end)
+    }
+}
+
+impl DateSpan for RangeInclusive<LocalDate> {
+    fn get_bounds(&self) -> (LocalDate, LocalDate) {
+        (*self.start(), self.end().add_days(1))
+    }
+}
+
+
+/// The amount to move forward by between each date in a `DateRange`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum DateStep {
+    Days(i64),
+    Weeks(i64),
+
+    /// Moves forward by a number of months. If the starting day doesn’t
+    /// exist in a month, such as the 31st of April, the last day of that
+    /// month is used instead.
+    Months(i64),
+
+    /// Moves forward by a number of years. If the range starts on the 29th
+    /// of February, the 28th is used in years that aren’t leap years.
+    Years(i64),
+}
+
+impl DateStep {
+
+    /// Returns the number of days or months in this step, and whether
+    /// it’s a number of months.
+    fn in_days_or_months(self) -> (i64, bool) {
+        match self {
+            DateStep::Days(days)    => (days, false),
+            DateStep::Weeks(weeks)  => (weeks * 7, false),
+            DateStep::Months(months) => (months, true),
+            DateStep::Years(years)  => (years * 12, true),
+        }
+    }
+}
+
+
+/// An iterator over dates between two bounds, which can be any distance
+/// apart, moving forward by a fixed step each time.
+///
+/// Every date is calculated from the *first* one, rather than from the one
+/// before it, so stepping monthly from the 31st of January gives the 28th
+/// of February and then the 31st of March.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month, DateRange, DateStep};
+///
+/// let start = LocalDate::ymd(2016, Month::January, 31).unwrap();
+/// let end   = LocalDate::ymd(2016, Month::May, 31).unwrap();
+///
+/// let dates: Vec<_> = DateRange::new(start ..= end, DateStep::Months(1)).unwrap().collect();
+/// assert_eq!(dates.len(), 5);
+/// assert_eq!(dates[1], LocalDate::ymd(2016, Month::February, 29).unwrap());
+/// assert_eq!(dates[4], end);
+///
+/// let days = DateRange::days(start .. end);
+/// assert_eq!(days.rev().next(), LocalDate::ymd(2016, Month::May, 30).ok());
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct DateRange {
+    start: LocalDate,
+    step: DateStep,
+    range: Range<i64>,
+}
+
+impl DateRange {
+
+    /// Creates a new iterator over the dates in the given span, moving
+    /// forward by the given step each time.
+    ///
+    /// The step is checked for validity, and passing in one that isn’t
+    /// positive will return an error.
+    pub fn new<S: DateSpan>(span: S, step: DateStep) -> Result<Self, Error> {
+        let (amount, _) = step.in_days_or_months();
+        if amount <= 0 {
+            return Err(Error::OutOfRange);
+        }
+
+        let (start, end) = span.get_bounds();
+        let mut range = Self { start, step, range: 0 .. 0 };
+        range.range = 0 .. range.count_before(end);
+        Ok(range)
+    }
+
+    /// Creates a new iterator over every date in the given span.
+    pub fn days<S: DateSpan>(span: S) -> Self {
+        // A step of one day is always valid.
+        Self::new(span, DateStep::Days(1)).unwrap()
+    }
+
+    /// Returns the date that’s the given number of steps after the first.
+    fn nth_date(&self, steps: i64) -> LocalDate {
+        let (amount, months) = self.step.in_days_or_months();
+
+        if months {
+            let ym = Year(self.start.year()).month(self.start.month()) + amount * steps;
+            let day = self.start.day().min(ym.day_count());
+            ym.day(day).unwrap()
+        }
+        else {
+            self.start.add_days(amount * steps)
+        }
+    }
+
+    /// Returns the number of steps it takes to reach or pass the given
+    /// date, which is the number of dates in the range.
+    fn count_before(&self, end: LocalDate) -> i64 {
+        let (amount, months) = self.step.in_days_or_months();
+
+        if months {
+            // Stepping by months, this lower bound is always at least one
+            // month before the end, so there are only a few steps to check.
+            let start_ym = Year(self.start.year()).month(self.start.month());
+            let end_ym = Year(end.year()).month(end.month());
+            let mut steps = (start_ym.months_until(end_ym) / amount - 1).max(0);
+
+            while self.nth_date(steps) < end {
+                steps += 1;
+            }
+
+            steps
+        }
+        else {
+            let days = self.start.days_until(end);
+            if days <= 0 { 0 } else { (days + amount - 1) / amount }
+        }
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = LocalDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|steps| self.nth_date(steps))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|steps| self.nth_date(steps))
+    }
+}
+
+
+impl Year {
+
+    /// Returns an iterator over every day in this year, returning
+    /// `LocalDate` values.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Year;
+    ///
+    /// assert_eq!(Year(2016).days().count(), 366);
+    /// assert_eq!(Year(2017).days().count(), 365);
+    /// ```
+    pub fn days(self) -> DateRange {
+        // The 1st of January always exists.
+        let first = LocalDate::yd(self.0, 1).unwrap();
+        let next = LocalDate::yd(self.0 + 1, 1).unwrap();
+        DateRange::days(first .. next)
+    }
+}
@@ -0,0 +1,141 @@
+//! Serial dates, as stored by spreadsheet programs.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::TimePiece;
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month};
+
+
+/// Number of milliseconds in a day.
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+
+/// A spreadsheet **date system** decides which date a serial number counts
+/// days from. Spreadsheets store datetimes as a number of days, with the
+/// fractional part being the time of day, so `0.5` is noon on the first
+/// day.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum SpreadsheetDateSystem {
+
+    /// The default system in Microsoft Excel, where serial 1 is the 1st of
+    /// January 1900. It wrongly treats 1900 as a leap year, for
+    /// compatibility with Lotus 1-2-3, so serial 60 is the 29th of
+    /// February 1900, a day that never existed, and the serial of every
+    /// date after it is one more than the number of days since the 31st of
+    /// December 1899. Serials can’t be negative.
+    Excel1900,
+
+    /// The system used by older versions of Excel for the Mac, where
+    /// serial 0 is the 1st of January 1904. Serials can’t be negative.
+    Excel1904,
+
+    /// The system used by LibreOffice and OpenOffice, where serial 0 is
+    /// the 30th of December 1899. This agrees with `Excel1900` from the
+    /// 1st of March 1900 onwards, and can go negative for earlier dates.
+    LibreOffice,
+}
+
+impl SpreadsheetDateSystem {
+
+    /// Returns the date that serial 0 refers to in this system, counting
+    /// forwards without any fake days.
+    fn epoch(self) -> LocalDate {
+        match self {
+            SpreadsheetDateSystem::Excel1900    => LocalDate::ymd(1899, Month::December, 31).unwrap(),
+            SpreadsheetDateSystem::Excel1904    => LocalDate::ymd(1904, Month::January, 1).unwrap(),
+            SpreadsheetDateSystem::LibreOffice  => LocalDate::ymd(1899, Month::December, 30).unwrap(),
+        }
+    }
+}
+
+
+/// Conversions to and from spreadsheet serial numbers.
+impl LocalDateTime {
+
+    /// Creates a new local datetime from a spreadsheet serial number in
+    /// the given date system. The fractional part is rounded to the
+    /// nearest millisecond, as times such as 8am can’t be stored exactly.
+    ///
+    /// This returns an error for the 29th of February 1900 in the Excel
+    /// 1900 system, and for serials outside the range the system allows.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, LocalDateTime, LocalTime, Month, SpreadsheetDateSystem};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::August, 21).unwrap();
+    /// let datetime = LocalDateTime::from_spreadsheet_serial(42968.75, SpreadsheetDateSystem::Excel1900).unwrap();
+    /// assert_eq!(datetime, LocalDateTime::new(date, LocalTime::hm(18, 0).unwrap()));
+    /// assert_eq!(datetime.to_spreadsheet_serial(SpreadsheetDateSystem::Excel1904), Some(41506.75));
+    /// ```
+    pub fn from_spreadsheet_serial(serial: f64, system: SpreadsheetDateSystem) -> Result<Self, Error> {
+        let milliseconds = (serial * MILLISECONDS_IN_DAY as f64).round();
+        if !milliseconds.is_finite() || milliseconds.abs() >= i64::MAX as f64 {
+            return Err(Error::OutOfRange);
+        }
+
+        let milliseconds = milliseconds as i64;
+        let mut days = milliseconds.div_euclid(MILLISECONDS_IN_DAY);
+        let time = milliseconds.rem_euclid(MILLISECONDS_IN_DAY);
+
+        if system != SpreadsheetDateSystem::LibreOffice && days < 0 {
+            return Err(Error::OutOfRange);
+        }
+
+        if system == SpreadsheetDateSystem::Excel1900 && days >= 60 {
+            if days == 60 {
+                return Err(Error::FakeLeapDay);
+            }
+
+            days -= 1;
+        }
+
+        let time = LocalTime::from_seconds_and_milliseconds_since_midnight(time / 1000, (time % 1000) as i16);
+        Ok(Self::new(system.epoch().add_days(days), time))
+    }
+
+    /// Returns the spreadsheet serial number of this local datetime in the
+    /// given date system, or `None` if it’s before the first date the
+    /// system can store.
+    pub fn to_spreadsheet_serial(&self, system: SpreadsheetDateSystem) -> Option<f64> {
+        let mut days = system.epoch().days_until(self.date());
+
+        if system != SpreadsheetDateSystem::LibreOffice && days < 0 {
+            return None;
+        }
+
+        if system == SpreadsheetDateSystem::Excel1900 && days >= 60 {
+            days += 1;
+        }
+
+        let milliseconds = self.time().to_seconds() * 1000 + self.millisecond() as i64;
+        Some(days as f64 + milliseconds as f64 / MILLISECONDS_IN_DAY as f64)
+    }
+}
+
+
+/// An error that can occur when converting a spreadsheet serial number.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Error {
+
+    /// The serial is negative in a system that doesn’t allow it, or isn’t a
+    /// finite number.
+    OutOfRange,
+
+    /// The serial is for the 29th of February 1900 in the Excel 1900
+    /// system, which doesn’t exist.
+    FakeLeapDay,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::OutOfRange   => write!(f, "serial out of range"),
+            Error::FakeLeapDay  => write!(f, "serial is for the 29th of February 1900, which doesn’t exist"),
+        }
+    }
+}
+
+impl ErrorTrait for Error {}
@@ -0,0 +1,161 @@
+//! Rounding dates and datetimes down or up to the boundaries of calendar
+//! units, such as the start of the day or the end of the month.
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Year};
+use cal::week::WeekRule;
+use duration::Duration;
+
+
+/// A **unit** of calendar time, used to find the start or end of the one
+/// that a date or datetime falls in.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+
+    /// A week, starting on the day given by the week rule.
+    Week(WeekRule),
+
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Unit {
+
+    /// Returns whether this unit is a day or longer, meaning that its
+    /// boundaries always fall at midnight.
+    pub(crate) fn is_date_unit(self) -> bool {
+        !matches!(self, Unit::Second | Unit::Minute | Unit::Hour)
+    }
+}
+
+
+impl LocalDate {
+
+    /// Returns the first day of the given unit that this date falls in.
+    /// For units shorter than a day, this is the date itself.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, Unit, WeekRule};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::August, 17).unwrap();
+    /// assert_eq!(date.start_of(Unit::Month), LocalDate::ymd(2017, Month::August, 1).unwrap());
+    /// assert_eq!(date.start_of(Unit::Quarter), LocalDate::ymd(2017, Month::July, 1).unwrap());
+    /// assert_eq!(date.start_of(Unit::Week(WeekRule::iso())), LocalDate::ymd(2017, Month::August, 14).unwrap());
+    /// ```
+    pub fn start_of(&self, unit: Unit) -> Self {
+        match unit {
+            Unit::Second | Unit::Minute | Unit::Hour | Unit::Day => *self,
+            Unit::Week(rule)  => self.start_of_week(rule),
+            Unit::Month       => self.add_days(1 - self.day() as i64),
+            Unit::Quarter     => self.year_quarter().first_day(),
+            Unit::Year        => self.add_days(1 - self.yearday() as i64),
+        }
+    }
+
+    /// Returns the last day of the given unit that this date falls in.
+    /// For units shorter than a day, this is the date itself.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, Unit};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::February, 10).unwrap();
+    /// assert_eq!(date.end_of(Unit::Month), LocalDate::ymd(2016, Month::February, 29).unwrap());
+    /// assert_eq!(date.end_of(Unit::Year), LocalDate::ymd(2016, Month::December, 31).unwrap());
+    /// ```
+    pub fn end_of(&self, unit: Unit) -> Self {
+        match unit {
+            Unit::Second | Unit::Minute | Unit::Hour | Unit::Day => *self,
+            Unit::Week(rule)  => self.start_of_week(rule).add_days(6),
+            Unit::Month       => {
+                let ym = Year(self.year()).month(self.month());
+                ym.day(ym.day_count()).unwrap()
+            },
+            Unit::Quarter     => self.year_quarter().last_day(),
+            Unit::Year        => LocalDate::yd(self.year() + 1, 1).unwrap().add_days(-1),
+        }
+    }
+}
+
+
+impl LocalDateTime {
+
+    /// Returns this datetime truncated to the start of the given unit,
+    /// which sets all the fields smaller than it to their lowest values.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Unit};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::August, 17).unwrap();
+    /// let datetime = LocalDateTime::new(date, LocalTime::hms_ms(13, 45, 30, 250).unwrap());
+    ///
+    /// assert_eq!(datetime.truncate(Unit::Hour), LocalDateTime::new(date, LocalTime::hm(13, 0).unwrap()));
+    /// assert_eq!(datetime.truncate(Unit::Day), LocalDateTime::new(date, LocalTime::midnight()));
+    /// ```
+    pub fn truncate(&self, unit: Unit) -> Self {
+        let time = match unit {
+            Unit::Second  => LocalTime::hms(self.hour(), self.minute(), self.second()),
+            Unit::Minute  => LocalTime::hm(self.hour(), self.minute()),
+            Unit::Hour    => LocalTime::hm(self.hour(), 0),
+            _             => Ok(LocalTime::midnight()),
+        };
+
+        // The fields all come from a valid time, so these can’t fail.
+        Self::new(self.date().start_of(unit), time.unwrap())
+    }
+
+    /// Returns the first moment of the given unit that this datetime falls
+    /// in. This is the same as `truncate`, but named to pair with `end_of`.
+    pub fn start_of(&self, unit: Unit) -> Self {
+        self.truncate(unit)
+    }
+
+    /// Returns the last moment of the given unit that this datetime falls
+    /// in, to the millisecond.
+    ///
+    /// Leap seconds are not taken into account, so the end of a day is
+    /// always 23:59:59.999, even on a day with a leap second.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Unit};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::August, 17).unwrap();
+    /// let datetime = LocalDateTime::new(date, LocalTime::hms(13, 45, 30).unwrap());
+    ///
+    /// let end = LocalDate::ymd(2017, Month::August, 31).unwrap();
+    /// assert_eq!(datetime.end_of(Unit::Month), LocalDateTime::new(end, LocalTime::hms_ms(23, 59, 59, 999).unwrap()));
+    /// ```
+    pub fn end_of(&self, unit: Unit) -> Self {
+        let time = match unit {
+            Unit::Second  => LocalTime::hms_ms(self.hour(), self.minute(), self.second(), 999),
+            Unit::Minute  => LocalTime::hms_ms(self.hour(), self.minute(), 59, 999),
+            Unit::Hour    => LocalTime::hms_ms(self.hour(), 59, 59, 999),
+            _             => LocalTime::hms_ms(23, 59, 59, 999),
+        };
+
+        Self::new(self.date().end_of(unit), time.unwrap())
+    }
+
+    /// Returns the first moment of the unit *after* the one that this
+    /// datetime falls in.
+    pub(crate) fn start_of_next(&self, unit: Unit) -> Self {
+        if unit.is_date_unit() {
+            Self::new(self.date().end_of(unit).add_days(1), LocalTime::midnight())
+        }
+        else {
+            self.end_of(unit) + Duration::of_ms(0, 1)
+        }
+    }
+}
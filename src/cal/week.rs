@@ -0,0 +1,232 @@
+//! Week numbering rules, for the many places that don’t number their weeks
+//! the ISO-8601 way.
+
+use cal::DatePiece;
+use cal::datetime::{LocalDate, Month, Weekday, Error};
+
+
+/// A **week rule** determines how the days of a year or month are split up
+/// into numbered weeks.
+///
+/// It has two parts: the day that each week starts on, and the minimum
+/// number of days that the first week of a year or month must have in it.
+/// If the week containing the 1st of January has fewer days than this in
+/// the new year, then it counts as the last week of the *previous* year
+/// instead, and week 1 is the week after it.
+///
+/// ISO-8601 weeks start on a Monday, and the first week must have at least
+/// four days, so it’s the week containing the first Thursday. In the United
+/// States, weeks start on a Sunday, and the first week is the one that
+/// contains the 1st of January, however few days of it are in the new year.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct WeekRule {
+    first_day: Weekday,
+    minimum_days: i8,
+}
+
+impl WeekRule {
+
+    /// Creates a new week rule with weeks starting on the given day, and
+    /// the first week needing to have at least the given number of days.
+    ///
+    /// The minimum number of days is checked for validity, and passing in
+    /// a number that isn’t between 1 and 7 will return an error.
+    pub fn new(first_day: Weekday, minimum_days: i8) -> Result<Self, Error> {
+        if (1 ..= 7).contains(&minimum_days) {
+            Ok(Self { first_day, minimum_days })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// The ISO-8601 week rule, with weeks starting on Monday, and week 1
+    /// being the first one with at least four days in the new year.
+    pub fn iso() -> Self {
+        Self { first_day: Weekday::Monday, minimum_days: 4 }
+    }
+
+    /// The week rule used in the United States and Canada, with weeks
+    /// starting on Sunday, and week 1 being the one containing the 1st of
+    /// January.
+    pub fn sunday_start() -> Self {
+        Self { first_day: Weekday::Sunday, minimum_days: 1 }
+    }
+
+    /// The week rule used in much of the Middle East, with weeks starting
+    /// on Saturday, and week 1 being the one containing the 1st of January.
+    pub fn saturday_start() -> Self {
+        Self { first_day: Weekday::Saturday, minimum_days: 1 }
+    }
+
+    /// Returns the day that each week starts on.
+    pub fn first_day(&self) -> Weekday {
+        self.first_day
+    }
+
+    /// Returns the minimum number of days that the first week of a year or
+    /// month must have.
+    pub fn minimum_days(&self) -> i8 {
+        self.minimum_days
+    }
+
+    /// Returns the number of weeks in the given week-numbering year, which
+    /// is either 52 or 53.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::WeekRule;
+    ///
+    /// assert_eq!(WeekRule::iso().weeks_in_year(2015), 53);
+    /// assert_eq!(WeekRule::sunday_start().weeks_in_year(2015), 52);
+    /// ```
+    pub fn weeks_in_year(&self, year: i64) -> i8 {
+        (self.first_week_of_year(year).days_until(self.first_week_of_year(year + 1)) / 7) as i8
+    }
+
+    /// Creates a new local date from the given week-numbering year, week
+    /// number, and weekday, using this rule. This is the generalisation of
+    /// `LocalDate::ywd`, which always uses the ISO-8601 rule.
+    ///
+    /// The week number is checked for validity, and passing in one that
+    /// isn’t in the year will return an error.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, Weekday, WeekRule};
+    ///
+    /// let date = WeekRule::sunday_start().date(2017, 1, Weekday::Saturday).unwrap();
+    /// assert_eq!(date, LocalDate::ymd(2017, Month::January, 7).unwrap());
+    /// ```
+    pub fn date(&self, year: i64, week: i8, weekday: Weekday) -> Result<LocalDate, Error> {
+        if week < 1 || week > self.weeks_in_year(year) {
+            return Err(Error::OutOfRange);
+        }
+
+        let start = self.first_week_of_year(year).add_weeks(week as i64 - 1);
+        Ok(start.add_days(self.days_into_week(weekday)))
+    }
+
+    /// Returns the number of days after the start of the week that the
+    /// given weekday is, from 0 to 6.
+    fn days_into_week(&self, weekday: Weekday) -> i64 {
+        (weekday as i64 - self.first_day as i64 + 7) % 7
+    }
+
+    /// Returns the date that the first week of a period starts on, given
+    /// the date of the first day of that period.
+    fn first_week_from(&self, first_of_period: LocalDate) -> LocalDate {
+        let offset = self.days_into_week(first_of_period.weekday());
+        let start = first_of_period.add_days(-offset);
+
+        // If the week with the first day in it has too few days in the
+        // period, then the first week is the one after it.
+        if 7 - offset >= self.minimum_days as i64 {
+            start
+        }
+        else {
+            start.add_weeks(1)
+        }
+    }
+
+    /// Returns the date that the first week of the given year starts on.
+    fn first_week_of_year(&self, year: i64) -> LocalDate {
+        self.first_week_from(LocalDate::ymd(year, Month::January, 1).unwrap())
+    }
+}
+
+impl Default for WeekRule {
+    fn default() -> Self {
+        Self::iso()
+    }
+}
+
+
+impl LocalDate {
+
+    /// Returns the date that this date’s week starts on, according to the
+    /// given week rule.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, WeekRule};
+    ///
+    /// let date = LocalDate::ymd(2017, Month::March, 15).unwrap();
+    /// assert_eq!(date.start_of_week(WeekRule::iso()), LocalDate::ymd(2017, Month::March, 13).unwrap());
+    /// assert_eq!(date.start_of_week(WeekRule::sunday_start()), LocalDate::ymd(2017, Month::March, 12).unwrap());
+    /// assert_eq!(date.start_of_week(WeekRule::saturday_start()), LocalDate::ymd(2017, Month::March, 11).unwrap());
+    /// ```
+    pub fn start_of_week(&self, rule: WeekRule) -> Self {
+        self.add_days(-rule.days_into_week(self.weekday()))
+    }
+
+    /// Returns the number of the week of the year that this date falls in,
+    /// according to the given week rule, from 1 to 53.
+    ///
+    /// As with ISO-8601 weeks, the first few days of January can fall in
+    /// the last week of the previous year, and the last few days of
+    /// December can fall in week 1 of the next. Use `week_year` to find out
+    /// which year the week belongs to.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, WeekRule};
+    ///
+    /// let date = LocalDate::ymd(2016, Month::January, 2).unwrap();
+    /// assert_eq!(date.week_of_year(WeekRule::iso()), 53);
+    /// assert_eq!(date.week_of_year(WeekRule::sunday_start()), 1);
+    /// ```
+    pub fn week_of_year(&self, rule: WeekRule) -> i8 {
+        self.week_year_and_week(rule).1
+    }
+
+    /// Returns the week-numbering year that this date’s `week_of_year`
+    /// belongs to, according to the given week rule.
+    pub fn week_year(&self, rule: WeekRule) -> i64 {
+        self.week_year_and_week(rule).0
+    }
+
+    /// Returns the number of the week of the month that this date falls
+    /// in, according to the given week rule.
+    ///
+    /// Unlike weeks of the year, weeks of the month don’t spill over into
+    /// the previous month: if the first week of the month has fewer days
+    /// than the rule’s minimum, those days are in week **0**.
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// use datetime::{LocalDate, Month, WeekRule};
+    ///
+    /// // The 1st of April 2017 was a Saturday.
+    /// let date = LocalDate::ymd(2017, Month::April, 1).unwrap();
+    /// assert_eq!(date.week_of_month(WeekRule::iso()), 0);
+    /// assert_eq!(date.week_of_month(WeekRule::sunday_start()), 1);
+    /// ```
+    pub fn week_of_month(&self, rule: WeekRule) -> i8 {
+        let first_of_month = self.add_days(1 - self.day() as i64);
+        let days = rule.first_week_from(first_of_month).days_until(*self);
+
+        if days < 0 { 0 } else { (days / 7 + 1) as i8 }
+    }
+
+    fn week_year_and_week(&self, rule: WeekRule) -> (i64, i8) {
+        let year = self.year();
+        let this_year = rule.first_week_of_year(year);
+
+        if *self < this_year {
+            let last_year = rule.first_week_of_year(year - 1);
+            return (year - 1, (last_year.days_until(*self) / 7 + 1) as i8);
+        }
+
+        if *self >= rule.first_week_of_year(year + 1) {
+            return (year + 1, 1);
+        }
+
+        (year, (this_year.days_until(*self) / 7 + 1) as i8)
+    }
+}
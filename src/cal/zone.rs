@@ -6,7 +6,7 @@ use std::sync::Arc;
 use duration::Duration;
 use instant::Instant;
 use cal::{LocalDateTime, DatePiece, TimePiece, Month, Weekday};
-use util::RangeExt;
+use cal::unit::Unit;
 
 
 /// A **time zone**, which here is a list of timespans, each containing a
@@ -94,12 +94,66 @@ impl TimeZone {
     /// or overlaps two separate timespans (an ambiguous time). The result
     /// will *almost* always be precise, but there are edge cases you need
     /// to watch out for.
-    pub fn convert_local(&self, local: LocalDateTime) -> LocalTimes {
+    pub fn convert_local(&self, local: LocalDateTime) -> LocalTimes<'static> {
         match self.0 {
             TimeZoneSource::Static(ref tz)   => tz.fixed_timespans.convert_local(local, &self.0),
             TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().convert_local(local, &self.0),
         }
     }
+
+    /// Returns the zoned datetime in this time zone at the given instant.
+    /// Unlike converting a local time, this always gives exactly one
+    /// result.
+    pub fn zoned_at(&self, instant: Instant) -> ZonedDateTime<'static> {
+        let utc = LocalDateTime::from_instant(instant);
+        let offset = self.offset(utc);
+
+        ZonedDateTime {
+            adjusted: utc + Duration::of(offset),
+            current_offset: offset,
+            time_zone: self.0.clone(),
+        }
+    }
+
+    /// Converts a local datetime that is informally in this time zone into
+    /// exactly one zoned datetime, in the way that RFC 5545 requires for
+    /// recurring events.
+    ///
+    /// If the local datetime is ambiguous, the earlier of the two is used.
+    /// If it’s impossible, because it falls in a gap where the clocks went
+    /// forward, it’s interpreted using the offset from *before* the gap,
+    /// which moves it forward by the length of the gap: 02:30 on a night
+    /// where the clocks go from 02:00 to 03:00 becomes 03:30.
+    pub fn resolve_local(&self, local: LocalDateTime) -> ZonedDateTime<'static> {
+        match self.0.convert_local(local) {
+            LocalTimes::Precise(zoned)             => zoned,
+            LocalTimes::Ambiguous { earlier, .. }  => earlier,
+            LocalTimes::Impossible                 => {
+
+                // Transitions are always more than a day apart, so the
+                // offset a day before is the one from before the gap.
+                let before = self.offset(local - Duration::of(86400));
+                self.zoned_at((local - Duration::of(before)).to_instant())
+            },
+        }
+    }
+}
+
+
+impl<'a> TimeZoneSource<'a> {
+    fn offset(&self, datetime: LocalDateTime) -> i64 {
+        match *self {
+            TimeZoneSource::Static(tz)       => tz.fixed_timespans.offset(datetime),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().offset(datetime),
+        }
+    }
+
+    fn convert_local(&self, local: LocalDateTime) -> LocalTimes<'a> {
+        match *self {
+            TimeZoneSource::Static(tz)       => tz.fixed_timespans.convert_local(local, self),
+            TimeZoneSource::Runtime(ref arc) => arc.fixed_timespans.borrow().convert_local(local, self),
+        }
+    }
 }
 
 
@@ -142,7 +196,7 @@ pub struct FixedTimespan<'a> {
 
 impl<'a> FixedTimespanSet<'a> {
     fn find(&self, time: i64) -> &FixedTimespan {
-        match self.rest.iter().take_while(|t| t.0 < time).last() {
+        match self.rest.iter().take_while(|t| t.0 <= time).last() {
             None     => &self.first,
             Some(zd) => &zd.1,
         }
@@ -162,7 +216,7 @@ impl<'a> FixedTimespanSet<'a> {
         self.rest.is_empty()
     }
 
-    fn convert_local(&self, local: LocalDateTime, source: &TimeZoneSource<'a>) -> LocalTimes<'a> {
+    fn convert_local<'b>(&self, local: LocalDateTime, source: &TimeZoneSource<'b>) -> LocalTimes<'b> {
         let unix_timestamp = local.to_instant().seconds();
 
         let zonify = |offset| ZonedDateTime {
@@ -171,60 +225,39 @@ impl<'a> FixedTimespanSet<'a> {
             time_zone: source.clone(),
         };
 
+        // The timespans are found using the local time as though it were
+        // UTC, which can be up to a day off from the actual instant, so
+        // the timespans either side of it need to be checked too. Each one
+        // is checked by working out the instant the local time would be at
+        // using its offset, and seeing whether it falls inside it.
         let timespans = self.find_with_surroundings(unix_timestamp);
+        let current_start = timespans.previous.map(|p| p.1);
+        let next_start = timespans.next.map(|n| n.0);
 
-        if let Some((previous_zone, previous_transition_time)) = timespans.previous {
-
-            assert!(timespans.current.offset != previous_zone.offset,
-                    "Offsets cannot be equal! Is this a non-transition transition?");
-
-            println!("unix timestamp {:?}, previous time {:?}", unix_timestamp, previous_transition_time);
-
-            // Test whether this timestamp is in the *overlap* after the
-            // current timespan starts but before the previous one ends.
-            if previous_zone.offset > timespans.current.offset
-            && (unix_timestamp - previous_transition_time).is_within(timespans.current.offset .. previous_zone.offset) {
-                return LocalTimes::Ambiguous {
-                    earlier:  zonify(previous_zone.offset),
-                    later:    zonify(timespans.current.offset),
-                };
-            }
-
-            // Test whether this timestamp is in the *space* after the
-            // previous timespan ends but before the current one starts.
-            if previous_zone.offset < timespans.current.offset
-            && (unix_timestamp - previous_transition_time).is_within(previous_zone.offset .. timespans.current.offset) {
-                return LocalTimes::Impossible;
-            }
+        let mut candidates = Vec::new();
+        if let Some((previous_zone, _)) = timespans.previous {
+            candidates.push((previous_zone.offset, None, current_start));
         }
 
-        if let Some(&(next_transition_time, ref next_zone)) = timespans.next {
-
-            assert!(timespans.current.offset != next_zone.offset,
-                "Offsets cannot be equal! Is this a non-transition transition?");
-
-            println!("unix timestamp {:?}, next time {:?}", unix_timestamp, next_transition_time);
-            println!("offset 1 {:?}, offset 2 {:?}", next_zone.offset, timespans.current.offset);
-
-            // Test whether this timestamp is in the *overlap* after the
-            // next timespan starts but before the current one ends.
-            if timespans.current.offset > next_zone.offset
-            && (unix_timestamp - next_transition_time).is_within(next_zone.offset .. timespans.current.offset) {
-                return LocalTimes::Ambiguous {
-                    earlier:  zonify(timespans.current.offset),
-                    later:    zonify(next_zone.offset),
-                };
-            }
+        candidates.push((timespans.current.offset, current_start, next_start));
 
-            // Test whether this timestamp is in the *space* after the
-            // current timespan ends but before the next one starts.
-            if timespans.current.offset < next_zone.offset
-            && (unix_timestamp - next_transition_time).is_within(timespans.current.offset .. next_zone.offset) {
-                return LocalTimes::Impossible;
-            }
+        if let Some((_, next_zone)) = timespans.next {
+            candidates.push((next_zone.offset, next_start, None));
         }
 
-        LocalTimes::Precise(zonify(timespans.current.offset))
+        let mut offsets = candidates.into_iter().filter(|&(offset, start, end)| {
+            let instant = unix_timestamp - offset;
+            start.map_or(true, |s| instant >= s) && end.map_or(true, |e| instant < e)
+        }).map(|c| c.0);
+
+        match (offsets.next(), offsets.next()) {
+            (None, _)                  => LocalTimes::Impossible,
+            (Some(offset), None)       => LocalTimes::Precise(zonify(offset)),
+            (Some(first), Some(then))  => LocalTimes::Ambiguous {
+                earlier:  zonify(first),
+                later:    zonify(then),
+            },
+        }
     }
 
     fn find_with_surroundings(&self, time: i64) -> Surroundings {
@@ -320,7 +353,7 @@ impl<'a> LocalTimes<'a> {
 }
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ZonedDateTime<'a> {
     adjusted: LocalDateTime,
     current_offset: i64,
@@ -331,6 +364,89 @@ impl<'a> ZonedDateTime<'a> {
     pub fn to_instant(&self) -> Instant {
         (self.adjusted - Duration::of(self.current_offset)).to_instant()
     }
+
+    /// Returns the first moment of the given unit that this datetime falls
+    /// in, in the same time zone.
+    ///
+    /// Days usually start at midnight, but some time zones have changed
+    /// their clocks at midnight, skipping straight from 23:59:59 to 01:00.
+    /// When the start of a unit falls in a gap like this, the first moment
+    /// *after* the gap is used instead. When it falls in an overlap, where
+    /// the wall clock shows the same time twice, the earlier moment is used
+    /// for days and longer, and the one with this datetime’s offset is used
+    /// for shorter units.
+    pub fn start_of(&self, unit: Unit) -> Self {
+        let local = self.adjusted.start_of(unit);
+
+        match self.time_zone.convert_local(local) {
+            LocalTimes::Ambiguous { ref later, .. } if !unit.is_date_unit() && later.current_offset == self.current_offset => later.clone(),
+            _ => self.first_at_or_after(local),
+        }
+    }
+
+    /// Returns the last moment of the given unit that this datetime falls
+    /// in, in the same time zone, to the millisecond. This is the moment
+    /// just before the start of the next unit.
+    pub fn end_of(&self, unit: Unit) -> Self {
+        let next = if unit.is_date_unit() {
+            self.first_at_or_after(self.adjusted.start_of_next(unit)).to_instant()
+        }
+        else {
+            // Units shorter than a day always have the same length, even
+            // if the wall clock gets changed halfway through one.
+            let length = self.adjusted.start_of_next(unit).to_instant() - self.adjusted.start_of(unit).to_instant();
+            self.start_of(unit).to_instant() + length
+        };
+
+        self.at_instant(next - Duration::of_ms(0, 1))
+    }
+
+    /// Returns the earliest zoned datetime that shows the given local
+    /// datetime, or the first one after it if it falls in a gap.
+    fn first_at_or_after(&self, local: LocalDateTime) -> Self {
+        match self.time_zone.convert_local(local) {
+            LocalTimes::Precise(zoned)             => zoned,
+            LocalTimes::Ambiguous { earlier, .. }  => earlier,
+            LocalTimes::Impossible                 => {
+
+                // Gaps are never as long as a day, so search forward for
+                // the second that they end on.
+                let (mut impossible, mut possible) = (0, 86400);
+                while possible - impossible > 1 {
+                    let middle = (impossible + possible) / 2;
+                    if self.time_zone.convert_local(local.add_seconds(middle)).is_impossible() {
+                        impossible = middle;
+                    }
+                    else {
+                        possible = middle;
+                    }
+                }
+
+                self.first_at_or_after(local.add_seconds(possible))
+            },
+        }
+    }
+
+    /// Returns the zoned datetime at the given instant, in the same time
+    /// zone as this one.
+    fn at_instant(&self, instant: Instant) -> Self {
+        let utc = LocalDateTime::from_instant(instant);
+        let offset = self.time_zone.offset(utc);
+
+        ZonedDateTime {
+            adjusted: utc + Duration::of(offset),
+            current_offset: offset,
+            time_zone: self.time_zone.clone(),
+        }
+    }
+}
+
+impl ZonedDateTime<'static> {
+
+    /// Returns the time zone that this datetime is in.
+    pub fn time_zone(&self) -> TimeZone {
+        TimeZone(self.time_zone.clone())
+    }
 }
 
 impl<'a> DatePiece for ZonedDateTime<'a> {
@@ -1,6 +1,10 @@
 //! Lengths of time on the timeline.
 
+use std::convert::TryFrom;
+use std::error::Error as ErrorTrait;
+use std::fmt;
 use std::ops::{Add, Sub, Mul};
+use std::time::Duration as StdDuration;
 
 
 /// A **duration** is a length of time on the timeline, irrespective of
@@ -82,3 +86,58 @@ impl Mul<i64> for Duration {
         Self::of_ms(self.seconds * amount + ms / 1000, (ms % 1000) as i16)
     }
 }
+
+
+/// Standard library durations can be converted losslessly down to the
+/// millisecond, with anything smaller being truncated. This fails if the
+/// number of seconds is too large to fit in an `i64`.
+impl TryFrom<StdDuration> for Duration {
+    type Error = ConversionError;
+
+    fn try_from(duration: StdDuration) -> Result<Self, Self::Error> {
+        if duration.as_secs() > i64::MAX as u64 {
+            return Err(ConversionError::Overflow);
+        }
+
+        Ok(Self::of_ms(duration.as_secs() as i64, duration.subsec_millis() as i16))
+    }
+}
+
+/// Standard library durations can’t be negative, so this fails for any
+/// duration less than zero.
+impl TryFrom<Duration> for StdDuration {
+    type Error = ConversionError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if duration.seconds < 0 {
+            return Err(ConversionError::Negative);
+        }
+
+        Ok(Self::new(duration.seconds as u64, duration.milliseconds as u32 * 1_000_000))
+    }
+}
+
+
+/// The error returned when converting between this library’s types and
+/// the standard library’s `std::time` types.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum ConversionError {
+
+    /// The value is negative, but the target type can’t be.
+    Negative,
+
+    /// The value is too large to be represented by the target type.
+    Overflow,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConversionError::Negative  => write!(f, "negative value cannot be converted"),
+            ConversionError::Overflow  => write!(f, "value out of range for conversion"),
+        }
+    }
+}
+
+impl ErrorTrait for ConversionError {
+}
@@ -1,10 +1,14 @@
 //! Exact points on a timeline.
 
+use std::convert::TryFrom;
 use std::fmt;
 use std::ops::{Add, Sub};
+use std::time::{SystemTime, UNIX_EPOCH, Duration as StdDuration};
 
+use cal::datetime::LocalDateTime;
+use cal::fmt::ISO;
 use system::sys_time;
-use duration::Duration;
+use duration::{Duration, ConversionError};
 
 
 /// An **instant** is an exact point on the timeline, irrespective of time
@@ -14,6 +18,12 @@ use duration::Duration;
 /// 16-bit integer of milliseconds. This means that it will overflow (and thus
 /// be unsuitable for) instants past GMT 15:30:08, Sunday 4th December,
 /// 292,277,026,596 (yes, that’s a year)
+///
+/// The millisecond field is always kept in the range 0 to 999, with any
+/// excess carried into (or borrowed from) the seconds field. This means that
+/// an instant half a second *before* the epoch is stored as -1 seconds and
+/// 500 milliseconds, rather than as 0 seconds and -500 milliseconds, which
+/// keeps the derived ordering correct.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct Instant {
     seconds: i64,
@@ -31,7 +41,24 @@ impl Instant {
     /// Creates a new Instant set to the number of seconds since the
     /// Unix epoch, along with the number of milliseconds so far this
     /// second.
+    ///
+    /// Millisecond values outside of the range 0 to 999 get carried over
+    /// into the seconds value, so `Instant::at_ms(0, -1)` is the same as
+    /// `Instant::at_ms(-1, 999)`. This panics if carrying them over would
+    /// overflow the seconds value.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::at_ms(3, 1500), Instant::at_ms(4, 500));
+    /// assert_eq!(Instant::at_ms(0, -1).seconds(), -1);
+    /// assert_eq!(Instant::at_ms(0, -1).milliseconds(), 999);
+    /// ```
     pub fn at_ms(seconds: i64, milliseconds: i16) -> Self {
+        let (carry, milliseconds) = split_milliseconds(milliseconds as i64);
+        let seconds = seconds.checked_add(carry).expect("overflow when carrying milliseconds into seconds");
         Self { seconds, milliseconds }
     }
 
@@ -39,7 +66,7 @@ impl Instant {
     #[cfg_attr(target_os = "redox", allow(unused_unsafe))]
     pub fn now() -> Self {
         let (seconds, milliseconds) = unsafe { sys_time() };
-        Self { seconds, milliseconds }
+        Self::at_ms(seconds, milliseconds)
     }
 
     /// Creates a new Instant set to the Unix epoch.
@@ -56,6 +83,406 @@ impl Instant {
     pub fn milliseconds(&self) -> i16 {
         self.milliseconds
     }
+
+    /// Adds the given duration to this instant, returning `None` if the
+    /// result would overflow.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, Duration};
+    ///
+    /// assert_eq!(Instant::at_ms(1, 750).checked_add(Duration::of_ms(0, 500)),
+    ///            Some(Instant::at_ms(2, 250)));
+    /// assert_eq!(Instant::at(i64::MAX).checked_add(Duration::of(1)), None);
+    /// ```
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let (seconds, milliseconds) = duration.lengths();
+        let (carry, milliseconds) = split_milliseconds(self.milliseconds as i64 + milliseconds as i64);
+
+        self.seconds.checked_add(seconds)
+            .and_then(|s| s.checked_add(carry))
+            .map(|seconds| Self { seconds, milliseconds })
+    }
+
+    /// Subtracts the given duration from this instant, returning `None` if
+    /// the result would overflow.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, Duration};
+    ///
+    /// assert_eq!(Instant::at_ms(2, 250).checked_sub(Duration::of_ms(0, 500)),
+    ///            Some(Instant::at_ms(1, 750)));
+    /// assert_eq!(Instant::at(i64::MIN).checked_sub(Duration::of(1)), None);
+    /// ```
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let (seconds, milliseconds) = duration.lengths();
+        let (carry, milliseconds) = split_milliseconds(self.milliseconds as i64 - milliseconds as i64);
+
+        self.seconds.checked_sub(seconds)
+            .and_then(|s| s.checked_add(carry))
+            .map(|seconds| Self { seconds, milliseconds })
+    }
+
+    /// Returns the length of time that has elapsed between the given
+    /// earlier instant and this one. This is the same as subtracting the
+    /// two instants, so if `earlier` is actually *later* than this
+    /// instant, the resulting duration will be negative.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, Duration};
+    ///
+    /// let start = Instant::at_ms(10, 900);
+    /// let end   = Instant::at_ms(12, 100);
+    /// assert_eq!(end.duration_since(start), Duration::of_ms(1, 200));
+    /// assert_eq!(start.duration_since(end), Duration::of_ms(-2, 800));
+    /// ```
+    pub fn duration_since(self, earlier: Self) -> Duration {
+        self - earlier
+    }
+
+    /// Returns the length of time that has elapsed between the given
+    /// earlier instant and this one, returning `None` if the result would
+    /// overflow.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, Duration};
+    ///
+    /// assert_eq!(Instant::at(1).checked_duration_since(Instant::at(0)), Some(Duration::of(1)));
+    /// assert_eq!(Instant::at(i64::MAX).checked_duration_since(Instant::at(-1)), None);
+    /// ```
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        let (carry, milliseconds) = split_milliseconds(self.milliseconds as i64 - earlier.milliseconds as i64);
+
+        self.seconds.checked_sub(earlier.seconds)
+            .and_then(|s| s.checked_add(carry))
+            .map(|seconds| Duration::of_ms(seconds, milliseconds))
+    }
+}
+
+
+/// Number of seconds between **1st January, 1900**, the NTP epoch, and the
+/// Unix epoch.
+pub(crate) const NTP_EPOCH: i64 = -2_208_988_800;
+
+/// Number of seconds between **1st January, 1601**, the epoch used by
+/// Windows `FILETIME` values, and the Unix epoch.
+const FILETIME_EPOCH: i64 = -11_644_473_600;
+
+/// Number of seconds between **1st January, 0001**, the epoch used by .NET
+/// `DateTime` ticks, and the Unix epoch.
+const DOTNET_EPOCH: i64 = -62_135_596_800;
+
+/// Number of seconds between the Unix epoch and **1st January, 2001**, the
+/// epoch used by Apple’s Cocoa and Core Foundation frameworks.
+const COCOA_EPOCH: i64 = 978_307_200;
+
+/// Number of seconds between the Unix epoch and **6th January, 1980**, the
+/// epoch used by GPS.
+pub(crate) const GPS_EPOCH: i64 = 315_964_800;
+
+/// The Julian Date of the Unix epoch, which is the number of days since
+/// noon UTC on **1st January, 4713 BC** in the proleptic Julian calendar.
+const JULIAN_DATE_EPOCH: f64 = 2_440_587.5;
+
+/// The Modified Julian Date of the Unix epoch, which is the number of days
+/// since **17th November, 1858**.
+const MODIFIED_JULIAN_DATE_EPOCH: f64 = 40_587.0;
+
+/// Number of milliseconds in a day, ignoring leap seconds.
+const MILLISECONDS_IN_DAY: f64 = 86_400_000.0;
+
+/// Number of 100-nanosecond ticks in a second, as used by both `FILETIME`
+/// and .NET.
+const TICKS_IN_SECOND: i64 = 10_000_000;
+
+/// Number of 100-nanosecond ticks in a millisecond.
+const TICKS_IN_MILLISECOND: i64 = 10_000;
+
+
+/// Timestamps in units other than seconds, and in epochs other than the Unix
+/// epoch.
+///
+/// Because instants only have millisecond precision, the constructors here
+/// round any finer units *down*, towards the past, apart from the ones
+/// that take fractional days, which round to the *nearest* millisecond. The
+/// accessors return `None` when the instant can’t be represented in the
+/// target format.
+impl Instant {
+
+    /// Creates a new Instant set to the number of milliseconds since the
+    /// Unix epoch.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::at_unix_millis(-1), Instant::at_ms(-1, 999));
+    /// assert_eq!(Instant::at_ms(-1, 999).unix_millis(), Some(-1));
+    /// ```
+    pub fn at_unix_millis(milliseconds: i64) -> Self {
+        let (seconds, milliseconds) = split_units(milliseconds, 1000);
+        Self { seconds, milliseconds: milliseconds as i16 }
+    }
+
+    /// Creates a new Instant set to the number of microseconds since the
+    /// Unix epoch.
+    pub fn at_unix_micros(microseconds: i64) -> Self {
+        Self::at_unix_millis(split_units(microseconds, 1000).0)
+    }
+
+    /// Creates a new Instant set to the number of nanoseconds since the
+    /// Unix epoch.
+    pub fn at_unix_nanos(nanoseconds: i64) -> Self {
+        Self::at_unix_millis(split_units(nanoseconds, 1_000_000).0)
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch.
+    pub fn unix_millis(&self) -> Option<i64> {
+        self.seconds.checked_mul(1000)?.checked_add(self.milliseconds as i64)
+    }
+
+    /// Returns the number of microseconds since the Unix epoch.
+    pub fn unix_micros(&self) -> Option<i64> {
+        self.unix_millis()?.checked_mul(1000)
+    }
+
+    /// Returns the number of nanoseconds since the Unix epoch.
+    pub fn unix_nanos(&self) -> Option<i64> {
+        self.unix_millis()?.checked_mul(1_000_000)
+    }
+
+    /// Creates a new Instant from a 64-bit NTP timestamp, which holds the
+    /// number of seconds since **1st January, 1900** in its upper 32 bits,
+    /// and the fraction of the second in its lower 32 bits.
+    ///
+    /// NTP timestamps wrap around every 136 years; this assumes the first
+    /// era, which ends in February 2036.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// let instant = Instant::at_ntp(0xE1B6_5F80_8000_0000);
+    /// assert_eq!(instant, Instant::at_ms(1_577_836_800, 500));
+    /// ```
+    pub fn at_ntp(timestamp: u64) -> Self {
+        let seconds = (timestamp >> 32) as i64;
+        let milliseconds = ((timestamp & 0xFFFF_FFFF) * 1000) >> 32;
+        Self::at_ms(seconds + NTP_EPOCH, milliseconds as i16)
+    }
+
+    /// Returns the 64-bit NTP timestamp for this instant, or `None` if it
+    /// lies outside the first NTP era (from 1900 to 2036).
+    pub fn to_ntp(&self) -> Option<u64> {
+        let seconds = self.seconds.checked_sub(NTP_EPOCH)?;
+        if !(0 ..= 0xFFFF_FFFF).contains(&seconds) {
+            return None;
+        }
+
+        // Round the fraction up, so that converting it back rounds down to
+        // the same number of milliseconds.
+        let fraction = (((self.milliseconds as u64) << 32) + 999) / 1000;
+        Some(((seconds as u64) << 32) | fraction)
+    }
+
+    /// Creates a new Instant from a Windows `FILETIME` value, which is the
+    /// number of 100-nanosecond ticks since **1st January, 1601**.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::at_filetime(116_444_736_000_000_000), Instant::at_epoch());
+    /// ```
+    pub fn at_filetime(ticks: u64) -> Self {
+        let seconds = (ticks / TICKS_IN_SECOND as u64) as i64;
+        let ticks = (ticks % TICKS_IN_SECOND as u64) as i64;
+        Self::at_ms(seconds + FILETIME_EPOCH, (ticks / TICKS_IN_MILLISECOND) as i16)
+    }
+
+    /// Returns the Windows `FILETIME` value for this instant, or `None` if
+    /// it lies before 1601.
+    pub fn to_filetime(&self) -> Option<u64> {
+        let ticks = self.ticks_since(FILETIME_EPOCH)?;
+        if ticks < 0 { None } else { Some(ticks as u64) }
+    }
+
+    /// Creates a new Instant from a number of .NET `DateTime` ticks, which
+    /// are 100-nanosecond intervals since **1st January, 0001**.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::at_dotnet_ticks(621_355_968_000_000_000), Instant::at_epoch());
+    /// ```
+    pub fn at_dotnet_ticks(ticks: i64) -> Self {
+        let (seconds, ticks) = split_units(ticks, TICKS_IN_SECOND);
+        Self::at_ms(seconds + DOTNET_EPOCH, (ticks / TICKS_IN_MILLISECOND) as i16)
+    }
+
+    /// Returns the number of .NET `DateTime` ticks for this instant, or
+    /// `None` if it’s too far away from the year 1 to fit.
+    pub fn to_dotnet_ticks(&self) -> Option<i64> {
+        self.ticks_since(DOTNET_EPOCH)
+    }
+
+    /// Creates a new Instant from a Cocoa or Core Foundation absolute time,
+    /// which is a floating-point number of seconds since **1st January,
+    /// 2001**, or `None` if it isn’t finite or is too large to fit.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::at_cocoa(-0.25), Some(Instant::at_ms(978_307_199, 750)));
+    /// assert_eq!(Instant::at_cocoa(1e300), None);
+    /// ```
+    pub fn at_cocoa(seconds: f64) -> Option<Self> {
+        let milliseconds = (seconds * 1000.0).floor();
+        if !milliseconds.is_finite() || milliseconds.abs() >= i64::MAX as f64 {
+            return None;
+        }
+
+        Self::at_unix_millis(milliseconds as i64).checked_add(Duration::of(COCOA_EPOCH))
+    }
+
+    /// Returns the Cocoa or Core Foundation absolute time for this instant.
+    pub fn to_cocoa(&self) -> f64 {
+        (self.seconds as f64 - COCOA_EPOCH as f64) + self.milliseconds as f64 / 1000.0
+    }
+
+    /// Creates a new Instant set to the number of seconds since the GPS
+    /// epoch, **6th January, 1980**, or `None` if it’s too large to fit.
+    ///
+    /// Like the rest of `Instant`, this ignores leap seconds, so it counts
+    /// *UTC* seconds from the epoch rather than seconds of GPS time, which
+    /// has drifted ahead of UTC by every leap second since. To convert
+    /// actual GPS time, use a `LeapSecondTable`.
+    pub fn at_seconds_since_gps_epoch(seconds: i64) -> Option<Self> {
+        seconds.checked_add(GPS_EPOCH).map(Self::at)
+    }
+
+    /// Returns the number of seconds since the GPS epoch, ignoring leap
+    /// seconds, along with the number of milliseconds so far this second,
+    /// or `None` if it’s too far before the epoch to fit.
+    pub fn seconds_since_gps_epoch(&self) -> Option<(i64, i16)> {
+        self.seconds.checked_sub(GPS_EPOCH).map(|seconds| (seconds, self.milliseconds))
+    }
+
+    /// Creates a new Instant from a Julian Date, which is a fractional
+    /// number of days since noon UTC on **1st January, 4713 BC** in the
+    /// proleptic Julian calendar, or `None` if it isn’t finite or is too
+    /// large to fit.
+    ///
+    /// Like the rest of `Instant`, this ignores leap seconds, so every day
+    /// is exactly 86,400 seconds long.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// // The J2000.0 epoch is noon on the 1st of January, 2000.
+    /// assert_eq!(Instant::at_julian_date(2_451_545.0), Some(Instant::at(946_728_000)));
+    /// assert_eq!(Instant::at(946_728_000).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(Instant::at_julian_date(f64::NAN), None);
+    /// ```
+    pub fn at_julian_date(days: f64) -> Option<Self> {
+        Self::at_days_since(days, JULIAN_DATE_EPOCH)
+    }
+
+    /// Returns the Julian Date for this instant.
+    pub fn to_julian_date(&self) -> f64 {
+        self.days_since(JULIAN_DATE_EPOCH)
+    }
+
+    /// Creates a new Instant from a Modified Julian Date, which is a
+    /// fractional number of days since midnight UTC on **17th November,
+    /// 1858**, or `None` if it isn’t finite or is too large to fit. It’s
+    /// the Julian Date minus 2,400,000.5.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::Instant;
+    ///
+    /// assert_eq!(Instant::at_modified_julian_date(40_587.25), Some(Instant::at(21_600)));
+    /// assert_eq!(Instant::at_modified_julian_date(f64::INFINITY), None);
+    /// ```
+    pub fn at_modified_julian_date(days: f64) -> Option<Self> {
+        Self::at_days_since(days, MODIFIED_JULIAN_DATE_EPOCH)
+    }
+
+    /// Returns the Modified Julian Date for this instant.
+    pub fn to_modified_julian_date(&self) -> f64 {
+        self.days_since(MODIFIED_JULIAN_DATE_EPOCH)
+    }
+
+    /// Creates a new Instant from a fractional number of days since an
+    /// epoch, given as the number of days at the Unix epoch.
+    ///
+    /// A millisecond isn’t a whole binary fraction of a day, so the number
+    /// of days for an exact millisecond is usually a tiny bit off, and
+    /// rounding down would lose a millisecond about half the time.
+    fn at_days_since(days: f64, epoch: f64) -> Option<Self> {
+        let milliseconds = ((days - epoch) * MILLISECONDS_IN_DAY).round();
+        if !milliseconds.is_finite() || milliseconds.abs() >= i64::MAX as f64 {
+            return None;
+        }
+
+        Some(Self::at_unix_millis(milliseconds as i64))
+    }
+
+    /// Returns the fractional number of days between an epoch, given as the
+    /// number of days at the Unix epoch, and this instant.
+    fn days_since(&self, epoch: f64) -> f64 {
+        let milliseconds = self.seconds as f64 * 1000.0 + self.milliseconds as f64;
+        milliseconds / MILLISECONDS_IN_DAY + epoch
+    }
+
+    /// Returns the number of 100-nanosecond ticks between the given epoch
+    /// (in seconds relative to the Unix epoch) and this instant.
+    fn ticks_since(&self, epoch: i64) -> Option<i64> {
+        self.seconds.checked_sub(epoch)?
+            .checked_mul(TICKS_IN_SECOND)?
+            .checked_add(self.milliseconds as i64 * TICKS_IN_MILLISECOND)
+    }
+}
+
+/// Splits a number of milliseconds into a number of whole seconds and the
+/// leftover milliseconds, with the leftover always being in the range 0 to
+/// 999 (so negative values borrow from the seconds instead).
+fn split_milliseconds(milliseconds: i64) -> (i64, i16) {
+    let (seconds, remainder) = split_units(milliseconds, 1000);
+    (seconds, remainder as i16)
+}
+
+/// Splits a count of some unit into a number of whole larger units and the
+/// leftover, which is always positive, so negative counts get rounded down
+/// rather than towards zero.
+fn split_units(count: i64, units_per_whole: i64) -> (i64, i64) {
+    let mut wholes    = count / units_per_whole;
+    let mut remainder = count % units_per_whole;
+
+    if remainder < 0 {
+        remainder += units_per_whole;
+        wholes    -= 1;
+    }
+
+    (wholes, remainder)
 }
 
 impl fmt::Debug for Instant {
@@ -64,15 +491,24 @@ impl fmt::Debug for Instant {
     }
 }
 
+/// Instants are displayed as RFC 3339 timestamps in UTC, such as
+/// `2009-02-13T23:31:30.000Z`.
+///
+/// RFC 3339 only covers the years 0000 to 9999. Years outside that range
+/// are written in the expanded form from ISO 8601 instead, with a sign and
+/// as many digits as they need, such as `+10000-01-01T00:00:00.000Z` or
+/// `-0001-12-31T23:59:59.000Z`, and these aren’t valid RFC 3339.
+impl fmt::Display for Instant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}Z", LocalDateTime::from_instant(*self).iso())
+    }
+}
+
 impl Add<Duration> for Instant {
     type Output = Self;
 
     fn add(self, duration: Duration) -> Self {
-        let (seconds, milliseconds) = duration.lengths();
-        Self {
-            seconds: self.seconds + seconds,
-            milliseconds: self.milliseconds + milliseconds,
-        }
+        self.checked_add(duration).expect("overflow when adding duration to instant")
     }
 }
 
@@ -80,10 +516,68 @@ impl Sub<Duration> for Instant {
     type Output = Self;
 
     fn sub(self, duration: Duration) -> Self {
-        let (seconds, milliseconds) = duration.lengths();
-        Self {
-            seconds: self.seconds - seconds,
-            milliseconds: self.milliseconds - milliseconds,
+        self.checked_sub(duration).expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    fn sub(self, earlier: Self) -> Duration {
+        self.checked_duration_since(earlier).expect("overflow when subtracting instants")
+    }
+}
+
+
+/// System times before the Unix epoch are supported, and any precision
+/// finer than a millisecond is rounded *down*, towards the past, so a
+/// system time is never converted to an instant after it.
+///
+/// An instant can hold any system time on Linux, macOS, and Windows. On a
+/// platform whose system times reach further from the epoch than that,
+/// the ones out of range are clamped to the earliest or latest instant.
+impl From<SystemTime> for Instant {
+    fn from(time: SystemTime) -> Self {
+        let milliseconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => {
+                i128::from(after.as_secs()) * 1000 + i128::from(after.subsec_millis())
+            }
+            Err(e) => {
+                // Round the fraction of a millisecond up before negating
+                // it, so the result gets rounded down.
+                let before = e.duration();
+                let milliseconds = before.subsec_millis() + if before.subsec_nanos() % 1_000_000 == 0 { 0 } else { 1 };
+                -(i128::from(before.as_secs()) * 1000 + i128::from(milliseconds))
+            }
+        };
+
+        match i64::try_from(milliseconds.div_euclid(1000)) {
+            Ok(seconds)                => Self { seconds, milliseconds: milliseconds.rem_euclid(1000) as i16 },
+            Err(_) if milliseconds < 0 => Self { seconds: i64::MIN, milliseconds: 0 },
+            Err(_)                     => Self { seconds: i64::MAX, milliseconds: 999 },
         }
     }
 }
+
+/// This fails if the instant lies outside the range of times that the
+/// platform’s `SystemTime` is able to represent.
+impl TryFrom<Instant> for SystemTime {
+    type Error = ConversionError;
+
+    fn try_from(instant: Instant) -> Result<Self, Self::Error> {
+        let milliseconds = StdDuration::from_millis(instant.milliseconds as u64);
+
+        let seconds = if instant.seconds >= 0 {
+            UNIX_EPOCH.checked_add(StdDuration::from_secs(instant.seconds as u64))
+        }
+        else {
+            // Negating i64::MIN would overflow, so add one second back
+            // on afterwards instead.
+            let before = (-(instant.seconds + 1)) as u64 + 1;
+            UNIX_EPOCH.checked_sub(StdDuration::from_secs(before))
+        };
+
+        seconds.and_then(|t| t.checked_add(milliseconds))
+               .ok_or(ConversionError::Overflow)
+    }
+}
@@ -0,0 +1,327 @@
+//! Half-open intervals of time, and sets of them.
+
+use std::iter::{once, FromIterator};
+use std::slice;
+
+use cal::datetime::{LocalDate, LocalDateTime, Error};
+use cal::range::DateSpan;
+use duration::Duration;
+use instant::Instant;
+
+
+/// An **interval** is a span of time from a start point up to, but not
+/// including, an end point. The points can be instants, dates, or
+/// datetimes.
+///
+/// Because the end point is excluded, two intervals where one ends at the
+/// same point that the other starts don’t overlap, and an interval that
+/// starts and ends at the same point is empty.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{Interval, LocalDate, Month};
+///
+/// let date = |day| LocalDate::ymd(2017, Month::August, day).unwrap();
+/// let first = Interval::new(date(1), date(10)).unwrap();
+/// let second = Interval::new(date(5), date(20)).unwrap();
+///
+/// assert!(first.overlaps(&second));
+/// assert_eq!(first.intersection(&second), Interval::new(date(5), date(10)).ok());
+/// assert_eq!(first.union(&second), Interval::new(date(1), date(20)).ok());
+/// assert_eq!(first.days(), 9);
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Ord + Copy> Interval<T> {
+
+    /// Creates a new interval from the start point up to the end point.
+    /// This returns an error if the end is before the start.
+    pub fn new(start: T, end: T) -> Result<Self, Error> {
+        if start <= end {
+            Ok(Self { start, end })
+        }
+        else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Returns the point that this interval starts at.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// Returns the point that this interval ends at, which is the first
+    /// point that isn’t included in it.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Returns whether this interval is empty, because it starts and ends
+    /// at the same point.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns whether the given point is in this interval.
+    pub fn contains(&self, point: T) -> bool {
+        self.start <= point && point < self.end
+    }
+
+    /// Returns whether every point in the other interval is also in this
+    /// one.
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns whether this interval shares any points with the other one.
+    /// Intervals that only touch at their ends don’t overlap, and empty
+    /// intervals never overlap anything.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the interval of points that are in both this interval and
+    /// the other one, or `None` if they don’t overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        if start < end { Some(Self { start, end }) } else { None }
+    }
+
+    /// Returns the interval of points that are in either this interval or
+    /// the other one, or `None` if there’s a gap between them, as the
+    /// result wouldn’t be a single interval. Intervals that touch at their
+    /// ends can be joined.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.gap(other).is_some() {
+            None
+        }
+        else {
+            Some(self.span(other))
+        }
+    }
+
+    /// Returns the smallest interval that covers both this interval and
+    /// the other one, including any gap between them.
+    pub fn span(&self, other: &Self) -> Self {
+        Self { start: self.start.min(other.start), end: self.end.max(other.end) }
+    }
+
+    /// Returns the interval between this interval and the other one, or
+    /// `None` if they overlap or touch.
+    pub fn gap(&self, other: &Self) -> Option<Self> {
+        if self.end < other.start {
+            Some(Self { start: self.end, end: other.start })
+        }
+        else if other.end < self.start {
+            Some(Self { start: other.end, end: self.start })
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl Interval<Instant> {
+
+    /// Returns the length of time that this interval lasts.
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+impl Interval<LocalDateTime> {
+
+    /// Returns the length of time that this interval lasts, as measured by
+    /// a clock that never changes, such as one in UTC.
+    pub fn duration(&self) -> Duration {
+        self.end.to_instant() - self.start.to_instant()
+    }
+}
+
+impl Interval<LocalDate> {
+
+    /// Returns the number of days in this interval.
+    pub fn days(&self) -> i64 {
+        self.start.days_until(self.end)
+    }
+}
+
+impl DateSpan for Interval<LocalDate> {
+    fn get_bounds(&self) -> (LocalDate, LocalDate) {
+        (self.start, self.end)
+    }
+}
+
+
+/// An **interval set** is a set of points in time, stored as a list of
+/// intervals. Intervals that overlap or touch get merged together, so the
+/// list is always in order with gaps between each interval.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{Interval, IntervalSet, Instant};
+///
+/// let interval = |start, end| Interval::new(Instant::at(start), Instant::at(end)).unwrap();
+///
+/// let mut bookings = IntervalSet::new();
+/// bookings.insert(interval(100, 200));
+/// bookings.insert(interval(150, 300));
+/// bookings.insert(interval(400, 500));
+/// assert_eq!(bookings.len(), 2);
+/// assert!(bookings.overlaps(&interval(250, 260)));
+///
+/// let mut free = IntervalSet::from(interval(0, 600));
+/// free.remove_all(&bookings);
+/// assert_eq!(free.iter().cloned().collect::<Vec<_>>(), vec![ interval(0, 100), interval(300, 400), interval(500, 600) ]);
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+
+    /// Creates a new, empty, interval set.
+    pub fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    /// Returns the number of separate intervals in this set.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns whether this set contains no points at all.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns an iterator over the intervals in this set, in order.
+    pub fn iter(&self) -> slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Adds every point in the given interval to this set, merging it with
+    /// any intervals that it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+
+        self.intervals.splice(first .. last, once(merged));
+    }
+
+    /// Removes every point in the given interval from this set, which can
+    /// split an interval in two.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|i| i.end <= interval.start);
+        let last = self.intervals.partition_point(|i| i.start < interval.end);
+        if first >= last {
+            return;
+        }
+
+        let before = Interval { start: self.intervals[first].start, end: interval.start };
+        let after = Interval { start: interval.end, end: self.intervals[last - 1].end };
+        let pieces = [ before, after ].iter().filter(|i| i.start < i.end).cloned().collect::<Vec<_>>();
+        self.intervals.splice(first .. last, pieces);
+    }
+
+    /// Adds every point in the other set to this one.
+    pub fn insert_all(&mut self, other: &Self) {
+        for interval in other.iter() {
+            self.insert(*interval);
+        }
+    }
+
+    /// Removes every point in the other set from this one.
+    pub fn remove_all(&mut self, other: &Self) {
+        for interval in other.iter() {
+            self.remove(*interval);
+        }
+    }
+
+    /// Returns whether the given point is in this set.
+    pub fn contains(&self, point: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= point);
+        self.intervals.get(index).map_or(false, |i| i.start <= point)
+    }
+
+    /// Returns whether any point in the given interval is in this set.
+    pub fn overlaps(&self, interval: &Interval<T>) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= interval.start);
+        self.intervals.get(index).map_or(false, |i| i.overlaps(interval))
+    }
+
+    /// Returns the set of points that are in either this set or the other.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.insert_all(other);
+        result
+    }
+
+    /// Returns the set of points that are in both this set and the other.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.iter().flat_map(|a| other.iter().filter_map(move |b| a.intersection(b))).collect()
+    }
+
+    /// Returns the set of points that are in this set but not the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.remove_all(other);
+        result
+    }
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Copy> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+
+        set
+    }
+}
+
+impl<'a, T: Ord + Copy> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -17,21 +17,44 @@ extern crate libc;  // used in the system module
 
 mod cal;
 pub use cal::{DatePiece, TimePiece};
-pub use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year, YearMonth};
+pub use cal::age::{Anniversaries, LeapDayPolicy};
+pub use cal::business::{BusinessCalendar, Holiday, Observance};
+pub use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year, YearMonth, YearWeek, Quarter, YearQuarter};
+pub use cal::easter::{Computus, MovableFeast};
+pub use cal::fiscal::{FiscalCalendar, FiscalPeriod, FiscalYear, FiscalYearLabel, RetailCalendar, RetailPattern, RetailYearEnd};
 #[cfg(feature="format")] pub use cal::fmt::custom as fmt;
 pub use cal::fmt::iso::ISO;  // TODO: replace this with just a 'fmt' import
+pub use cal::hebrew::{HebrewDate, HebrewMonth};
+#[cfg(feature="parse")] pub use cal::iso_interval::{IsoInterval, IntervalPoint, RepeatingInterval};
+pub use cal::julian::{JulianDate, HistoricalDate, Cutover};
 pub use cal::offset::{Offset, OffsetDateTime};
+pub use cal::period::Period;
+pub use cal::range::{DateRange, DateSpan, DateStep};
+pub use cal::spreadsheet::{SpreadsheetDateSystem, Error as SpreadsheetError};
+pub use cal::unit::Unit;
+pub use cal::week::WeekRule;
 pub use cal::zone::{TimeZone, ZonedDateTime};
 pub use cal::zone as zone;
 
 pub use cal::convenience;
 
 mod duration;
-pub use duration::Duration;
+pub use duration::{Duration, ConversionError};
 
 mod instant;
 pub use instant::Instant;
 
+mod interval;
+pub use interval::{Interval, IntervalSet};
+
+pub mod recur;
+pub use recur::{Recurrence, RecurrenceRule, DstPolicy};
+pub use recur::calendar::CalendarEvent;
+pub use recur::cron::CronSchedule;
+
+pub mod scale;
+pub use scale::{TimeScale, LeapSecond, LeapSecondTable};
+
 mod system;
 pub use system::sys_timezone;
 
@@ -0,0 +1,486 @@
+//! Calendar events, as used by systemd timers.
+//!
+//! A calendar event, as described in `systemd.time(7)`, has up to four
+//! parts, separated by spaces, any of which can be left out:
+//!
+//! ```text
+//! Mon..Fri 2017-*-01 09:00:00 Europe/London
+//! │        │         │        └─ time zone
+//! │        │         └─ time: hour:minute, or hour:minute:second
+//! │        └─ date: year-month-day, or month-day
+//! └─ weekdays
+//! ```
+//!
+//! Leaving out the weekdays or the date matches every day, and leaving out
+//! the time, or just the seconds, matches the start of the day or minute.
+//!
+//! Weekdays are written as English names or their three-letter
+//! abbreviations, separated by commas, with ranges written as `Mon..Fri`.
+//! Each component of the date and time is a comma-separated list of values
+//! (`5`), ranges (`1..5`), or a `*` for every value, any of which can be
+//! followed by a repetition (`*:0/15`, `1..30/2`). Writing the day with a
+//! `~` rather than a `-` counts from the end of the month, so `*-02~01` is
+//! the last day of February and `Mon *-05~07/1` is the last Monday in May.
+//! Seconds must be whole.
+//!
+//! The words `minutely`, `hourly`, `daily`, `weekly`, `monthly`,
+//! `quarterly`, `semiannually`, and `yearly` (or `annually`) can be used as
+//! shorthands, optionally followed by a time zone.
+
+use std::borrow::Cow;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Weekday, Year};
+use cal::unit::Unit;
+use cal::zone::{FixedTimespan, FixedTimespanSet, StaticTimeZone, TimeZone, TimeZoneSource, ZonedDateTime};
+use instant::Instant;
+use recur::DAYS_IN_CYCLE;
+use recur::dst::{self, DstPolicy};
+
+
+/// A parsed systemd **calendar event**, which can find the next time a
+/// timer using it should elapse.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, CalendarEvent};
+///
+/// let event: CalendarEvent = "Mon..Fri *-*-* 09:00:00".parse().unwrap();
+///
+/// // Saturday morning
+/// let now = LocalDateTime::new(LocalDate::ymd(2017, Month::August, 19).unwrap(), LocalTime::hm(8, 0).unwrap());
+/// let next = LocalDateTime::new(LocalDate::ymd(2017, Month::August, 21).unwrap(), LocalTime::hm(9, 0).unwrap());
+/// assert_eq!(event.next_elapse(now), Some(next));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CalendarEvent {
+    weekdays: u8,
+    years: Component,
+    months: Component,
+    days: Component,
+    days_from_end: bool,
+    hours: Component,
+    minutes: Component,
+    seconds: Component,
+    time_zone: Option<String>,
+}
+
+/// The values that one component of a calendar event matches. An empty
+/// list of repetitions matches every value.
+#[derive(PartialEq, Eq, Debug, Clone)]
+struct Component(Vec<Repetition>);
+
+/// Every `step`th value from `start` up to and including `end`. A single
+/// value is a repetition with the same start and end.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+struct Repetition {
+    start: i64,
+    end: i64,
+    step: i64,
+}
+
+impl Component {
+    fn any() -> Self {
+        Component(Vec::new())
+    }
+
+    fn zero() -> Self {
+        Component(vec![ Repetition { start: 0, end: 0, step: 1 } ])
+    }
+
+    fn contains(&self, value: i64) -> bool {
+        self.0.is_empty() || self.0.iter().any(|r| {
+            (r.start ..= r.end).contains(&value) && (value - r.start) % r.step == 0
+        })
+    }
+}
+
+
+/// The UTC time zone, which is the only one that an event can name that
+/// doesn’t need to be looked up.
+static UTC: StaticTimeZone<'static> = StaticTimeZone {
+    name: "UTC",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("UTC"),
+        },
+        rest: &[],
+    },
+};
+
+
+impl CalendarEvent {
+
+    /// Returns the name of the time zone given at the end of the event, if
+    /// there is one.
+    pub fn time_zone(&self) -> Option<&str> {
+        self.time_zone.as_deref()
+    }
+
+    /// Returns whether this event elapses on the given date, at any time.
+    pub fn matches_date(&self, date: LocalDate) -> bool {
+        let day = if self.days_from_end {
+            Year(date.year()).month(date.month()).day_count() - date.day() + 1
+        }
+        else {
+            date.day()
+        };
+
+        self.weekdays & (1 << date.weekday() as u8) != 0
+            && self.years.contains(date.year())
+            && self.months.contains(date.month() as i64)
+            && self.days.contains(day as i64)
+    }
+
+    /// Returns whether this event elapses at exactly the given time.
+    pub fn matches(&self, datetime: LocalDateTime) -> bool {
+        datetime.millisecond() == 0
+            && self.seconds.contains(datetime.second() as i64)
+            && self.minutes.contains(datetime.minute() as i64)
+            && self.hours.contains(datetime.hour() as i64)
+            && self.matches_date(datetime.date())
+    }
+
+    /// Returns the first time in a day that this event elapses at, after
+    /// the given number of seconds since midnight.
+    fn next_time(&self, after: i64) -> Option<LocalTime> {
+        let mut seconds = after + 1;
+
+        while seconds < 86400 {
+            if !self.hours.contains(seconds / 3600) {
+                seconds = (seconds / 3600 + 1) * 3600;
+            }
+            else if !self.minutes.contains(seconds / 60 % 60) {
+                seconds = (seconds / 60 + 1) * 60;
+            }
+            else if !self.seconds.contains(seconds % 60) {
+                seconds += 1;
+            }
+            else {
+                // The number of seconds is always less than a day’s worth.
+                return LocalTime::hms((seconds / 3600) as i8, (seconds / 60 % 60) as i8, (seconds % 60) as i8).ok();
+            }
+        }
+
+        None
+    }
+
+    /// Returns the first local time after the given one that this event
+    /// elapses at, or `None` if it never does again. Any time zone given
+    /// in the event is ignored.
+    pub fn next_elapse(&self, after: LocalDateTime) -> Option<LocalDateTime> {
+        let mut date = after.date();
+        let mut seconds = after.hour() as i64 * 3600 + after.minute() as i64 * 60 + after.second() as i64;
+        let last = date.add_days(DAYS_IN_CYCLE);
+
+        while date <= last {
+            if !self.years.contains(date.year()) {
+                // The 1st of January always exists.
+                date = LocalDate::yd(date.year() + 1, 1).unwrap();
+            }
+            else if !self.months.contains(date.month() as i64) {
+                date = date.end_of(Unit::Month).add_days(1);
+            }
+            else {
+                if self.matches_date(date) {
+                    if let Some(time) = self.next_time(seconds) {
+                        return Some(LocalDateTime::new(date, time));
+                    }
+                }
+
+                date = date.add_days(1);
+            }
+
+            seconds = -1;
+        }
+
+        None
+    }
+
+    /// Returns the first time after the given instant that this event
+    /// elapses at, using the given time zone unless the event says to use
+    /// UTC. The result is in the given time zone.
+    ///
+    /// As with systemd, a time that the clocks skip over is moved forward by
+    /// the length of the gap, and a time that the clocks show twice elapses
+    /// only the first time.
+    pub fn next_elapse_in(&self, after: Instant, zone: &TimeZone) -> Option<ZonedDateTime<'static>> {
+        let utc = TimeZone(TimeZoneSource::Static(&UTC));
+        let event_zone = if self.time_zone() == Some("UTC") { &utc } else { zone };
+
+        dst::next_instant(event_zone, DstPolicy::once(), after, |local| self.next_elapse(local))
+            .map(|instant| zone.zoned_at(instant))
+    }
+
+    /// Returns the first time after the given one that this event elapses
+    /// at, in the same time zone.
+    ///
+    /// If the event names a time zone other than UTC, it’s up to the caller
+    /// to load that zone and convert the datetime into it first, as this
+    /// library can’t look time zones up by name.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, CalendarEvent, TimeZone, DatePiece, TimePiece, Month};
+    /// use datetime::zone::{TimeZoneSource, StaticTimeZone, FixedTimespanSet, FixedTimespan};
+    /// use std::borrow::Cow;
+    ///
+    /// static ZONE: StaticTimeZone<'static> = StaticTimeZone {
+    ///     name: "Example",
+    ///     fixed_timespans: FixedTimespanSet {
+    ///         first: FixedTimespan { offset: 3600, is_dst: false, name: Cow::Borrowed("A") },
+    ///         rest: &[],
+    ///     },
+    /// };
+    ///
+    /// let zone = TimeZone(TimeZoneSource::Static(&ZONE));
+    /// let now = zone.zoned_at(Instant::at(1503140400));  // 2017-08-19 12:00:00 +01:00
+    ///
+    /// let event: CalendarEvent = "quarterly".parse().unwrap();
+    /// let next = event.next_elapse_zoned(&now).unwrap();
+    /// assert_eq!((next.month(), next.day(), next.hour()), (Month::October, 1, 0));
+    ///
+    /// let event: CalendarEvent = "daily UTC".parse().unwrap();
+    /// let next = event.next_elapse_zoned(&now).unwrap();
+    /// assert_eq!((next.day(), next.hour()), (20, 1));
+    /// ```
+    pub fn next_elapse_zoned(&self, after: &ZonedDateTime<'static>) -> Option<ZonedDateTime<'static>> {
+        self.next_elapse_in(after.to_instant(), &after.time_zone())
+    }
+}
+
+
+impl FromStr for CalendarEvent {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words: Vec<&str> = input.split_whitespace().collect();
+        if words.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if let Some(expansion) = shorthand(words[0]) {
+            words.splice(0 .. 1, expansion.split(' '));
+        }
+
+        let mut event = Self {
+            weekdays:       0x7F,
+            years:          Component::any(),
+            months:         Component::any(),
+            days:           Component::any(),
+            days_from_end:  false,
+            hours:          Component::zero(),
+            minutes:        Component::zero(),
+            seconds:        Component::zero(),
+            time_zone:      None,
+        };
+
+        let invalid = |word: &str| Error::InvalidWord(word.to_string());
+        let mut words = words.into_iter().peekable();
+
+        if let Some(word) = words.next_if(|w| w.starts_with(|c: char| c.is_ascii_alphabetic())) {
+            event.weekdays = weekdays(word).ok_or_else(|| invalid(word))?;
+        }
+
+        if let Some(word) = words.next_if(|w| w.starts_with(|c: char| c.is_ascii_digit() || c == '*') && w.contains(['-', '~'])) {
+            date(word, &mut event).ok_or_else(|| invalid(word))?;
+        }
+
+        if let Some(word) = words.next_if(|w| w.contains(':')) {
+            time(word, &mut event).ok_or_else(|| invalid(word))?;
+        }
+
+        if let Some(word) = words.next() {
+            if !is_time_zone_name(word) {
+                return Err(invalid(word));
+            }
+
+            event.time_zone = Some(word.to_string());
+        }
+
+        match words.next() {
+            Some(word)  => Err(invalid(word)),
+            None        => Ok(event),
+        }
+    }
+}
+
+/// Returns what a shorthand word stands for, if it is one.
+fn shorthand(word: &str) -> Option<&'static str> {
+    Some(match &*word.to_ascii_lowercase() {
+        "minutely"               => "*-*-* *:*:00",
+        "hourly"                 => "*-*-* *:00:00",
+        "daily"                  => "*-*-* 00:00:00",
+        "weekly"                 => "Mon *-*-* 00:00:00",
+        "monthly"                => "*-*-01 00:00:00",
+        "quarterly"              => "*-01,04,07,10-01 00:00:00",
+        "semiannually"           => "*-01,07-01 00:00:00",
+        "yearly" | "annually"    => "*-01-01 00:00:00",
+        _                        => return None,
+    })
+}
+
+/// Returns whether a word could name a time zone: either `UTC`, or a name
+/// from the time zone database in its `Area/Location` form, such as
+/// `Europe/London` or `America/Argentina/Buenos_Aires`.
+fn is_time_zone_name(word: &str) -> bool {
+    word == "UTC" || (word.contains('/') && word.split('/').all(|part| {
+        part.starts_with(|c: char| c.is_ascii_alphabetic())
+        && part.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    }))
+}
+
+/// Parses a list of weekdays and weekday ranges into a bit set, where bit
+/// *n* is set if the weekday with number *n*, counting from Sunday as 0,
+/// is in the list.
+fn weekdays(input: &str) -> Option<u8> {
+    // Ranges follow systemd in counting the week from Monday.
+    let from_monday = |name: &str| weekday(name).map(|w| (w as i8 + 6) % 7);
+
+    input.split(',').try_fold(0, |bits, item| {
+        let (start, end) = match item.split_once("..") {
+            Some((start, end))  => (from_monday(start)?, from_monday(end)?),
+            None                => (from_monday(item)?, from_monday(item)?),
+        };
+
+        if start > end {
+            return None;
+        }
+
+        Some((start ..= end).fold(bits, |bits, day| bits | 1 << ((day + 1) % 7)))
+    })
+}
+
+fn weekday(name: &str) -> Option<Weekday> {
+    const NAMES: [&str; 7] = [ "sunday", "monday", "tuesday", "wednesday", "thursday", "friday", "saturday" ];
+
+    let name = name.to_ascii_lowercase();
+    let index = NAMES.iter().position(|n| *n == name || (name.len() == 3 && n.starts_with(&*name)))?;
+    Weekday::from_zero(index as i8).ok()
+}
+
+/// Parses the date part of an event, which has an optional year, a month,
+/// and a day that can count from either end of the month.
+fn date(input: &str, event: &mut CalendarEvent) -> Option<()> {
+    let (rest, day, from_end) = match input.split_once('~') {
+        Some((rest, day))  => (rest, day, true),
+        None               => {
+            let (rest, day) = input.rsplit_once('-')?;
+            (rest, day, false)
+        },
+    };
+
+    let (year, month) = match rest.split_once('-') {
+        Some((year, month))  => (Some(year), month),
+        None                 => (None, rest),
+    };
+
+    if let Some(year) = year {
+        event.years = component(year, 1970, 2199, false)?;
+    }
+
+    event.months = component(month, 1, 12, false)?;
+    event.days = component(day, 1, 31, from_end)?;
+    event.days_from_end = from_end;
+    Some(())
+}
+
+/// Parses the time part of an event, which has an hour, a minute, and an
+/// optional second.
+fn time(input: &str, event: &mut CalendarEvent) -> Option<()> {
+    let parts: Vec<&str> = input.split(':').collect();
+    if parts.len() != 2 && parts.len() != 3 {
+        return None;
+    }
+
+    event.hours = component(parts[0], 0, 23, false)?;
+    event.minutes = component(parts[1], 0, 59, false)?;
+    event.seconds = match parts.get(2) {
+        Some(seconds)  => component(seconds, 0, 59, false)?,
+        None           => Component::zero(),
+    };
+
+    Some(())
+}
+
+/// Parses one component of a date or time, which is a comma-separated
+/// list of values, ranges, and repetitions between the given bounds.
+///
+/// A repetition from a single value normally counts upwards from it, but
+/// for days counted from the end of the month, it counts *downwards*,
+/// towards the end of the month.
+fn component(input: &str, min: i64, max: i64, downwards: bool) -> Option<Component> {
+    if input == "*" {
+        return Some(Component::any());
+    }
+
+    let repetitions = input.split(',').map(|item| {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step))  => (range, Some(step.parse().ok().filter(|&s: &i64| s > 0)?)),
+            None                 => (item, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        }
+        else if let Some((start, end)) = range.split_once("..") {
+            (number(start)?, number(end)?)
+        }
+        else {
+            let value = number(range)?;
+            match step {
+                Some(step) if downwards  => ((value - min) % step + min, value),
+                Some(_)                  => (value, max),
+                None                     => (value, value),
+            }
+        };
+
+        if start < min || end > max || start > end {
+            return None;
+        }
+
+        Some(Repetition { start, end, step: step.unwrap_or(1) })
+    });
+
+    repetitions.collect::<Option<_>>().map(Component)
+}
+
+fn number(input: &str) -> Option<i64> {
+    if input.is_empty() || !input.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    input.parse().ok()
+}
+
+
+/// An error that can occur when parsing a calendar event.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+
+    /// The event is empty.
+    Empty,
+
+    /// A word of the event can’t be parsed, or has a value that’s out of
+    /// range. This contains the word.
+    InvalidWord(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Empty               => write!(f, "calendar event is empty"),
+            Error::InvalidWord(ref s)  => write!(f, "invalid calendar event part ‘{}’", s),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+}
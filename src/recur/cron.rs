@@ -0,0 +1,493 @@
+//! Cron expressions, for running jobs on a schedule.
+//!
+//! A cron expression is a list of fields separated by spaces, each of which
+//! limits the times that a job runs at. The standard form has five fields:
+//!
+//! ```text
+//! ┌─ minute (0–59)
+//! │ ┌─ hour (0–23)
+//! │ │ ┌─ day of the month (1–31)
+//! │ │ │ ┌─ month (1–12, or JAN–DEC)
+//! │ │ │ │ ┌─ day of the week (0–7, or SUN–SAT, where 0 and 7 are Sunday)
+//! * * * * *
+//! ```
+//!
+//! An extra field for the second (0–59) can be put at the start, giving six
+//! fields. Without it, jobs run at the start of the minute.
+//!
+//! Each field is a comma-separated list of values (`5`), ranges (`1-5`), or
+//! a `*` for every value, any of which can be followed by a step (`*/15`,
+//! `1-30/2`). A single value with a step, such as `5/15`, runs from that
+//! value to the end of the field. A `?` is the same as `*` in the two day
+//! fields.
+//!
+//! The day-of-the-month field also accepts:
+//!
+//! - `L` for the last day of the month, or `L-3` for three days before it;
+//! - `15W` for the weekday (Monday to Friday) nearest the 15th, without
+//!   leaving the month;
+//! - `LW` for the last weekday of the month.
+//!
+//! The day-of-the-week field also accepts:
+//!
+//! - `5L` or `FRIL` for the last Friday of the month;
+//! - `5#3` or `FRI#3` for the third Friday of the month.
+//!
+//! As in other cron implementations, when *both* day fields are restricted
+//! (neither starts with `*` or `?`), a day matches if *either* of them
+//! does, so `0 0 1 * MON` runs on the 1st of every month and every Monday.
+//!
+//! The expressions `@yearly` (or `@annually`), `@monthly`, `@weekly`,
+//! `@daily` (or `@midnight`), and `@hourly` can be used as shorthands.
+
+use std::error::Error as ErrorTrait;
+use std::fmt;
+use std::str::FromStr;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Weekday, Year};
+use cal::unit::Unit;
+use cal::zone::{TimeZone, ZonedDateTime};
+use instant::Instant;
+use recur::DAYS_IN_CYCLE;
+use recur::dst::{self, DstPolicy};
+
+
+/// A parsed **cron expression**, which can find the times a job should run
+/// at either side of a given time.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, LocalTime, LocalDateTime, Month, CronSchedule};
+///
+/// let schedule: CronSchedule = "30 9 * * MON-FRI".parse().unwrap();
+///
+/// // Saturday morning
+/// let now = LocalDateTime::new(LocalDate::ymd(2017, Month::August, 19).unwrap(), LocalTime::hm(8, 0).unwrap());
+/// let next = LocalDateTime::new(LocalDate::ymd(2017, Month::August, 21).unwrap(), LocalTime::hm(9, 30).unwrap());
+/// assert_eq!(schedule.next_local_after(now), Some(next));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CronSchedule {
+    seconds: u64,
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    months: u16,
+    weekdays: u8,
+    specials: Vec<Special>,
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+/// A day-field value that can’t be represented as a fixed set of numbers.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum Special {
+
+    /// `L` or `L-n`: the given number of days before the last day of the
+    /// month.
+    BeforeLastDay(i8),
+
+    /// `nW`: the weekday nearest the given day of the month.
+    NearestWeekday(i8),
+
+    /// `LW`: the last weekday of the month.
+    LastWeekday,
+
+    /// `nL`: the last of the given weekday in the month.
+    LastOf(Weekday),
+
+    /// `n#k`: the given occurrence of the given weekday in the month.
+    Nth(Weekday, i8),
+}
+
+impl Special {
+    fn is_day_of_month(self) -> bool {
+        matches!(self, Special::BeforeLastDay(_) | Special::NearestWeekday(_) | Special::LastWeekday)
+    }
+
+    fn matches(self, date: LocalDate) -> bool {
+        let day_count = Year(date.year()).month(date.month()).day_count();
+
+        match self {
+            Special::BeforeLastDay(n)   => date.day() == day_count - n,
+            Special::NearestWeekday(n)  => n <= day_count && date.day() == nearest_weekday(date.add_days((n - date.day()) as i64)),
+            Special::LastWeekday        => date.day() == nearest_weekday(date.end_of(Unit::Month)),
+            Special::LastOf(weekday)    => date.weekday() == weekday && date.is_last_weekday_of_month(),
+            Special::Nth(weekday, n)    => date.weekday() == weekday && date.weekday_of_month() == n,
+        }
+    }
+}
+
+/// Returns the time of day that’s the given number of seconds after
+/// midnight.
+fn time_of_day(seconds: i64) -> LocalTime {
+    // The number of seconds is always less than a day’s worth.
+    LocalTime::hms((seconds / 3600) as i8, (seconds / 60 % 60) as i8, (seconds % 60) as i8).unwrap()
+}
+
+/// Returns the day of the month of the weekday nearest the given date,
+/// without leaving its month.
+fn nearest_weekday(date: LocalDate) -> i8 {
+    let day_count = Year(date.year()).month(date.month()).day_count();
+
+    match date.weekday() {
+        Weekday::Saturday if date.day() == 1          => 3,
+        Weekday::Saturday                             => date.day() - 1,
+        Weekday::Sunday   if date.day() == day_count  => date.day() - 2,
+        Weekday::Sunday                               => date.day() + 1,
+        _                                             => date.day(),
+    }
+}
+
+
+impl CronSchedule {
+
+    /// Returns whether this schedule runs on the given date, at any time.
+    pub fn matches_date(&self, date: LocalDate) -> bool {
+        if self.months & (1 << date.month() as u8) == 0 {
+            return false;
+        }
+
+        let day = self.days & (1 << date.day()) != 0
+               || self.specials.iter().any(|s| s.is_day_of_month() && s.matches(date));
+
+        let weekday = self.weekdays & (1 << date.weekday() as u8) != 0
+                   || self.specials.iter().any(|s| !s.is_day_of_month() && s.matches(date));
+
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true)   => day || weekday,
+            _              => day && weekday,
+        }
+    }
+
+    /// Returns whether this schedule runs at exactly the given time.
+    pub fn matches(&self, datetime: LocalDateTime) -> bool {
+        datetime.millisecond() == 0
+            && self.seconds & (1 << datetime.second()) != 0
+            && self.minutes & (1 << datetime.minute()) != 0
+            && self.hours & (1 << datetime.hour()) != 0
+            && self.matches_date(datetime.date())
+    }
+
+    /// Returns the first time in a day that this schedule runs at, after the
+    /// given number of seconds since midnight.
+    fn next_time(&self, after: i64) -> Option<LocalTime> {
+        let mut seconds = after + 1;
+
+        while seconds < 86400 {
+            if self.hours & (1 << (seconds / 3600)) == 0 {
+                seconds = (seconds / 3600 + 1) * 3600;
+            }
+            else if self.minutes & (1 << (seconds / 60 % 60)) == 0 {
+                seconds = (seconds / 60 + 1) * 60;
+            }
+            else if self.seconds & (1 << (seconds % 60)) == 0 {
+                seconds += 1;
+            }
+            else {
+                return Some(time_of_day(seconds));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the last time in a day that this schedule runs at, before
+    /// the given number of seconds since midnight.
+    fn previous_time(&self, before: i64) -> Option<LocalTime> {
+        let mut seconds = before - 1;
+
+        while seconds >= 0 {
+            if self.hours & (1 << (seconds / 3600)) == 0 {
+                seconds = seconds / 3600 * 3600 - 1;
+            }
+            else if self.minutes & (1 << (seconds / 60 % 60)) == 0 {
+                seconds = seconds / 60 * 60 - 1;
+            }
+            else if self.seconds & (1 << (seconds % 60)) == 0 {
+                seconds -= 1;
+            }
+            else {
+                return Some(time_of_day(seconds));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the first local time after the given one that this schedule
+    /// runs at, or `None` if it never runs.
+    pub fn next_local_after(&self, after: LocalDateTime) -> Option<LocalDateTime> {
+        let mut date = after.date();
+        let mut seconds = after.hour() as i64 * 3600 + after.minute() as i64 * 60 + after.second() as i64;
+        let last = date.add_days(DAYS_IN_CYCLE);
+
+        while date <= last {
+            if self.months & (1 << date.month() as u8) == 0 {
+                date = date.end_of(Unit::Month).add_days(1);
+                seconds = -1;
+                continue;
+            }
+
+            if self.matches_date(date) {
+                if let Some(time) = self.next_time(seconds) {
+                    return Some(LocalDateTime::new(date, time));
+                }
+            }
+
+            date = date.add_days(1);
+            seconds = -1;
+        }
+
+        None
+    }
+
+    /// Returns the last local time before the given one that this schedule
+    /// runs at, or `None` if it never runs.
+    pub fn prev_local_before(&self, before: LocalDateTime) -> Option<LocalDateTime> {
+        let mut date = before.date();
+        let mut seconds = before.hour() as i64 * 3600 + before.minute() as i64 * 60 + before.second() as i64;
+        if before.millisecond() > 0 {
+            seconds += 1;
+        }
+
+        let first = date.add_days(-DAYS_IN_CYCLE);
+
+        while date >= first {
+            if self.months & (1 << date.month() as u8) == 0 {
+                date = date.start_of(Unit::Month).add_days(-1);
+                seconds = 86400;
+                continue;
+            }
+
+            if self.matches_date(date) {
+                if let Some(time) = self.previous_time(seconds) {
+                    return Some(LocalDateTime::new(date, time));
+                }
+            }
+
+            date = date.add_days(-1);
+            seconds = 86400;
+        }
+
+        None
+    }
+
+    /// Returns the first time after the given instant that this schedule
+    /// runs at in the given time zone, or `None` if it never runs.
+    ///
+    /// The schedule’s times are wall-clock times in the time zone, so when
+    /// the clocks change, some of them happen twice and some don’t happen
+    /// at all. The policy says what to do with these.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, CronSchedule, DstPolicy, TimeZone};
+    /// use datetime::zone::{TimeZoneSource, StaticTimeZone, FixedTimespanSet, FixedTimespan};
+    /// use std::borrow::Cow;
+    ///
+    /// // A zone that goes back an hour at 02:00 local time on 31 October 2010.
+    /// static ZONE: StaticTimeZone<'static> = StaticTimeZone {
+    ///     name: "Example",
+    ///     fixed_timespans: FixedTimespanSet {
+    ///         first: FixedTimespan { offset: 3600, is_dst: true, name: Cow::Borrowed("B") },
+    ///         rest: &[ (1288486800, FixedTimespan { offset: 0, is_dst: false, name: Cow::Borrowed("A") }) ],
+    ///     },
+    /// };
+    /// let zone = TimeZone(TimeZoneSource::Static(&ZONE));
+    ///
+    /// let schedule: CronSchedule = "30 1 * * *".parse().unwrap();
+    /// let midnight = Instant::at(1288483200 - 3600);
+    ///
+    /// // 01:30 happens twice that night, but only runs once.
+    /// let first = schedule.next_after(midnight, &zone, DstPolicy::once()).unwrap();
+    /// let second = schedule.next_after(first.to_instant(), &zone, DstPolicy::once()).unwrap();
+    /// assert_eq!(second.to_instant() - first.to_instant(), datetime::Duration::of(86400 + 3600));
+    /// ```
+    pub fn next_after(&self, after: Instant, zone: &TimeZone, policy: DstPolicy) -> Option<ZonedDateTime<'static>> {
+        dst::next_instant(zone, policy, after, |local| self.next_local_after(local))
+            .map(|instant| zone.zoned_at(instant))
+    }
+
+    /// Returns the last time before the given instant that this schedule
+    /// ran at in the given time zone, or `None` if it never ran. See
+    /// `next_after` for how the policy is used.
+    pub fn prev_before(&self, before: Instant, zone: &TimeZone, policy: DstPolicy) -> Option<ZonedDateTime<'static>> {
+        dst::previous_instant(zone, policy, before, |local| self.prev_local_before(local))
+            .map(|instant| zone.zoned_at(instant))
+    }
+}
+
+
+impl FromStr for CronSchedule {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        let input = match &*input.to_ascii_lowercase() {
+            "@yearly" | "@annually"  => "0 0 1 1 *",
+            "@monthly"               => "0 0 1 * *",
+            "@weekly"                => "0 0 * * 0",
+            "@daily" | "@midnight"   => "0 0 * * *",
+            "@hourly"                => "0 * * * *",
+            _ if input.starts_with('@') => return Err(Error::InvalidField(input.to_string())),
+            _                        => input,
+        };
+
+        let fields: Vec<&str> = input.split_whitespace().collect();
+        let (seconds, fields) = match fields.len() {
+            5  => ("0", &fields[..]),
+            6  => (fields[0], &fields[1..]),
+            n  => return Err(Error::FieldCount(n)),
+        };
+
+        let invalid = |field: &str| Error::InvalidField(field.to_string());
+        let mut specials = Vec::new();
+
+        let seconds = list(seconds, 0, 59, &[]).ok_or_else(|| invalid(seconds))?;
+        let minutes = list(fields[0], 0, 59, &[]).ok_or_else(|| invalid(fields[0]))?;
+        let hours = list(fields[1], 0, 23, &[]).ok_or_else(|| invalid(fields[1]))?;
+        let days = days_of_month(fields[2], &mut specials).ok_or_else(|| invalid(fields[2]))?;
+        let months = list(fields[3], 1, 12, &MONTH_NAMES).ok_or_else(|| invalid(fields[3]))?;
+        let weekdays = days_of_week(fields[4], &mut specials).ok_or_else(|| invalid(fields[4]))?;
+
+        Ok(Self {
+            seconds,
+            minutes,
+            hours: hours as u32,
+            days: days as u32,
+            months: months as u16,
+            weekdays,
+            specials,
+            days_restricted: !fields[2].starts_with(['*', '?']),
+            weekdays_restricted: !fields[4].starts_with(['*', '?']),
+        })
+    }
+}
+
+
+const MONTH_NAMES: [&str; 12] = [ "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC" ];
+const WEEKDAY_NAMES: [&str; 7] = [ "SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT" ];
+
+/// Parses a single number or name between the given bounds. Names count
+/// up from the minimum.
+fn value(input: &str, min: u32, max: u32, names: &[&str]) -> Option<u32> {
+    let number = match input.parse() {
+        Ok(number)  => number,
+        Err(_)      => names.iter().position(|n| n.eq_ignore_ascii_case(input))? as u32 + min,
+    };
+
+    if (min ..= max).contains(&number) { Some(number) } else { None }
+}
+
+/// Parses a comma-separated list of values, ranges, and steps into a bit
+/// set, where bit *n* is set if the field contains *n*.
+fn list(input: &str, min: u32, max: u32, names: &[&str]) -> Option<u64> {
+    input.split(',').try_fold(0, |bits, item| Some(bits | item_bits(item, min, max, names)?))
+}
+
+fn item_bits(input: &str, min: u32, max: u32, names: &[&str]) -> Option<u64> {
+    let (range, step) = match input.find('/') {
+        Some(index)  => (&input[.. index], Some(input[index + 1 ..].parse::<u32>().ok().filter(|&s| s > 0)?)),
+        None         => (input, None),
+    };
+
+    let (start, end) = if range == "*" {
+        (min, max)
+    }
+    else if let Some(index) = range.find('-') {
+        (value(&range[.. index], min, max, names)?, value(&range[index + 1 ..], min, max, names)?)
+    }
+    else {
+        let start = value(range, min, max, names)?;
+        (start, if step.is_some() { max } else { start })
+    };
+
+    if start > end {
+        return None;
+    }
+
+    Some((start ..= end).step_by(step.unwrap_or(1) as usize).fold(0, |bits, n| bits | (1 << n)))
+}
+
+/// Parses the day-of-the-month field, adding any special values to the
+/// list, and returning the bit set of the others.
+fn days_of_month(input: &str, specials: &mut Vec<Special>) -> Option<u64> {
+    let mut bits = 0;
+
+    for item in input.split(',') {
+        let item = if item == "?" { "*" } else { item };
+
+        if item.eq_ignore_ascii_case("L") {
+            specials.push(Special::BeforeLastDay(0));
+        }
+        else if item.eq_ignore_ascii_case("LW") {
+            specials.push(Special::LastWeekday);
+        }
+        else if let Some(days) = item.strip_prefix("L-").or_else(|| item.strip_prefix("l-")) {
+            specials.push(Special::BeforeLastDay(value(days, 1, 30, &[])? as i8));
+        }
+        else if let Some(day) = item.strip_suffix(['W', 'w']) {
+            specials.push(Special::NearestWeekday(value(day, 1, 31, &[])? as i8));
+        }
+        else {
+            bits |= item_bits(item, 1, 31, &[])?;
+        }
+    }
+
+    Some(bits)
+}
+
+/// Parses the day-of-the-week field, adding any special values to the
+/// list, and returning the bit set of the others, with Sunday as bit 0.
+fn days_of_week(input: &str, specials: &mut Vec<Special>) -> Option<u8> {
+    let weekday = |input: &str| Weekday::from_zero((value(input, 0, 7, &WEEKDAY_NAMES)? % 7) as i8).ok();
+    let mut bits = 0;
+
+    for item in input.split(',') {
+        let item = if item == "?" { "*" } else { item };
+
+        if let Some(index) = item.find('#') {
+            let n = value(&item[index + 1 ..], 1, 5, &[])? as i8;
+            specials.push(Special::Nth(weekday(&item[.. index])?, n));
+        }
+        else if let Some(day) = item.strip_suffix(['L', 'l']).filter(|d| !d.is_empty()) {
+            specials.push(Special::LastOf(weekday(day)?));
+        }
+        else {
+            bits |= item_bits(item, 0, 7, &WEEKDAY_NAMES)?;
+        }
+    }
+
+    // Sunday can be written as either 0 or 7.
+    Some((bits | bits >> 7) as u8 & 0x7F)
+}
+
+
+/// An error that can occur when parsing a cron expression.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+
+    /// The expression doesn’t have five or six fields. This contains the
+    /// number of fields it has.
+    FieldCount(usize),
+
+    /// A field has a value that can’t be parsed or is out of range. This
+    /// contains the field.
+    InvalidField(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::FieldCount(n)       => write!(f, "cron expression has {} fields, rather than five or six", n),
+            Error::InvalidField(ref s)  => write!(f, "invalid cron field ‘{}’", s),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+}
@@ -0,0 +1,179 @@
+//! Turning scheduled wall-clock times into instants, across clock changes.
+
+use cal::datetime::LocalDateTime;
+use cal::zone::{LocalTimes, TimeZone};
+use duration::Duration;
+use instant::Instant;
+
+
+/// What to do with a scheduled wall-clock time that a time zone’s clocks
+/// skip over, or show twice.
+///
+/// There’s no single right answer here: a backup job should probably run
+/// once on a night when the clocks go back, but a job that runs every
+/// fifteen minutes should probably keep running every fifteen minutes. So
+/// code that schedules jobs has to pick one explicitly.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct DstPolicy {
+
+    /// What to do with a time in a gap, where the clocks go forward.
+    pub gap: GapPolicy,
+
+    /// What to do with a time in an overlap, where the clocks go back.
+    pub overlap: OverlapPolicy,
+}
+
+impl DstPolicy {
+
+    /// The policy that runs every scheduled time exactly once: times in a
+    /// gap are moved forward by the length of the gap, and times in an
+    /// overlap use the first time the clock shows them. This is what most
+    /// cron daemons do, and what RFC 5545 says to do for calendar events.
+    pub fn once() -> Self {
+        Self { gap: GapPolicy::ShiftForward, overlap: OverlapPolicy::Earlier }
+    }
+
+    /// The policy that runs a job whenever the wall clock shows one of
+    /// its scheduled times: never for times in a gap, and twice for times
+    /// in an overlap.
+    pub fn wall_clock() -> Self {
+        Self { gap: GapPolicy::Skip, overlap: OverlapPolicy::Both }
+    }
+
+    /// Returns the instants that a scheduled local time resolves to in the
+    /// given time zone under this policy, in order. There can be none, one,
+    /// or two of them.
+    pub(crate) fn instants(self, zone: &TimeZone, local: LocalDateTime) -> Vec<Instant> {
+        match zone.convert_local(local) {
+            LocalTimes::Precise(zoned)                => vec![ zoned.to_instant() ],
+            LocalTimes::Ambiguous { earlier, later }  => match self.overlap {
+                OverlapPolicy::Earlier  => vec![ earlier.to_instant() ],
+                OverlapPolicy::Later    => vec![ later.to_instant() ],
+                OverlapPolicy::Both     => vec![ earlier.to_instant(), later.to_instant() ],
+            },
+            LocalTimes::Impossible                    => match self.gap {
+                GapPolicy::Skip          => Vec::new(),
+                GapPolicy::ShiftForward  => vec![ zone.resolve_local(local).to_instant() ],
+            },
+        }
+    }
+}
+
+impl Default for DstPolicy {
+    fn default() -> Self {
+        Self::once()
+    }
+}
+
+
+/// What to do with a scheduled time that falls in a gap, such as 02:30 on
+/// a night when the clocks go from 02:00 straight to 03:00.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum GapPolicy {
+
+    /// Don’t run at that time at all.
+    Skip,
+
+    /// Run later, moving the time forward by the length of the gap, so
+    /// 02:30 becomes 03:30.
+    ShiftForward,
+}
+
+
+/// What to do with a scheduled time that falls in an overlap, such as
+/// 01:30 on a night when the clocks go from 02:00 back to 01:00.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum OverlapPolicy {
+
+    /// Run the first time the clock shows that time.
+    Earlier,
+
+    /// Run the second time the clock shows that time.
+    Later,
+
+    /// Run both times.
+    Both,
+}
+
+
+/// Returns the smallest and largest offsets that a time zone has within a
+/// day either side of the given instant.
+fn offsets_around(zone: &TimeZone, instant: Instant) -> (i64, i64) {
+    let utc = LocalDateTime::from_instant(instant);
+    let offsets = [ -86400, 0, 86400 ].iter().map(|&s| zone.offset(utc + Duration::of(s)));
+    let (min, max) = offsets.fold((i64::MAX, i64::MIN), |(min, max), o| (min.min(o), max.max(o)));
+    (min, max)
+}
+
+/// Returns the first instant after the given one that a schedule fires at
+/// in a time zone, where `next_local` returns the schedule’s first local
+/// time after the one it’s given.
+///
+/// Local times are searched in order, but around an overlap, the instants
+/// they resolve to can be out of order. So once one is found, the search
+/// carries on for as long as the clocks moved, in case a later local time
+/// resolves to an earlier instant.
+pub(crate) fn next_instant<F>(zone: &TimeZone, policy: DstPolicy, after: Instant, next_local: F) -> Option<Instant>
+where F: Fn(LocalDateTime) -> Option<LocalDateTime>
+{
+    // No local time before this one can resolve to an instant after the
+    // one we were given.
+    let (min, _) = offsets_around(zone, after);
+    let mut local = LocalDateTime::from_instant(after) + Duration::of(min);
+
+    let mut best: Option<Instant> = None;
+    let mut limit = None;
+
+    while let Some(next) = next_local(local) {
+        if limit.map_or(false, |limit| next >= limit) {
+            break;
+        }
+
+        local = next;
+        for instant in policy.instants(zone, local) {
+            if instant > after && best.map_or(true, |b| instant < b) {
+                best = Some(instant);
+            }
+        }
+
+        if let (None, Some(b)) = (limit, best) {
+            let (min, max) = offsets_around(zone, b);
+            limit = Some(local + Duration::of(max - min));
+        }
+    }
+
+    best
+}
+
+/// Returns the last instant before the given one that a schedule fires at
+/// in a time zone, where `previous_local` returns the schedule’s last local
+/// time before the one it’s given.
+pub(crate) fn previous_instant<F>(zone: &TimeZone, policy: DstPolicy, before: Instant, previous_local: F) -> Option<Instant>
+where F: Fn(LocalDateTime) -> Option<LocalDateTime>
+{
+    let (_, max) = offsets_around(zone, before);
+    let mut local = LocalDateTime::from_instant(before) + Duration::of(max);
+
+    let mut best: Option<Instant> = None;
+    let mut limit = None;
+
+    while let Some(previous) = previous_local(local) {
+        if limit.map_or(false, |limit| previous <= limit) {
+            break;
+        }
+
+        local = previous;
+        for instant in policy.instants(zone, local) {
+            if instant < before && best.map_or(true, |b| instant > b) {
+                best = Some(instant);
+            }
+        }
+
+        if let (None, Some(b)) = (limit, best) {
+            let (min, max) = offsets_around(zone, b);
+            limit = Some(local - Duration::of(max - min));
+        }
+    }
+
+    best
+}
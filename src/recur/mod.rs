@@ -0,0 +1,764 @@
+//! Recurring events, as described by iCalendar recurrence rules.
+//!
+//! RFC 5545 describes a recurring event using a start time (`DTSTART`), any
+//! number of recurrence rules (`RRULE`) that generate further occurrences
+//! from it, and lists of extra dates to add (`RDATE`) and remove
+//! (`EXDATE`). A recurrence rule such as `FREQ=MONTHLY;BYDAY=-1FR` works by
+//! splitting time up into periods — here, months — and then picking out
+//! the days and times within each period that match its other parts.
+//!
+//! Occurrences are generated as `LocalDateTime` values, in the same
+//! wall-clock time as the start time. They can be converted into a time
+//! zone using `Recurrence::zoned`, which handles clock changes the way RFC
+//! 5545 says to.
+
+use std::collections::VecDeque;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::{DatePiece, TimePiece};
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Year};
+use cal::week::WeekRule;
+use cal::zone::{TimeZone, ZonedDateTime};
+use instant::Instant;
+
+pub mod calendar;
+pub mod cron;
+mod dst;
+pub use self::dst::{DstPolicy, GapPolicy, OverlapPolicy};
+mod parse;
+
+
+/// The number of days in the Gregorian calendar’s 400-year cycle. If a
+/// schedule doesn’t match any day in this many, it never will.
+pub(crate) const DAYS_IN_CYCLE: i64 = 146_097;
+
+
+/// How often a recurrence rule repeats, which is the length of the periods
+/// that it splits time up into.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Weekly,
+    Daily,
+    Hourly,
+}
+
+
+/// A weekday in a `BYDAY` rule part, with an optional ordinal: `MO` means
+/// every Monday, `2MO` means the second Monday, and `-1MO` means the last
+/// Monday.
+///
+/// In a monthly rule, or a yearly rule with a `BYMONTH` part, the ordinal
+/// counts within the month. In a yearly rule without one, it counts within
+/// the year.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct ByDay {
+
+    /// Which occurrence of the weekday to match, or 0 to match all of them.
+    pub ordinal: i8,
+
+    /// The weekday to match.
+    pub weekday: Weekday,
+}
+
+
+/// The last time that a recurrence rule can produce an occurrence at.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Until {
+
+    /// The rule stops at the end of the given date.
+    Date(LocalDate),
+
+    /// The rule stops at the given wall-clock time.
+    Local(LocalDateTime),
+
+    /// The rule stops at the given instant. This is what an `UNTIL` value
+    /// ending in `Z` means.
+    Utc(Instant),
+}
+
+
+/// A date and time in a recurrence set, from a `DTSTART`, `RDATE`, or
+/// `EXDATE` property.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum ExtraDate {
+
+    /// A wall-clock time, in the same time zone as the start time.
+    Local(LocalDateTime),
+
+    /// An instant. This is what a value ending in `Z` means.
+    Utc(Instant),
+}
+
+impl ExtraDate {
+
+    /// Returns the wall-clock time of this date in the given time zone,
+    /// treating times as UTC if there isn’t one.
+    fn local_in(&self, zone: Option<&TimeZone>) -> LocalDateTime {
+        match *self {
+            ExtraDate::Local(local)    => local,
+            ExtraDate::Utc(instant)    => {
+                let utc = LocalDateTime::from_instant(instant);
+                match zone {
+                    Some(zone) => zone.to_zoned(utc),
+                    None       => utc,
+                }
+            },
+        }
+    }
+}
+
+impl From<LocalDateTime> for ExtraDate {
+    fn from(local: LocalDateTime) -> Self {
+        ExtraDate::Local(local)
+    }
+}
+
+impl From<Instant> for ExtraDate {
+    fn from(instant: Instant) -> Self {
+        ExtraDate::Utc(instant)
+    }
+}
+
+
+/// A **recurrence rule**, as found in an iCalendar `RRULE` property.
+///
+/// Every field apart from the frequency is optional, and the rule parts
+/// that are lists all default to being empty, meaning they don’t restrict
+/// anything. Rules can be built up field by field, or parsed from their
+/// iCalendar text form.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::recur::{RecurrenceRule, Frequency};
+///
+/// let rule: RecurrenceRule = "FREQ=MONTHLY;BYDAY=-1FR;COUNT=6".parse().unwrap();
+/// assert_eq!(rule.frequency, Frequency::Monthly);
+/// assert_eq!(rule.count, Some(6));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct RecurrenceRule {
+
+    /// The length of the periods that the rule splits time up into.
+    pub frequency: Frequency,
+
+    /// How many periods there are between each one that occurrences can
+    /// come from: 2 with a weekly frequency means every other week.
+    pub interval: i64,
+
+    /// The number of occurrences the rule produces before it stops.
+    pub count: Option<u32>,
+
+    /// The last time that the rule can produce an occurrence at.
+    pub until: Option<Until>,
+
+    /// The weekdays the rule matches (`BYDAY`).
+    pub by_day: Vec<ByDay>,
+
+    /// The days of the month the rule matches, with negative numbers
+    /// counting back from the end of the month (`BYMONTHDAY`).
+    pub by_month_day: Vec<i8>,
+
+    /// The days of the year the rule matches, with negative numbers
+    /// counting back from the end of the year (`BYYEARDAY`).
+    pub by_year_day: Vec<i16>,
+
+    /// The weeks of the year the rule matches, with negative numbers
+    /// counting back from the end of the year (`BYWEEKNO`). Weeks are
+    /// numbered the ISO-8601 way, but starting on `week_start`.
+    pub by_week_no: Vec<i8>,
+
+    /// The months the rule matches (`BYMONTH`).
+    pub by_month: Vec<Month>,
+
+    /// The hours of the day the rule matches (`BYHOUR`).
+    pub by_hour: Vec<i8>,
+
+    /// Which of the occurrences within each period to keep, with negative
+    /// numbers counting back from the last one (`BYSETPOS`).
+    pub by_set_pos: Vec<i16>,
+
+    /// The day that weeks start on (`WKST`), which affects weekly rules
+    /// with an interval, and `BYWEEKNO`. This defaults to Monday.
+    pub week_start: Weekday,
+}
+
+impl RecurrenceRule {
+
+    /// Creates a new rule with the given frequency, which produces one
+    /// occurrence per period, forever.
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval:      1,
+            count:         None,
+            until:         None,
+            by_day:        Vec::new(),
+            by_month_day:  Vec::new(),
+            by_year_day:   Vec::new(),
+            by_week_no:    Vec::new(),
+            by_month:      Vec::new(),
+            by_hour:       Vec::new(),
+            by_set_pos:    Vec::new(),
+            week_start:    Weekday::Monday,
+        }
+    }
+
+    /// Returns an iterator over the occurrences of this rule, starting
+    /// from the given start time.
+    ///
+    /// Only occurrences at or after the start time are produced. The
+    /// start time is only produced if it matches the rule, but it always
+    /// counts towards the rule’s `COUNT`, so a rule should normally be
+    /// used as part of a `Recurrence` instead.
+    pub fn occurrences(&self, start: LocalDateTime) -> RuleOccurrences<'_> {
+        RuleOccurrences::new(self, start, None)
+    }
+}
+
+
+/// A **recurrence set**: a start time, any number of rules, and extra
+/// dates to add and remove, which together make up the occurrences of a
+/// recurring event.
+///
+/// ### Examples
+///
+/// ```
+/// use datetime::{LocalDate, Month};
+/// use datetime::recur::Recurrence;
+///
+/// let recurrence = Recurrence::parse("\
+/// DTSTART:20170105T090000
+/// RRULE:FREQ=WEEKLY;BYDAY=TH;COUNT=4
+/// EXDATE:20170112T090000
+/// ").unwrap();
+///
+/// let dates: Vec<_> = recurrence.dates().collect();
+/// assert_eq!(dates, vec![
+///     LocalDate::ymd(2017, Month::January, 5).unwrap(),
+///     LocalDate::ymd(2017, Month::January, 19).unwrap(),
+///     LocalDate::ymd(2017, Month::January, 26).unwrap(),
+/// ]);
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct Recurrence {
+
+    /// The time of the first occurrence (`DTSTART`), which the rules work
+    /// forward from. When this is in UTC, the rules are worked out in UTC
+    /// too, and any wall-clock extra dates are treated as UTC.
+    pub start: ExtraDate,
+
+    /// The rules that generate occurrences (`RRULE`).
+    pub rules: Vec<RecurrenceRule>,
+
+    /// Extra occurrences that are added to the set (`RDATE`).
+    pub dates: Vec<ExtraDate>,
+
+    /// Occurrences that are removed from the set, even if a rule or an
+    /// extra date produces them (`EXDATE`).
+    pub exclusions: Vec<ExtraDate>,
+}
+
+impl Recurrence {
+
+    /// Creates a new recurrence set with the given start time, which can be
+    /// a local datetime or an instant in UTC, and a single rule.
+    pub fn new<S: Into<ExtraDate>>(start: S, rule: RecurrenceRule) -> Self {
+        Self { start: start.into(), rules: vec![ rule ], dates: Vec::new(), exclusions: Vec::new() }
+    }
+
+    /// Returns an iterator over the occurrences in this set, as local
+    /// datetimes, in order and without duplicates. Start times and extra
+    /// dates given in UTC are used as they are.
+    ///
+    /// The start time always counts as the first occurrence, unless it’s
+    /// excluded. A rule’s `COUNT` includes the start time, even if it
+    /// doesn’t match the rule, and every occurrence the rule produces,
+    /// even those that end up excluded.
+    ///
+    /// Rules with neither a count nor an end time go on producing
+    /// occurrences until the year 9999. A rule that can never match
+    /// anything, such as one for the 30th of February, stops once it’s
+    /// gone through a whole 400-year cycle without an occurrence.
+    pub fn iter(&self) -> Occurrences<'_> {
+        self.iter_in(None)
+    }
+
+    /// Returns an iterator over the dates of the occurrences in this set.
+    pub fn dates<'r>(&'r self) -> impl Iterator<Item=LocalDate> + 'r {
+        self.iter().map(|datetime| datetime.date())
+    }
+
+    /// Returns an iterator over the occurrences in this set in the given
+    /// time zone.
+    ///
+    /// As RFC 5545 requires, an occurrence at an ambiguous time happens at
+    /// the earlier of the two, and an occurrence at a time that gets
+    /// skipped when the clocks go forward gets moved forward by the length
+    /// of the gap. Extra dates and `UNTIL` times given in UTC are
+    /// converted into the time zone first. When the start time is in UTC,
+    /// the occurrences are worked out in UTC, and each one is converted
+    /// into the time zone afterwards.
+    pub fn zoned<'r>(&'r self, zone: &'r TimeZone) -> impl Iterator<Item=ZonedDateTime<'static>> + 'r {
+        let utc = self.is_utc();
+        self.iter_in(Some(zone)).map(move |local| {
+            if utc { zone.zoned_at(local.to_instant()) }
+              else { zone.resolve_local(local) }
+        })
+    }
+
+    /// Returns whether the start time is in UTC, which means the
+    /// occurrences are worked out in UTC rather than in a time zone.
+    fn is_utc(&self) -> bool {
+        match self.start {
+            ExtraDate::Utc(_)    => true,
+            ExtraDate::Local(_)  => false,
+        }
+    }
+
+    fn iter_in<'r>(&'r self, zone: Option<&'r TimeZone>) -> Occurrences<'r> {
+        let zone = if self.is_utc() { None } else { zone };
+        let start = self.start.local_in(zone);
+
+        let mut dates: Vec<_> = self.dates.iter().map(|date| date.local_in(zone)).collect();
+        dates.push(start);
+        dates.sort();
+
+        Occurrences {
+            rules: self.rules.iter().map(|rule| RuleOccurrences::new(rule, start, zone).peekable()).collect(),
+            dates: dates.into(),
+            exclusions: self.exclusions.iter().map(|date| date.local_in(zone)).collect(),
+            last: None,
+        }
+    }
+}
+
+
+/// An iterator over the occurrences of a single recurrence rule.
+///
+/// Use the `occurrences` method on `RecurrenceRule` to create instances of
+/// this iterator.
+pub struct RuleOccurrences<'r> {
+    rule: &'r RecurrenceRule,
+    start: LocalDateTime,
+    zone: Option<&'r TimeZone>,
+
+    /// The rule’s `BYDAY`, `BYMONTHDAY`, and `BYMONTH` parts, with the
+    /// values taken from the start time filled in where the rule leaves
+    /// them out.
+    by_day: Vec<ByDay>,
+    by_month_day: Vec<i8>,
+    by_month: Vec<Month>,
+
+    /// The number of the next period to look in, counting from the one
+    /// with the start time in.
+    period: i64,
+
+    /// Occurrences from the current period that have yet to be returned.
+    buffer: VecDeque<LocalDateTime>,
+
+    /// The number of periods in a row that have had no occurrences in.
+    empty_periods: i64,
+
+    /// The number of occurrences produced so far, for checking against
+    /// the rule’s count.
+    produced: u32,
+    finished: bool,
+}
+
+impl<'r> RuleOccurrences<'r> {
+    fn new(rule: &'r RecurrenceRule, start: LocalDateTime, zone: Option<&'r TimeZone>) -> Self {
+        let mut by_day = rule.by_day.clone();
+        let mut by_month_day = rule.by_month_day.clone();
+        let mut by_month = rule.by_month.clone();
+
+        // A rule that doesn’t say which days in its period it matches
+        // takes them from the start time instead, so a monthly rule
+        // starting on the 15th repeats on the 15th of each month.
+        let no_days = by_day.is_empty() && by_month_day.is_empty() && rule.by_year_day.is_empty() && rule.by_week_no.is_empty();
+        match rule.frequency {
+            Frequency::Yearly if no_days => {
+                by_month_day.push(start.day());
+                if by_month.is_empty() {
+                    by_month.push(start.month());
+                }
+            },
+            Frequency::Monthly if no_days => by_month_day.push(start.day()),
+            Frequency::Weekly if by_day.is_empty() => by_day.push(ByDay { ordinal: 0, weekday: start.weekday() }),
+            _ => {},
+        }
+
+        Self {
+            rule, start, zone,
+            by_day, by_month_day, by_month,
+            period: 0,
+            buffer: VecDeque::new(),
+            empty_periods: 0,
+            produced: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns the week rule used for `BYWEEKNO` and weekly periods.
+    fn week_rule(&self) -> WeekRule {
+        WeekRule::new(self.rule.week_start, 4).unwrap()
+    }
+
+    /// Returns the days in the period with the given number, in order, or
+    /// `None` if the period is too far in the future to look in.
+    fn period_days(&self, period: i64) -> Option<Vec<LocalDate>> {
+        let steps = period * self.rule.interval;
+        let date = self.start.date();
+
+        let (first, last) = match self.rule.frequency {
+            Frequency::Yearly => {
+                let year = date.year() + steps;
+                if year > 9999 {
+                    return None;
+                }
+                else if self.rule.by_week_no.is_empty() {
+                    (LocalDate::yd(year, 1).unwrap(), LocalDate::yd(year + 1, 1).unwrap().add_days(-1))
+                }
+                else {
+                    // Weeks near the start and end of the year can include
+                    // days from the years either side.
+                    let rule = self.week_rule();
+                    (rule.date(year, 1, rule.first_day()).unwrap(), rule.date(year + 1, 1, rule.first_day()).unwrap().add_days(-1))
+                }
+            },
+            Frequency::Monthly => {
+                let ym = Year(date.year()).month(date.month()) + steps;
+                (ym.day(1).unwrap(), ym.day(ym.day_count()).unwrap())
+            },
+            Frequency::Weekly => {
+                let first = date.start_of_week(self.week_rule()).add_weeks(steps);
+                (first, first.add_days(6))
+            },
+            Frequency::Daily => {
+                let day = date.add_days(steps);
+                (day, day)
+            },
+            Frequency::Hourly => {
+                let day = self.hourly_period(period).date();
+                (day, day)
+            },
+        };
+
+        if first.year() > 9999 {
+            return None;
+        }
+
+        let count = first.days_until(last) + 1;
+        Some((0 .. count).map(|d| first.add_days(d)).filter(|d| self.matches(*d)).collect())
+    }
+
+    /// Returns the start of the hour with the given number, for rules with
+    /// an hourly frequency.
+    fn hourly_period(&self, period: i64) -> LocalDateTime {
+        let start = LocalDateTime::new(self.start.date(), LocalTime::hm(self.start.hour(), 0).unwrap());
+        start.add_seconds(period * self.rule.interval * 3600)
+    }
+
+    /// Returns whether the given date matches all the day-related parts of
+    /// the rule.
+    fn matches(&self, date: LocalDate) -> bool {
+        let month_length = Year(date.year()).month(date.month()).day_count() as i64;
+        let year_length = if Year(date.year()).is_leap_year() { 366 } else { 365 };
+
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+            return false;
+        }
+
+        if !self.rule.by_week_no.is_empty() {
+            let rule = self.week_rule();
+            let weeks = rule.weeks_in_year(date.week_year(rule)) as i64;
+            let week = date.week_of_year(rule) as i64;
+            if !self.rule.by_week_no.iter().any(|&n| from_either_end(n as i64, weeks) == week) {
+                return false;
+            }
+        }
+
+        if !self.rule.by_year_day.is_empty()
+        && !self.rule.by_year_day.iter().any(|&n| from_either_end(n as i64, year_length) == date.yearday() as i64) {
+            return false;
+        }
+
+        if !self.by_month_day.is_empty()
+        && !self.by_month_day.iter().any(|&n| from_either_end(n as i64, month_length) == date.day() as i64) {
+            return false;
+        }
+
+        if !self.by_day.is_empty() {
+            // Ordinals count within the month for monthly rules and yearly
+            // rules that pick months, and within the year for other yearly
+            // rules. Other frequencies ignore them.
+            let scope = match self.rule.frequency {
+                Frequency::Monthly                                   => Some((date.day() as i64, month_length)),
+                Frequency::Yearly if !self.rule.by_month.is_empty()  => Some((date.day() as i64, month_length)),
+                Frequency::Yearly if self.rule.by_week_no.is_empty() => Some((date.yearday() as i64, year_length)),
+                _                                                    => None,
+            };
+
+            let matches_day = |by: &ByDay| {
+                by.weekday == date.weekday() && match (by.ordinal, scope) {
+                    (0, _) | (_, None)          => true,
+                    (n, Some((day, _))) if n > 0 => (day - 1) / 7 + 1 == n as i64,
+                    (n, Some((day, length)))    => (length - day) / 7 + 1 == -n as i64,
+                }
+            };
+
+            if !self.by_day.iter().any(matches_day) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Fills the buffer with the occurrences in the next period, returning
+    /// false if there are no more periods to look in.
+    fn fill_buffer(&mut self) -> bool {
+
+        // The periods line up the same way with the calendar every 400
+        // years, so at most this many of them can go by before the rule
+        // has seen every combination of day and hour that it ever will.
+        // Going through that many without an occurrence means there
+        // won’t ever be one.
+        let limit = match self.rule.frequency {
+            Frequency::Hourly => DAYS_IN_CYCLE * 24,
+            _                 => DAYS_IN_CYCLE,
+        };
+
+        if self.empty_periods >= limit {
+            return false;
+        }
+
+        let period = self.period;
+        self.period += 1;
+
+        let days = match self.period_days(period) {
+            Some(days) => days,
+            None       => return false,
+        };
+
+        let mut candidates = Vec::new();
+        if self.rule.frequency == Frequency::Hourly {
+            let hour = self.hourly_period(period);
+            if days.is_empty() {
+
+                // None of the other hours in this day can match either, so
+                // skip straight to the first period in the next one.
+                let skipped = (23 - hour.hour() as i64) / self.rule.interval + 1;
+                self.period = period + skipped;
+                self.empty_periods += skipped;
+                return true;
+            }
+
+            if days.contains(&hour.date()) && (self.rule.by_hour.is_empty() || self.rule.by_hour.contains(&hour.hour())) {
+                candidates.push(hour);
+            }
+        }
+        else {
+            let mut hours = self.rule.by_hour.clone();
+            if hours.is_empty() {
+                hours.push(self.start.hour());
+            }
+
+            hours.sort();
+            for day in days {
+                for &hour in &hours {
+                    candidates.push(LocalDateTime::new(day, LocalTime::hm(hour, 0).unwrap()));
+                }
+            }
+        }
+
+        // Every occurrence has the minutes, seconds, and milliseconds of
+        // the start time.
+        let offset = self.start.time().to_seconds() % 3600;
+        let millis = self.start.millisecond();
+        let mut candidates: Vec<_> = candidates.into_iter().map(|c| {
+            let time = LocalTime::from_seconds_and_milliseconds_since_midnight(c.time().to_seconds() + offset, millis);
+            LocalDateTime::new(c.date(), time)
+        }).collect();
+
+        if !self.rule.by_set_pos.is_empty() {
+            let length = candidates.len() as i64;
+            let mut picked: Vec<_> = self.rule.by_set_pos.iter()
+                .map(|&n| from_either_end(n as i64, length) - 1)
+                .filter(|&index| index >= 0 && index < length)
+                .map(|index| candidates[index as usize])
+                .collect();
+
+            picked.sort();
+            picked.dedup();
+            candidates = picked;
+        }
+
+        let start = self.start;
+        self.buffer.extend(candidates.into_iter().filter(|c| *c >= start));
+
+        if self.buffer.is_empty() {
+            self.empty_periods += 1;
+        }
+        else {
+            self.empty_periods = 0;
+        }
+
+        true
+    }
+
+    /// Returns whether the given occurrence is after the rule’s end time.
+    fn is_past_until(&self, occurrence: LocalDateTime) -> bool {
+        match self.rule.until {
+            None                        => false,
+            Some(Until::Date(date))     => occurrence.date() > date,
+            Some(Until::Local(local))   => occurrence > local,
+            Some(Until::Utc(instant))   => {
+                let actual = match self.zone {
+                    Some(zone) => zone.resolve_local(occurrence).to_instant(),
+                    None       => occurrence.to_instant(),
+                };
+
+                actual > instant
+            },
+        }
+    }
+}
+
+impl<'r> Iterator for RuleOccurrences<'r> {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished || self.rule.count.map_or(false, |count| self.produced >= count) {
+            return None;
+        }
+
+        while self.buffer.is_empty() {
+            if !self.fill_buffer() {
+                self.finished = true;
+                return None;
+            }
+        }
+
+        let occurrence = self.buffer.pop_front().unwrap();
+        if self.is_past_until(occurrence) {
+            self.finished = true;
+            return None;
+        }
+
+        // RFC 5545 says the start time always counts as the first
+        // occurrence, so when it doesn’t match the rule, it uses up one
+        // of the rule’s count without being produced.
+        if self.produced == 0 && occurrence != self.start {
+            self.produced += 1;
+            if self.rule.count.map_or(false, |count| self.produced >= count) {
+                self.finished = true;
+                return None;
+            }
+        }
+
+        self.produced += 1;
+        Some(occurrence)
+    }
+}
+
+
+/// An iterator over the occurrences in a recurrence set.
+///
+/// Use the `iter` method on `Recurrence` to create instances of this
+/// iterator.
+pub struct Occurrences<'r> {
+    rules: Vec<::std::iter::Peekable<RuleOccurrences<'r>>>,
+    dates: VecDeque<LocalDateTime>,
+    exclusions: Vec<LocalDateTime>,
+    last: Option<LocalDateTime>,
+}
+
+impl<'r> Iterator for Occurrences<'r> {
+    type Item = LocalDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // Take the earliest of the next occurrences from each rule and
+            // the next extra date.
+            let mut earliest = self.dates.front().cloned();
+            let mut source = None;
+            for (index, rule) in self.rules.iter_mut().enumerate() {
+                if let Some(&next) = rule.peek() {
+                    if earliest.map_or(true, |e| next < e) {
+                        earliest = Some(next);
+                        source = Some(index);
+                    }
+                }
+            }
+
+            let occurrence = earliest?;
+            match source {
+                Some(index) => { self.rules[index].next(); },
+                None        => { self.dates.pop_front(); },
+            }
+
+            if self.last == Some(occurrence) || self.exclusions.contains(&occurrence) {
+                continue;
+            }
+
+            self.last = Some(occurrence);
+            return Some(occurrence);
+        }
+    }
+}
+
+
+/// Returns the position that a number in a rule part refers to, where
+/// positive numbers count from 1 at the start, and negative numbers count
+/// from -1 at the end of a span with the given length.
+fn from_either_end(number: i64, length: i64) -> i64 {
+    if number < 0 { length + 1 + number } else { number }
+}
+
+
+/// An error that can occur when parsing a recurrence rule or set.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+
+    /// The rule has no `FREQ` part.
+    MissingFrequency,
+
+    /// The rule has a `FREQ` that isn’t supported, such as `MINUTELY`.
+    UnsupportedFrequency(String),
+
+    /// A part of the rule has a name that isn’t recognised, or a value
+    /// that can’t be parsed or is out of range. This contains the part.
+    InvalidPart(String),
+
+    /// The rule has both a `COUNT` and an `UNTIL` part, which RFC 5545
+    /// doesn’t allow.
+    CountAndUntil,
+
+    /// A line of a recurrence set isn’t a `DTSTART`, `RRULE`, `RDATE`, or
+    /// `EXDATE` property, or has a value that can’t be parsed. This
+    /// contains the line number, counting from 1.
+    InvalidLine(usize),
+
+    /// A recurrence set has no `DTSTART` line.
+    MissingStart,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingFrequency             => write!(f, "recurrence rule has no FREQ part"),
+            Error::UnsupportedFrequency(ref s)  => write!(f, "unsupported recurrence frequency ‘{}’", s),
+            Error::InvalidPart(ref s)           => write!(f, "invalid recurrence rule part ‘{}’", s),
+            Error::CountAndUntil                => write!(f, "recurrence rule has both COUNT and UNTIL parts"),
+            Error::InvalidLine(n)               => write!(f, "invalid recurrence set on line {}", n),
+            Error::MissingStart                 => write!(f, "recurrence set has no DTSTART line"),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+}
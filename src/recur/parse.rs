@@ -0,0 +1,208 @@
+//! Parsing recurrence rules and sets from their iCalendar text form.
+//!
+//! A rule is a list of `NAME=VALUE` parts separated by semicolons, such as
+//! `FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH`. A set is a list of lines, each
+//! holding one property: `DTSTART`, `RRULE`, `RDATE`, or `EXDATE`. Dates
+//! and times are in the iCalendar basic format, such as `20170105` or
+//! `20170105T090000`.
+
+use std::str::FromStr;
+
+use cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday};
+use recur::{ByDay, Error, ExtraDate, Frequency, Recurrence, RecurrenceRule, Until};
+
+
+impl FromStr for RecurrenceRule {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.strip_prefix("RRULE:").unwrap_or(input);
+        let mut rule = None;
+        let mut parts = Vec::new();
+
+        // The frequency has to be known before anything else, so find it
+        // first, wherever it is in the list.
+        for part in input.trim().split(';').filter(|p| !p.is_empty()) {
+            let (name, value) = match part.find('=') {
+                Some(index) => (&part[.. index], &part[index + 1 ..]),
+                None        => return Err(Error::InvalidPart(part.to_string())),
+            };
+
+            if name.eq_ignore_ascii_case("FREQ") {
+                rule = Some(RecurrenceRule::new(frequency(value)?));
+            }
+            else {
+                parts.push((name.to_ascii_uppercase(), value, part));
+            }
+        }
+
+        let mut rule = rule.ok_or(Error::MissingFrequency)?;
+        for (name, value, part) in parts {
+            let invalid = || Error::InvalidPart(part.to_string());
+
+            match &*name {
+                "INTERVAL"    => rule.interval = value.parse().ok().filter(|&i| i > 0).ok_or_else(invalid)?,
+                "COUNT"       => rule.count = Some(value.parse().map_err(|_| invalid())?),
+                "UNTIL"       => rule.until = Some(until(value).ok_or_else(invalid)?),
+                "BYDAY"       => rule.by_day = list(value, by_day).ok_or_else(invalid)?,
+                "BYMONTHDAY"  => rule.by_month_day = list(value, |v| number(v, 31)).ok_or_else(invalid)?,
+                "BYYEARDAY"   => rule.by_year_day = list(value, |v| number(v, 366)).ok_or_else(invalid)?,
+                "BYWEEKNO"    => rule.by_week_no = list(value, |v| number(v, 53)).ok_or_else(invalid)?,
+                "BYSETPOS"    => rule.by_set_pos = list(value, |v| number(v, 366)).ok_or_else(invalid)?,
+                "BYMONTH"     => rule.by_month = list(value, |v| v.parse().ok().and_then(|m| Month::from_one(m).ok())).ok_or_else(invalid)?,
+                "BYHOUR"      => rule.by_hour = list(value, |v| v.parse().ok().filter(|h| (0 .. 24).contains(h))).ok_or_else(invalid)?,
+                "WKST"        => rule.week_start = weekday(value).ok_or_else(invalid)?,
+                _             => return Err(invalid()),
+            }
+        }
+
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(Error::CountAndUntil);
+        }
+
+        // Week numbers only make sense for yearly rules.
+        if !rule.by_week_no.is_empty() && rule.frequency != Frequency::Yearly {
+            return Err(Error::InvalidPart(format!("BYWEEKNO with FREQ={:?}", rule.frequency).to_uppercase()));
+        }
+
+        Ok(rule)
+    }
+}
+
+impl Recurrence {
+
+    /// Parses a recurrence set from its `DTSTART`, `RRULE`, `RDATE`, and
+    /// `EXDATE` lines. There must be exactly one `DTSTART` line, but any
+    /// number of the others, in any order.
+    ///
+    /// Property parameters, such as `VALUE=DATE` or `TZID=…`, are ignored:
+    /// every date and time is treated as a wall-clock time, unless it ends
+    /// in `Z` to say it’s in UTC, and a date on its own is treated as
+    /// midnight. Blank lines are skipped.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut start = None;
+        let mut rules = Vec::new();
+        let mut dates = Vec::new();
+        let mut exclusions = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (name, value) = match line.find(':') {
+                Some(index) => (&line[.. index], &line[index + 1 ..]),
+                None        => return Err(Error::InvalidLine(line_number)),
+            };
+
+            // Remove any parameters from the property name.
+            let name = name.split(';').next().unwrap().to_ascii_uppercase();
+            let values = || list(value, extra_date).ok_or(Error::InvalidLine(line_number));
+
+            match &*name {
+                "DTSTART" if start.is_none()  => start = Some(extra_date(value).ok_or(Error::InvalidLine(line_number))?),
+                "RRULE"                       => rules.push(value.parse()?),
+                "RDATE"                       => dates.extend(values()?),
+                "EXDATE"                      => exclusions.extend(values()?),
+                _                             => return Err(Error::InvalidLine(line_number)),
+            }
+        }
+
+        let start = start.ok_or(Error::MissingStart)?;
+        Ok(Self { start, rules, dates, exclusions })
+    }
+}
+
+
+fn frequency(input: &str) -> Result<Frequency, Error> {
+    match &*input.to_ascii_uppercase() {
+        "YEARLY"   => Ok(Frequency::Yearly),
+        "MONTHLY"  => Ok(Frequency::Monthly),
+        "WEEKLY"   => Ok(Frequency::Weekly),
+        "DAILY"    => Ok(Frequency::Daily),
+        "HOURLY"   => Ok(Frequency::Hourly),
+        _          => Err(Error::UnsupportedFrequency(input.to_string())),
+    }
+}
+
+/// Parses a comma-separated list of values, failing if any of them fail.
+fn list<T, F: Fn(&str) -> Option<T>>(input: &str, parse: F) -> Option<Vec<T>> {
+    input.split(',').map(|v| parse(v.trim())).collect()
+}
+
+/// Parses a number that’s between 1 and the given maximum, or between the
+/// negative maximum and -1.
+fn number<T: FromStr + Into<i64> + Copy>(input: &str, max: i64) -> Option<T> {
+    input.trim_start_matches('+').parse().ok().filter(|&n: &T| {
+        let n: i64 = n.into();
+        n != 0 && n.abs() <= max
+    })
+}
+
+fn weekday(input: &str) -> Option<Weekday> {
+    Some(match &*input.to_ascii_uppercase() {
+        "SU" => Weekday::Sunday,     "MO" => Weekday::Monday,
+        "TU" => Weekday::Tuesday,    "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,   "FR" => Weekday::Friday,
+        "SA" => Weekday::Saturday,   _    => return None,
+    })
+}
+
+/// Parses a weekday with an optional ordinal, such as `MO` or `-1FR`.
+fn by_day(input: &str) -> Option<ByDay> {
+    if input.len() < 2 || !input.is_char_boundary(input.len() - 2) {
+        return None;
+    }
+
+    let (ordinal, day) = input.split_at(input.len() - 2);
+    let ordinal = if ordinal.is_empty() { 0 } else { number(ordinal, 53)? };
+    Some(ByDay { ordinal, weekday: weekday(day)? })
+}
+
+/// Parses a date or datetime in the iCalendar basic format, ignoring any
+/// trailing `Z`.
+fn datetime(input: &str) -> Option<LocalDateTime> {
+    let input = input.strip_suffix('Z').unwrap_or(input);
+    if !input.bytes().all(|b| b.is_ascii_digit() || b == b'T') {
+        return None;
+    }
+
+    let field = |range: ::std::ops::Range<usize>| input.get(range).and_then(|f| f.parse::<i64>().ok());
+    let month = Month::from_one(field(4 .. 6)? as i8).ok()?;
+    let date = LocalDate::ymd(field(0 .. 4)?, month, field(6 .. 8)? as i8).ok()?;
+
+    let time = match input.len() {
+        8  => LocalTime::midnight(),
+        15 if &input[8 .. 9] == "T" => LocalTime::hms(field(9 .. 11)? as i8, field(11 .. 13)? as i8, field(13 .. 15)? as i8).ok()?,
+        _  => return None,
+    };
+
+    Some(LocalDateTime::new(date, time))
+}
+
+fn extra_date(input: &str) -> Option<ExtraDate> {
+    let datetime = datetime(input)?;
+
+    Some(if input.ends_with('Z') {
+        ExtraDate::Utc(datetime.to_instant())
+    }
+    else {
+        ExtraDate::Local(datetime)
+    })
+}
+
+fn until(input: &str) -> Option<Until> {
+    let datetime = datetime(input)?;
+
+    Some(if input.ends_with('Z') {
+        Until::Utc(datetime.to_instant())
+    }
+    else if input.len() == 8 {
+        Until::Date(datetime.date())
+    }
+    else {
+        Until::Local(datetime)
+    })
+}
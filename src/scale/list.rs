@@ -0,0 +1,230 @@
+//! Parsing the `leap-seconds.list` file published by the IERS and NIST,
+//! and distributed with the tz database.
+//!
+//! Apart from comments, the file contains three special lines: the time it
+//! was last updated (`#$`), the time after which it should no longer be
+//! used (`#@`), and a SHA-1 hash of its contents (`#h`). Every other line
+//! holds a leap second, as the NTP timestamp at which it takes effect and
+//! the new offset of TAI ahead of UTC. All timestamps in the file are NTP
+//! timestamps, counting seconds since **1st January, 1900**.
+
+use std::borrow::Cow;
+
+use instant::{Instant, NTP_EPOCH};
+use scale::{Error, LeapSecond, LeapSecondTable};
+
+
+impl LeapSecondTable {
+
+    /// Parses the contents of a `leap-seconds.list` file into a table.
+    ///
+    /// As well as checking that every line is valid, this checks that the
+    /// hash line matches the rest of the file, and that the file has not
+    /// expired by the given instant, which would normally be the current
+    /// time.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, LeapSecondTable};
+    ///
+    /// let input = "\
+    /// #$\t3676924800
+    /// #@\t3928521600
+    /// 2272060800\t10\t# 1 Jan 1972
+    /// 2287785600\t11\t# 1 Jul 1972
+    /// #h\td7e8daaa 5093916f 5ddff9b0 985835e2 d88ba258
+    /// ";
+    ///
+    /// let table = LeapSecondTable::parse_list(input, Instant::at(1_500_000_000)).unwrap();
+    /// assert_eq!(table.leap_seconds().len(), 2);
+    /// assert_eq!(table.expires(), Some(Instant::at(1_719_532_800)));
+    ///
+    /// assert!(LeapSecondTable::parse_list(input, Instant::at(1_800_000_000)).is_err());
+    /// ```
+    pub fn parse_list(input: &str, now: Instant) -> Result<Self, Error> {
+        let mut updated = None;
+        let mut expires = None;
+        let mut hash = None;
+        let mut leap_seconds = Vec::new();
+
+        // The hash is computed over the digits of the last-update time, the
+        // expiry time, and the first two fields of every leap second line,
+        // with everything else stripped out.
+        let mut hashed = String::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+
+            if let Some(rest) = line.strip_prefix("#$") {
+                let field = number_field(rest, line_number)?;
+                hashed.push_str(field);
+                updated = Some(field);
+            }
+            else if let Some(rest) = line.strip_prefix("#@") {
+                let field = number_field(rest, line_number)?;
+                hashed.push_str(field);
+                expires = Some(ntp_to_instant(field, line_number)?);
+            }
+            else if let Some(rest) = line.strip_prefix("#h") {
+                hash = Some(hash_field(rest, line_number)?);
+            }
+            else if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            else {
+                let data = line.split('#').next().unwrap_or("");
+                let mut fields = data.split_whitespace();
+
+                let (at, offset) = match (fields.next(), fields.next()) {
+                    (Some(at), Some(offset))  => (at, offset),
+                    _                         => return Err(Error::InvalidLine(line_number)),
+                };
+
+                hashed.push_str(at);
+                hashed.push_str(offset);
+
+                leap_seconds.push(LeapSecond {
+                    at: ntp_to_instant(at, line_number)?.seconds(),
+                    tai_offset: offset.parse().map_err(|_| Error::InvalidLine(line_number))?,
+                });
+            }
+        }
+
+        if updated.is_none() {
+            return Err(Error::MissingLine('$'));
+        }
+
+        let expires = expires.ok_or(Error::MissingLine('@'))?;
+        let hash = hash.ok_or(Error::MissingLine('h'))?;
+
+        if sha1(hashed.as_bytes()) != hash {
+            return Err(Error::HashMismatch);
+        }
+
+        if now >= expires {
+            return Err(Error::Expired(expires));
+        }
+
+        let table = Self::new(leap_seconds)?;
+        Ok(Self {
+            leap_seconds: Cow::Owned(table.leap_seconds.into_owned()),
+            expires: Some(expires),
+        })
+    }
+}
+
+/// Extracts the single number from a `#$` or `#@` line.
+fn number_field(rest: &str, line_number: usize) -> Result<&str, Error> {
+    let field = rest.trim();
+    if !field.is_empty() && field.bytes().all(|b| b.is_ascii_digit()) {
+        Ok(field)
+    }
+    else {
+        Err(Error::InvalidLine(line_number))
+    }
+}
+
+/// Parses the five groups of hex digits on a `#h` line. Leading zeroes
+/// within a group are sometimes left out, so each group is parsed as a
+/// number, rather than as a fixed-width string.
+fn hash_field(rest: &str, line_number: usize) -> Result<[u32; 5], Error> {
+    let mut hash = [0; 5];
+    let mut groups = rest.split_whitespace();
+
+    for word in hash.iter_mut() {
+        let group = groups.next().ok_or(Error::InvalidLine(line_number))?;
+        *word = u32::from_str_radix(group, 16).map_err(|_| Error::InvalidLine(line_number))?;
+    }
+
+    if groups.next().is_some() {
+        return Err(Error::InvalidLine(line_number));
+    }
+
+    Ok(hash)
+}
+
+/// Converts an NTP timestamp, in whole seconds, to an instant.
+fn ntp_to_instant(field: &str, line_number: usize) -> Result<Instant, Error> {
+    match field.parse::<i64>() {
+        Ok(seconds)  => Ok(Instant::at(seconds + NTP_EPOCH)),
+        Err(_)       => Err(Error::InvalidLine(line_number)),
+    }
+}
+
+
+/// Computes the SHA-1 hash of the given data, which is the algorithm used
+/// by the `#h` line. This is *only* used to check the integrity of the file,
+/// which is what it was chosen for, and not for anything security-related.
+fn sha1(data: &[u8]) -> [u32; 5] {
+    let mut state: [u32; 5] = [ 0x6745_2301, 0xEFCD_AB89, 0x98BA_DCFE, 0x1032_5476, 0xC3D2_E1F0 ];
+
+    // Pad the message with a single 1 bit, then zeroes, then the message
+    // length in bits, up to a multiple of 64 bytes.
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for block in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([ word[0], word[1], word[2], word[3] ]);
+        }
+        for i in 16 .. 80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                 0 ..= 19  => ((b & c) | (!b & d),           0x5A82_7999),
+                20 ..= 39  => (b ^ c ^ d,                    0x6ED9_EBA1),
+                40 ..= 59  => ((b & c) | (b & d) | (c & d),  0x8F1B_BCDC),
+                _          => (b ^ c ^ d,                    0xCA62_C1D6),
+            };
+
+            let temp = a.rotate_left(5)
+                        .wrapping_add(f)
+                        .wrapping_add(e)
+                        .wrapping_add(k)
+                        .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (s, v) in state.iter_mut().zip(&[ a, b, c, d, e ]) {
+            *s = s.wrapping_add(*v);
+        }
+    }
+
+    state
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::sha1;
+
+    #[test]
+    fn empty() {
+        assert_eq!(sha1(b""), [ 0xda39a3ee, 0x5e6b4b0d, 0x3255bfef, 0x95601890, 0xafd80709 ]);
+    }
+
+    #[test]
+    fn abc() {
+        assert_eq!(sha1(b"abc"), [ 0xa9993e36, 0x4706816a, 0xba3e2571, 0x7850c26c, 0x9cd0d89d ]);
+    }
+
+    #[test]
+    fn two_blocks() {
+        let input = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        assert_eq!(sha1(input), [ 0x84983e44, 0x1c3bd26e, 0xbaae4aa1, 0xf95129e5, 0xe54670f1 ]);
+    }
+}
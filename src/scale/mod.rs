@@ -0,0 +1,301 @@
+//! Time scales, and the leap seconds that separate them.
+//!
+//! An `Instant` counts seconds in the same way as Unix time does, which
+//! follows UTC but pretends that every day is exactly 86,400 seconds long.
+//! Other time scales, such as TAI and GPS time, count every second that
+//! actually elapses, so they drift away from UTC each time a leap second
+//! gets inserted. Converting between them requires a table of when those
+//! leap seconds occurred.
+
+use std::borrow::Cow;
+use std::error::Error as ErrorTrait;
+use std::fmt;
+
+use cal::datetime::{LocalDate, LocalDateTime, LocalTime};
+use duration::Duration;
+use instant::{Instant, GPS_EPOCH};
+
+mod list;
+
+
+/// A **time scale** is a way of counting seconds from an epoch.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TimeScale {
+
+    /// Coordinated Universal Time, counted as Unix time: the number of
+    /// non-leap seconds since **1st January, 1970**. This is what
+    /// `Instant` uses.
+    UTC,
+
+    /// International Atomic Time, counted as the number of seconds since
+    /// **1st January, 1970** TAI, in the same way as Linux’s `CLOCK_TAI`
+    /// clock. This is ahead of UTC by the current TAI−UTC offset.
+    TAI,
+
+    /// GPS time, counted as the number of seconds since the GPS epoch,
+    /// **6th January, 1980** UTC. This is always 19 seconds behind TAI.
+    GPS,
+}
+
+/// Number of seconds that GPS time is behind TAI. This has been fixed since
+/// the GPS epoch, at which point TAI was 19 seconds ahead of UTC.
+const GPS_BEHIND_TAI: i64 = 19;
+
+
+/// A **leap second** entry marks the point at which the offset between TAI
+/// and UTC changed.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct LeapSecond {
+
+    /// The UTC instant at which the new offset comes into effect, stored as
+    /// a Unix timestamp. This is the midnight immediately *after* the
+    /// leap second.
+    pub at: i64,
+
+    /// The total offset of TAI ahead of UTC, in seconds, from this point
+    /// onwards.
+    pub tai_offset: i64,
+}
+
+/// The leap seconds that have been announced so far, as published by the
+/// IERS in the `leap-seconds.list` file that expires on **28th December,
+/// 2026**. The first entry is the start of the modern UTC system in 1972,
+/// before which TAI and UTC were related by fractional adjustments that
+/// this library does not attempt to model.
+static BUILTIN_LEAP_SECONDS: &[LeapSecond] = &[
+    LeapSecond { at:    63_072_000, tai_offset: 10 },  // 1972-01-01
+    LeapSecond { at:    78_796_800, tai_offset: 11 },  // 1972-07-01
+    LeapSecond { at:    94_694_400, tai_offset: 12 },  // 1973-01-01
+    LeapSecond { at:   126_230_400, tai_offset: 13 },  // 1974-01-01
+    LeapSecond { at:   157_766_400, tai_offset: 14 },  // 1975-01-01
+    LeapSecond { at:   189_302_400, tai_offset: 15 },  // 1976-01-01
+    LeapSecond { at:   220_924_800, tai_offset: 16 },  // 1977-01-01
+    LeapSecond { at:   252_460_800, tai_offset: 17 },  // 1978-01-01
+    LeapSecond { at:   283_996_800, tai_offset: 18 },  // 1979-01-01
+    LeapSecond { at:   315_532_800, tai_offset: 19 },  // 1980-01-01
+    LeapSecond { at:   362_793_600, tai_offset: 20 },  // 1981-07-01
+    LeapSecond { at:   394_329_600, tai_offset: 21 },  // 1982-07-01
+    LeapSecond { at:   425_865_600, tai_offset: 22 },  // 1983-07-01
+    LeapSecond { at:   489_024_000, tai_offset: 23 },  // 1985-07-01
+    LeapSecond { at:   567_993_600, tai_offset: 24 },  // 1988-01-01
+    LeapSecond { at:   631_152_000, tai_offset: 25 },  // 1990-01-01
+    LeapSecond { at:   662_688_000, tai_offset: 26 },  // 1991-01-01
+    LeapSecond { at:   709_948_800, tai_offset: 27 },  // 1992-07-01
+    LeapSecond { at:   741_484_800, tai_offset: 28 },  // 1993-07-01
+    LeapSecond { at:   773_020_800, tai_offset: 29 },  // 1994-07-01
+    LeapSecond { at:   820_454_400, tai_offset: 30 },  // 1996-01-01
+    LeapSecond { at:   867_715_200, tai_offset: 31 },  // 1997-07-01
+    LeapSecond { at:   915_148_800, tai_offset: 32 },  // 1999-01-01
+    LeapSecond { at: 1_136_073_600, tai_offset: 33 },  // 2006-01-01
+    LeapSecond { at: 1_230_768_000, tai_offset: 34 },  // 2009-01-01
+    LeapSecond { at: 1_341_100_800, tai_offset: 35 },  // 2012-07-01
+    LeapSecond { at: 1_435_708_800, tai_offset: 36 },  // 2015-07-01
+    LeapSecond { at: 1_483_228_800, tai_offset: 37 },  // 2017-01-01
+];
+
+/// The instant after which the embedded table can no longer be relied on to
+/// contain every leap second: 2026-12-28.
+const BUILTIN_EXPIRY: i64 = 1_798_416_000;
+
+
+/// A **leap second table** is a list of leap seconds, in order, which is
+/// used to convert instants between time scales.
+///
+/// The table embedded in this library will go out of date whenever a new
+/// leap second is announced, so it can be replaced with a newer one.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LeapSecondTable {
+    leap_seconds: Cow<'static, [LeapSecond]>,
+    expires: Option<Instant>,
+}
+
+impl LeapSecondTable {
+
+    /// Returns the table of leap seconds embedded in this library.
+    pub fn builtin() -> Self {
+        Self {
+            leap_seconds: Cow::Borrowed(BUILTIN_LEAP_SECONDS),
+            expires: Some(Instant::at(BUILTIN_EXPIRY)),
+        }
+    }
+
+    /// Creates a new table from the given list of leap seconds, which must
+    /// not be empty, and must be in strictly ascending order of time. The
+    /// table has no expiry date.
+    pub fn new(leap_seconds: Vec<LeapSecond>) -> Result<Self, Error> {
+        if leap_seconds.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if leap_seconds.windows(2).any(|pair| pair[0].at >= pair[1].at) {
+            return Err(Error::OutOfOrder);
+        }
+
+        Ok(Self { leap_seconds: Cow::Owned(leap_seconds), expires: None })
+    }
+
+    /// Returns the leap seconds in this table.
+    pub fn leap_seconds(&self) -> &[LeapSecond] {
+        &self.leap_seconds
+    }
+
+    /// Returns the instant after which this table may be missing leap
+    /// seconds that have since been announced, if it has one.
+    pub fn expires(&self) -> Option<Instant> {
+        self.expires
+    }
+
+    /// Returns whether a leap second was inserted at the end of the given
+    /// day, making its last minute 61 seconds long.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{LocalDate, Month, LeapSecondTable};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// assert!(table.has_leap_second_on(LocalDate::ymd(2016, Month::December, 31).unwrap()));
+    /// assert!(!table.has_leap_second_on(LocalDate::ymd(2017, Month::December, 31).unwrap()));
+    /// ```
+    pub fn has_leap_second_on(&self, date: LocalDate) -> bool {
+        let midnight = LocalDateTime::new(date, LocalTime::midnight()).to_instant().seconds();
+        let next_midnight = midnight + 86_400;
+
+        // The first entry marks the start of the table, rather than the
+        // point at which the offset changed, so it’s skipped.
+        self.leap_seconds.windows(2)
+            .any(|pair| pair[1].at == next_midnight && pair[1].tai_offset > pair[0].tai_offset)
+    }
+
+    /// Returns the offset of TAI ahead of UTC, in seconds, at the given
+    /// instant. Instants before the first entry in the table use that
+    /// entry’s offset.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, LeapSecondTable};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// assert_eq!(table.tai_offset(Instant::at(1_483_228_799)), 36);
+    /// assert_eq!(table.tai_offset(Instant::at(1_483_228_800)), 37);
+    /// ```
+    pub fn tai_offset(&self, utc: Instant) -> i64 {
+        match self.leap_seconds.iter().take_while(|l| l.at <= utc.seconds()).last() {
+            Some(leap)  => leap.tai_offset,
+            None        => self.leap_seconds[0].tai_offset,
+        }
+    }
+
+    /// Converts a UTC instant to the length of time since the epoch of the
+    /// given time scale.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, Duration, LeapSecondTable, TimeScale};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let gps = table.to_scale(Instant::at(1_483_228_800), TimeScale::GPS);
+    /// assert_eq!(gps, Duration::of(1_167_264_018));
+    /// ```
+    pub fn to_scale(&self, utc: Instant, scale: TimeScale) -> Duration {
+        let offset = match scale {
+            TimeScale::UTC  => 0,
+            TimeScale::TAI  => self.tai_offset(utc),
+            TimeScale::GPS  => self.tai_offset(utc) - GPS_BEHIND_TAI - GPS_EPOCH,
+        };
+
+        Duration::of_ms(utc.seconds() + offset, utc.milliseconds())
+    }
+
+    /// Converts a length of time since the epoch of the given time scale to
+    /// a UTC instant.
+    ///
+    /// Because Unix time has no way to represent a leap second, any time
+    /// that falls *during* one gets converted to the second before it, in
+    /// the same way that a Unix clock repeats 23:59:59.
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// use datetime::{Instant, Duration, LeapSecondTable, TimeScale};
+    ///
+    /// let table = LeapSecondTable::builtin();
+    /// let utc = table.from_scale(Duration::of(1_167_264_018), TimeScale::GPS);
+    /// assert_eq!(utc, Instant::at(1_483_228_800));
+    /// ```
+    pub fn from_scale(&self, elapsed: Duration, scale: TimeScale) -> Instant {
+        let (seconds, milliseconds) = elapsed.lengths();
+
+        let tai = match scale {
+            TimeScale::UTC  => return Instant::at_ms(seconds, milliseconds),
+            TimeScale::TAI  => seconds,
+            TimeScale::GPS  => seconds + GPS_EPOCH + GPS_BEHIND_TAI,
+        };
+
+        // Step through the leap seconds, switching to each new offset once
+        // the time, converted with the old offset, has reached it. A time
+        // that falls during the leap second itself gets switched over too,
+        // so it repeats the last second of the day.
+        let mut offset = self.leap_seconds[0].tai_offset;
+        for leap in self.leap_seconds.iter() {
+            if tai - offset >= leap.at {
+                offset = leap.tai_offset;
+            }
+            else {
+                break;
+            }
+        }
+
+        Instant::at_ms(tai - offset, milliseconds)
+    }
+}
+
+impl Default for LeapSecondTable {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+
+/// An error that can occur when creating or parsing a leap second table.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Error {
+
+    /// The table has no entries.
+    Empty,
+
+    /// The entries are not in ascending order of time.
+    OutOfOrder,
+
+    /// A line in a `leap-seconds.list` file could not be parsed. This
+    /// contains the line number, counting from 1.
+    InvalidLine(usize),
+
+    /// A `leap-seconds.list` file is missing its last-update (`#$`),
+    /// expiry (`#@`), or hash (`#h`) line.
+    MissingLine(char),
+
+    /// A `leap-seconds.list` file’s hash line does not match its contents.
+    HashMismatch,
+
+    /// A `leap-seconds.list` file expired at the given instant.
+    Expired(Instant),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Empty            => write!(f, "leap second table is empty"),
+            Error::OutOfOrder       => write!(f, "leap seconds are out of order"),
+            Error::InvalidLine(n)   => write!(f, "invalid leap second list on line {}", n),
+            Error::MissingLine(c)   => write!(f, "leap second list has no ‘#{}’ line", c),
+            Error::HashMismatch     => write!(f, "leap second list hash does not match"),
+            Error::Expired(when)    => write!(f, "leap second list expired at {}", when),
+        }
+    }
+}
+
+impl ErrorTrait for Error {
+}
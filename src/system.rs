@@ -32,8 +32,6 @@ pub(crate) unsafe fn sys_time() -> (i64, i16) {
 }
 
 #[cfg(windows)] use winapi::shared::minwindef::FILETIME;
-#[cfg(windows)] const HECTONANOSECS_IN_SEC: i64 = 10_000_000;
-#[cfg(windows)] const HECTONANOSEC_TO_UNIX_EPOCH: i64 = 11_644_473_600 * HECTONANOSECS_IN_SEC;
 
 /// Returns the system’s current time, as a tuple of seconds elapsed since
 /// the Unix epoch, and the millisecond of the second.
@@ -41,23 +39,12 @@ pub(crate) unsafe fn sys_time() -> (i64, i16) {
 pub(crate) unsafe fn sys_time() -> (i64, i16) {
     use std::mem;
     use winapi::um::sysinfoapi::GetSystemTimeAsFileTime;
+    use instant::Instant;
     let mut ft = mem::zeroed();
 
     GetSystemTimeAsFileTime(&mut ft);
-    (file_time_to_unix_seconds(&ft), (file_time_to_nsec(&ft) / 1000000) as i16)
-
-}
-
-#[cfg(any(target_os = "windows"))]
-fn file_time_to_nsec(ft: &FILETIME) -> i32 {
-    let t = file_time_as_u64(ft) as i64;
-    ((t % HECTONANOSECS_IN_SEC) * 100) as i32
-}
-
-#[cfg(any(target_os = "windows"))]
-fn file_time_to_unix_seconds(ft: &FILETIME) -> i64 {
-    let t = file_time_as_u64(ft) as i64;
-    ((t - HECTONANOSEC_TO_UNIX_EPOCH) / HECTONANOSECS_IN_SEC)
+    let instant = Instant::at_filetime(file_time_as_u64(&ft));
+    (instant.seconds(), instant.milliseconds())
 }
 
 #[cfg(any(target_os = "windows"))]
@@ -73,7 +60,7 @@ fn file_time_as_u64(ft: &FILETIME) -> u64 {
 pub unsafe fn sys_time() -> (i64, i16) {
     let mut tv = libc::timespec { tv_sec: 0, tv_nsec: 0 };
     let _ = clock_gettime(libc::CLOCK_REALTIME, &mut tv);
-    (tv.tv_sec as i64, (tv.tv_nsec / 1000) as i16)
+    (tv.tv_sec as i64, (tv.tv_nsec / 1_000_000) as i16)
 }
 
 /// Returns the system’s current time, as a tuple of seconds elapsed since
@@ -83,7 +70,7 @@ pub fn sys_time() -> (i64, i16) {
    let mut ts = redox_syscall::TimeSpec::default();
    let realtime_clock = redox_syscall::CLOCK_REALTIME;
    let _ = redox_syscall::clock_gettime(realtime_clock, &mut ts);
-   (ts.tv_sec, (ts.tv_nsec / 1000) as i16)
+   (ts.tv_sec, (ts.tv_nsec / 1_000_000) as i16)
 }
 
 /// Attempts to determine the system’s current time zone. There’s no
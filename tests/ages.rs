@@ -0,0 +1,123 @@
+extern crate datetime;
+use datetime::{LocalDate, Month, Period, LeapDayPolicy};
+use datetime::Month::*;
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+fn age(years: i64, months: i64, days: i64) -> Option<Period> {
+    Some(Period { years, months, days, ..Period::default() })
+}
+
+
+mod age_on {
+    use super::*;
+
+    #[test]
+    fn same_day() {
+        assert_eq!(date(1990, May, 5).age_on(date(1990, May, 5)), age(0, 0, 0));
+    }
+
+    #[test]
+    fn birthday() {
+        assert_eq!(date(1990, May, 5).age_on(date(2017, May, 5)), age(27, 0, 0));
+    }
+
+    #[test]
+    fn day_before_birthday() {
+        assert_eq!(date(1990, May, 5).age_on(date(2017, May, 4)), age(26, 11, 29));
+    }
+
+    #[test]
+    fn day_before_birthday_across_year() {
+        assert_eq!(date(1990, January, 1).age_on(date(2017, December, 31)), age(27, 11, 30));
+    }
+
+    #[test]
+    fn naive_year_subtraction_is_wrong() {
+        // Subtracting the years would give 1.
+        assert_eq!(date(2016, December, 31).age_on(date(2017, January, 1)), age(0, 0, 1));
+    }
+
+    #[test]
+    fn short_months() {
+        assert_eq!(date(2017, January, 31).age_on(date(2017, February, 28)), age(0, 1, 0));
+        assert_eq!(date(2017, January, 31).age_on(date(2017, March, 30)), age(0, 1, 30));
+        assert_eq!(date(2017, January, 31).age_on(date(2017, March, 31)), age(0, 2, 0));
+    }
+
+    #[test]
+    fn before_start() {
+        assert_eq!(date(1990, May, 5).age_on(date(1990, May, 4)), None);
+    }
+}
+
+
+mod leap_day {
+    use super::*;
+
+    #[test]
+    fn february_28_policy() {
+        let birth = date(2000, February, 29);
+        assert_eq!(birth.age_on_with(date(2001, February, 27), LeapDayPolicy::February28), age(0, 11, 29));
+        assert_eq!(birth.age_on_with(date(2001, February, 28), LeapDayPolicy::February28), age(1, 0, 0));
+        assert_eq!(birth.age_on_with(date(2001, March, 1), LeapDayPolicy::February28), age(1, 0, 1));
+    }
+
+    #[test]
+    fn march_1_policy() {
+        let birth = date(2000, February, 29);
+        assert_eq!(birth.age_on_with(date(2001, February, 28), LeapDayPolicy::March1), age(0, 11, 30));
+        assert_eq!(birth.age_on_with(date(2001, March, 1), LeapDayPolicy::March1), age(1, 0, 0));
+    }
+
+    #[test]
+    fn leap_years_unaffected() {
+        let birth = date(2000, February, 29);
+        assert_eq!(birth.age_on_with(date(2004, February, 28), LeapDayPolicy::February28), age(3, 11, 30));
+        assert_eq!(birth.age_on_with(date(2004, February, 29), LeapDayPolicy::March1), age(4, 0, 0));
+    }
+
+    #[test]
+    fn coming_of_age() {
+        let birth = date(2000, February, 29);
+        assert_eq!(birth.age_on_with(date(2018, February, 28), LeapDayPolicy::March1).unwrap().years, 17);
+        assert_eq!(birth.age_on_with(date(2018, February, 28), LeapDayPolicy::February28).unwrap().years, 18);
+    }
+
+    #[test]
+    fn default_policy() {
+        assert_eq!(LeapDayPolicy::default(), LeapDayPolicy::February28);
+    }
+}
+
+
+mod anniversaries {
+    use super::*;
+
+    #[test]
+    fn after_this_year() {
+        let dates: Vec<_> = date(1990, May, 5).anniversaries_after(date(2017, June, 1), LeapDayPolicy::default()).take(2).collect();
+        assert_eq!(dates, vec![ date(2018, May, 5), date(2019, May, 5) ]);
+    }
+
+    #[test]
+    fn on_anniversary() {
+        let mut dates = date(1990, May, 5).anniversaries_after(date(2017, May, 5), LeapDayPolicy::default());
+        assert_eq!(dates.next(), Some(date(2018, May, 5)));
+    }
+
+    #[test]
+    fn before_start() {
+        let mut dates = date(1990, May, 5).anniversaries_after(date(1980, January, 1), LeapDayPolicy::default());
+        assert_eq!(dates.next(), Some(date(1991, May, 5)));
+    }
+
+    #[test]
+    fn leap_day() {
+        let dates: Vec<_> = date(2000, February, 29).anniversaries_after(date(2000, February, 29), LeapDayPolicy::February28).take(4).collect();
+        assert_eq!(dates, vec![ date(2001, February, 28), date(2002, February, 28), date(2003, February, 28), date(2004, February, 29) ]);
+    }
+}
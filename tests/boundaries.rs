@@ -0,0 +1,81 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekday, Unit, WeekRule, DatePiece};
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+fn datetime(date: LocalDate, hour: i8, minute: i8, second: i8, millisecond: i16) -> LocalDateTime {
+    LocalDateTime::new(date, LocalTime::hms_ms(hour, minute, second, millisecond).unwrap())
+}
+
+
+mod dates {
+    use super::*;
+
+    #[test]
+    fn time_units_are_the_same_day() {
+        let day = date(2017, Month::August, 17);
+        for &unit in &[ Unit::Second, Unit::Minute, Unit::Hour, Unit::Day ] {
+            assert_eq!(day.start_of(unit), day);
+            assert_eq!(day.end_of(unit), day);
+        }
+    }
+
+    #[test]
+    fn weeks() {
+        let day = date(2017, Month::August, 17);
+        let sunday = Unit::Week(WeekRule::sunday_start());
+        assert_eq!(day.start_of(sunday), date(2017, Month::August, 13));
+        assert_eq!(day.end_of(sunday), date(2017, Month::August, 19));
+        assert_eq!(day.end_of(sunday).weekday(), Weekday::Saturday);
+    }
+
+    #[test]
+    fn quarters() {
+        let day = date(2017, Month::November, 17);
+        assert_eq!(day.start_of(Unit::Quarter), date(2017, Month::October, 1));
+        assert_eq!(day.end_of(Unit::Quarter), date(2017, Month::December, 31));
+    }
+
+    #[test]
+    fn years() {
+        let day = date(2016, Month::March, 1);
+        assert_eq!(day.start_of(Unit::Year), date(2016, Month::January, 1));
+        assert_eq!(day.end_of(Unit::Year), date(2016, Month::December, 31));
+    }
+}
+
+
+mod datetimes {
+    use super::*;
+
+    #[test]
+    fn truncation() {
+        let day = date(2017, Month::August, 17);
+        let dt = datetime(day, 13, 45, 30, 250);
+        assert_eq!(dt.truncate(Unit::Second), datetime(day, 13, 45, 30, 0));
+        assert_eq!(dt.truncate(Unit::Minute), datetime(day, 13, 45, 0, 0));
+        assert_eq!(dt.truncate(Unit::Year), datetime(date(2017, Month::January, 1), 0, 0, 0, 0));
+    }
+
+    #[test]
+    fn start_is_truncation() {
+        let dt = datetime(date(2017, Month::August, 17), 13, 45, 30, 250);
+        for &unit in &[ Unit::Second, Unit::Minute, Unit::Hour, Unit::Day, Unit::Week(WeekRule::iso()), Unit::Month, Unit::Quarter, Unit::Year ] {
+            assert_eq!(dt.start_of(unit), dt.truncate(unit));
+            assert!(dt.start_of(unit) <= dt && dt <= dt.end_of(unit));
+        }
+    }
+
+    #[test]
+    fn ends() {
+        let day = date(2017, Month::August, 17);
+        let dt = datetime(day, 13, 45, 30, 250);
+        assert_eq!(dt.end_of(Unit::Second), datetime(day, 13, 45, 30, 999));
+        assert_eq!(dt.end_of(Unit::Hour), datetime(day, 13, 59, 59, 999));
+        assert_eq!(dt.end_of(Unit::Day), datetime(day, 23, 59, 59, 999));
+        assert_eq!(dt.end_of(Unit::Quarter), datetime(date(2017, Month::September, 30), 23, 59, 59, 999));
+    }
+}
@@ -0,0 +1,214 @@
+extern crate datetime;
+use datetime::{BusinessCalendar, Holiday, Observance, LocalDate, Month, Weekday, DatePiece};
+use datetime::Month::*;
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+/// The United States federal holidays, as observed since 1971.
+fn federal() -> BusinessCalendar {
+    let mut calendar = BusinessCalendar::new();
+    let observed = |h: Holiday| h.observed(Observance::NearestWeekday);
+
+    calendar.add_holiday(observed(Holiday::fixed(January, 1).unwrap()));
+    calendar.add_holiday(Holiday::nth_weekday(January, Weekday::Monday, 3).unwrap());
+    calendar.add_holiday(Holiday::nth_weekday(February, Weekday::Monday, 3).unwrap());
+    calendar.add_holiday(Holiday::nth_weekday(May, Weekday::Monday, -1).unwrap());
+    calendar.add_holiday(observed(Holiday::fixed(July, 4).unwrap()));
+    calendar.add_holiday(Holiday::nth_weekday(September, Weekday::Monday, 1).unwrap());
+    calendar.add_holiday(Holiday::nth_weekday(October, Weekday::Monday, 2).unwrap());
+    calendar.add_holiday(observed(Holiday::fixed(November, 11).unwrap()));
+    calendar.add_holiday(Holiday::nth_weekday(November, Weekday::Thursday, 4).unwrap());
+    calendar.add_holiday(observed(Holiday::fixed(December, 25).unwrap()));
+    calendar
+}
+
+
+mod holidays {
+    use super::*;
+
+    #[test]
+    fn federal_holidays_2017() {
+        assert_eq!(federal().holidays_in(2017), vec![
+            date(2017, January, 2),  date(2017, January, 16), date(2017, February, 20),
+            date(2017, May, 29),     date(2017, July, 4),     date(2017, September, 4),
+            date(2017, October, 9),  date(2017, November, 10), date(2017, November, 23),
+            date(2017, December, 25),
+        ]);
+    }
+
+    #[test]
+    fn observed_in_previous_year() {
+        // The 1st of January 2022 was a Saturday.
+        let calendar = federal();
+        assert!(calendar.is_holiday(date(2021, December, 31)));
+        assert!(!calendar.is_holiday(date(2022, January, 3)));
+        assert_eq!(calendar.holidays_in(2021).last(), Some(&date(2021, December, 31)));
+        assert_eq!(calendar.holidays_in(2022).first(), Some(&date(2022, January, 17)));
+    }
+
+    #[test]
+    fn following_monday() {
+        let boxing_day = Holiday::fixed(December, 26).unwrap().observed(Observance::FollowingMonday);
+        assert_eq!(boxing_day.observed_in(2020), Some(date(2020, December, 28)));
+        assert_eq!(boxing_day.observed_in(2021), Some(date(2021, December, 27)));
+        assert_eq!(boxing_day.observed_in(2022), Some(date(2022, December, 26)));
+    }
+
+    #[test]
+    fn substitute_days() {
+        let mut calendar = BusinessCalendar::new();
+        calendar.add_holiday(Holiday::fixed(December, 25).unwrap().observed(Observance::FollowingMonday));
+        calendar.add_holiday(Holiday::fixed(December, 26).unwrap().observed(Observance::FollowingMonday));
+
+        assert_eq!(calendar.holidays_in(2020), vec![ date(2020, December, 25), date(2020, December, 28) ]);
+        assert_eq!(calendar.holidays_in(2021), vec![ date(2021, December, 27), date(2021, December, 28) ]);
+        assert_eq!(calendar.holidays_in(2022), vec![ date(2022, December, 26), date(2022, December, 27) ]);
+        assert!(calendar.is_holiday(date(2021, December, 28)));
+        assert_eq!(calendar.next_business_day(date(2021, December, 24)), date(2021, December, 29));
+    }
+
+    #[test]
+    fn substitute_days_across_years() {
+        // The 31st of December 2022 was a Saturday, and the 1st of January
+        // 2023 a Sunday, so both would be observed on the Monday.
+        let mut calendar = BusinessCalendar::new();
+        calendar.add_holiday(Holiday::fixed(December, 31).unwrap().observed(Observance::FollowingMonday));
+        calendar.add_holiday(Holiday::fixed(January, 1).unwrap().observed(Observance::FollowingMonday));
+
+        assert_eq!(calendar.holidays_in(2023)[.. 2], [ date(2023, January, 2), date(2023, January, 3) ]);
+        assert!(calendar.is_holiday(date(2023, January, 3)));
+        assert_eq!(calendar.next_business_day(date(2022, December, 30)), date(2023, January, 4));
+        assert_eq!(calendar.add_business_days(date(2022, December, 29), 2), date(2023, January, 4));
+    }
+
+    #[test]
+    fn other_weekends() {
+        // The 4th of July 2020 was a Saturday, and the 3rd a Friday.
+        let mut calendar = BusinessCalendar::with_weekend(&[ Weekday::Friday, Weekday::Saturday ]).unwrap();
+        calendar.add_holiday(Holiday::fixed(July, 3).unwrap().observed(Observance::NearestWeekday));
+        calendar.add_holiday(Holiday::fixed(July, 4).unwrap().observed(Observance::NearestWeekday));
+        calendar.add_holiday(Holiday::fixed(December, 26).unwrap().observed(Observance::FollowingMonday));
+
+        assert_eq!(calendar.holidays_in(2020), vec![
+            date(2020, July, 2), date(2020, July, 5), date(2020, December, 27),
+        ]);
+        assert!(!calendar.is_business_day(date(2020, July, 5)));
+        assert_eq!(calendar.next_business_day(date(2020, July, 1)), date(2020, July, 6));
+    }
+
+    #[test]
+    fn actual() {
+        let christmas = Holiday::fixed(December, 25).unwrap();
+        assert_eq!(christmas.date_in(2021), christmas.observed_in(2021));
+    }
+
+    #[test]
+    fn leap_day() {
+        let leap_day = Holiday::fixed(February, 29).unwrap();
+        assert_eq!(leap_day.date_in(2016), Some(date(2016, February, 29)));
+        assert_eq!(leap_day.date_in(2017), None);
+    }
+
+    #[test]
+    fn fifth_weekday() {
+        let fifth_friday = Holiday::nth_weekday(August, Weekday::Friday, 5).unwrap();
+        assert_eq!(fifth_friday.date_in(2017), None);
+        assert_eq!(fifth_friday.date_in(2018), Some(date(2018, August, 31)));
+    }
+
+    #[test]
+    fn once() {
+        let jubilee = Holiday::once(date(2022, June, 3));
+        assert_eq!(jubilee.date_in(2022), Some(date(2022, June, 3)));
+        assert_eq!(jubilee.date_in(2023), None);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Holiday::fixed(February, 30).is_err());
+        assert!(Holiday::fixed(April, 0).is_err());
+        assert!(Holiday::nth_weekday(May, Weekday::Monday, 0).is_err());
+        assert!(Holiday::nth_weekday(May, Weekday::Monday, 6).is_err());
+    }
+}
+
+
+mod business_days {
+    use super::*;
+
+    #[test]
+    fn weekends_and_holidays() {
+        let calendar = federal();
+        assert!(calendar.is_business_day(date(2017, July, 3)));
+        assert!(!calendar.is_business_day(date(2017, July, 4)));
+        assert!(!calendar.is_business_day(date(2017, July, 8)));
+        assert!(calendar.is_weekend(date(2017, July, 9)));
+    }
+
+    #[test]
+    fn next_and_previous() {
+        let calendar = federal();
+        assert_eq!(calendar.next_business_day(date(2017, July, 3)), date(2017, July, 5));
+        assert_eq!(calendar.next_business_day(date(2017, November, 22)), date(2017, November, 24));
+        assert_eq!(calendar.previous_business_day(date(2017, September, 5)), date(2017, September, 1));
+    }
+
+    #[test]
+    fn settlement() {
+        // T+2 from the Wednesday before Thanksgiving.
+        let calendar = federal();
+        assert_eq!(calendar.add_business_days(date(2017, November, 22), 2), date(2017, November, 27));
+        assert_eq!(calendar.add_business_days(date(2017, November, 27), -2), date(2017, November, 22));
+        assert_eq!(calendar.add_business_days(date(2017, November, 25), 0), date(2017, November, 25));
+    }
+
+    #[test]
+    fn whole_year() {
+        assert_eq!(federal().business_days_between(date(2017, January, 1), date(2018, January, 1)), 250);
+        assert_eq!(BusinessCalendar::new().business_days_between(date(2017, January, 1), date(2018, January, 1)), 260);
+    }
+
+    #[test]
+    fn counting_matches_stepping() {
+        let calendar = federal();
+        let start = date(2016, December, 20);
+
+        for length in 0 .. 60 {
+            let end = start.add_days(length);
+            let stepped = (0 .. length).filter(|&d| calendar.is_business_day(start.add_days(d))).count() as i64;
+            assert_eq!(calendar.business_days_between(start, end), stepped, "{:?} to {:?}", start, end);
+            assert_eq!(calendar.business_days_between(end, start), -stepped);
+        }
+    }
+
+    #[test]
+    fn adding_then_counting() {
+        let calendar = federal();
+        let start = date(2017, December, 20);
+
+        for days in 1 .. 40 {
+            let end = calendar.add_business_days(start, days);
+            assert!(calendar.is_business_day(end));
+            assert_eq!(calendar.business_days_between(calendar.next_business_day(start), end.add_days(1)), days);
+        }
+    }
+
+    #[test]
+    fn other_weekends() {
+        let calendar = BusinessCalendar::with_weekend(&[ Weekday::Friday, Weekday::Saturday ]).unwrap();
+        let thursday = date(2017, August, 17);
+        assert_eq!(calendar.next_business_day(thursday), date(2017, August, 20));
+        assert_eq!(calendar.next_business_day(thursday).weekday(), Weekday::Sunday);
+        assert_eq!(calendar.business_days_between(thursday, thursday.add_days(14)), 10);
+    }
+
+    #[test]
+    fn no_business_days() {
+        let every_day = [ Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday,
+                          Weekday::Friday, Weekday::Saturday, Weekday::Sunday ];
+        assert!(BusinessCalendar::with_weekend(&every_day).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 extern crate datetime;
-use datetime::{LocalDateTime, Duration};
+use datetime::{LocalDateTime, Month, Duration, DatePiece};
 
 
 #[test]
@@ -13,3 +13,112 @@ fn subtraction() {
     let date = LocalDateTime::at(100000000);
     assert_eq!(LocalDateTime::at(99999999), date - Duration::of(1))
 }
+
+#[test]
+fn leap_day_of_400_year_cycle() {
+    // Midnight on the 29th of February 1600, 2000, and 2400, each of which
+    // is the last day of a 400-year cycle.
+    for &seconds in &[ -11_670_998_400, 951_782_400, 13_574_563_200 ] {
+        let datetime = LocalDateTime::at(seconds);
+        assert_eq!((datetime.month(), datetime.day()), (Month::February, 29));
+    }
+}
+
+
+mod dates {
+    use datetime::{LocalDate, Month, Weekday, DatePiece};
+
+    #[test]
+    fn add_days() {
+        let date = LocalDate::ymd(2015, Month::December, 31).unwrap();
+        assert_eq!(date.add_days(1), LocalDate::ymd(2016, Month::January, 1).unwrap());
+    }
+
+    #[test]
+    fn add_days_leap_year() {
+        let date = LocalDate::ymd(2000, Month::February, 28).unwrap();
+        assert_eq!(date.add_days(1), LocalDate::ymd(2000, Month::February, 29).unwrap());
+        assert_eq!(date.add_days(366), LocalDate::ymd(2001, Month::February, 28).unwrap());
+    }
+
+    #[test]
+    fn subtract_days() {
+        let date = LocalDate::ymd(1970, Month::January, 1).unwrap();
+        assert_eq!(date.add_days(-1), LocalDate::ymd(1969, Month::December, 31).unwrap());
+    }
+
+    #[test]
+    fn add_weeks() {
+        let date = LocalDate::ymd(2015, Month::September, 11).unwrap();
+        let later = date.add_weeks(52);
+        assert_eq!(later, LocalDate::ymd(2016, Month::September, 9).unwrap());
+        assert_eq!(later.weekday(), Weekday::Friday);
+    }
+
+    #[test]
+    fn days_until() {
+        let start = LocalDate::ymd(1600, Month::January, 1).unwrap();
+        let end   = LocalDate::ymd(2000, Month::January, 1).unwrap();
+        assert_eq!(start.days_until(end), 146_097);
+    }
+
+    #[test]
+    fn subtraction() {
+        let start = LocalDate::ymd(2015, Month::March, 1).unwrap();
+        let end   = LocalDate::ymd(2016, Month::March, 1).unwrap();
+        assert_eq!(end - start, 366);
+        assert_eq!(start - end, -366);
+    }
+
+    #[test]
+    fn round_trip() {
+        let start = LocalDate::ymd(1900, Month::January, 1).unwrap();
+        for days in (-100_000 .. 100_000).step_by(97) {
+            assert_eq!(start.add_days(days) - start, days);
+        }
+    }
+}
+
+
+mod year_months {
+    use datetime::Year;
+    use datetime::Month::*;
+
+    #[test]
+    fn add_months() {
+        assert_eq!(Year(2016).month(March) + 2, Year(2016).month(May))
+    }
+
+    #[test]
+    fn add_months_across_year() {
+        assert_eq!(Year(2016).month(November) + 3, Year(2017).month(February))
+    }
+
+    #[test]
+    fn add_many_months() {
+        assert_eq!(Year(2016).month(January) + 120, Year(2026).month(January))
+    }
+
+    #[test]
+    fn subtract_months_across_year() {
+        assert_eq!(Year(2016).month(February) - 3, Year(2015).month(November))
+    }
+
+    #[test]
+    fn subtract_months_before_year_zero() {
+        assert_eq!(Year(0).month(January) - 1, Year(-1).month(December))
+    }
+
+    #[test]
+    fn months_until() {
+        let start = Year(1999).month(December);
+        assert_eq!(start.months_until(Year(2001).month(January)), 13);
+        assert_eq!(start.months_until(start), 0);
+    }
+
+    #[test]
+    fn succ_and_pred() {
+        assert_eq!(Year(2000).succ(), Year(2001));
+        assert_eq!(Year(2000).pred(), Year(1999));
+    }
+}
@@ -79,6 +79,43 @@ mod months {
     }
 }
 
+mod consecutive_months {
+    use super::*;
+    use datetime::Month::*;
+
+    #[test]
+    fn across_years() {
+        let months: Vec<_> = Year(2015).month(November).until(Year(2016).month(March)).collect();
+        assert_eq!(months, vec![
+            Year(2015).month(November),
+            Year(2015).month(December),
+            Year(2016).month(January),
+            Year(2016).month(February),
+        ]);
+    }
+
+    #[test]
+    fn backwards() {
+        let months: Vec<_> = Year(2015).month(December).until(Year(2016).month(February)).rev().collect();
+        assert_eq!(months, vec![
+            Year(2016).month(January),
+            Year(2015).month(December),
+        ]);
+    }
+
+    #[test]
+    fn empty() {
+        let month = Year(2015).month(December);
+        assert_eq!(month.until(month).count(), 0);
+        assert_eq!(month.until(Year(2015).month(January)).count(), 0);
+    }
+
+    #[test]
+    fn many_years() {
+        assert_eq!(Year(2000).month(January).until(Year(2010).month(January)).count(), 120);
+    }
+}
+
 mod days {
     use super::*;
     use datetime::LocalDate;
@@ -132,3 +169,99 @@ fn entire_leap_year() {
 
     assert_eq!(count, 366);
 }
+
+
+mod date_ranges {
+    use datetime::{LocalDate, Month, Year, DateRange, DateStep, DatePiece};
+
+    fn date(year: i64, month: Month, day: i8) -> LocalDate {
+        LocalDate::ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn half_open() {
+        let days: Vec<_> = DateRange::days(date(2016, Month::December, 30) .. date(2017, Month::January, 2)).collect();
+        assert_eq!(days, vec![
+            date(2016, Month::December, 30),
+            date(2016, Month::December, 31),
+            date(2017, Month::January, 1),
+        ]);
+    }
+
+    #[test]
+    fn inclusive() {
+        let range = DateRange::days(date(2016, Month::December, 30) ..= date(2017, Month::January, 2));
+        assert_eq!(range.count(), 4);
+    }
+
+    #[test]
+    fn empty() {
+        let start = date(2017, Month::January, 2);
+        assert_eq!(DateRange::days(start .. start).count(), 0);
+        assert_eq!(DateRange::days(start .. date(2016, Month::January, 1)).count(), 0);
+        assert_eq!(DateRange::new(start ..= start, DateStep::Years(3)).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn invalid_step() {
+        let start = date(2017, Month::January, 2);
+        assert!(DateRange::new(start .. start, DateStep::Days(0)).is_err());
+        assert!(DateRange::new(start .. start, DateStep::Months(-1)).is_err());
+    }
+
+    #[test]
+    fn weeks() {
+        let mut range = DateRange::new(date(2017, Month::January, 1) .. date(2017, Month::January, 29), DateStep::Weeks(1)).unwrap();
+        assert_eq!(range.size_hint(), (4, Some(4)));
+        assert_eq!(range.next_back(), Some(date(2017, Month::January, 22)));
+    }
+
+    #[test]
+    fn uneven_days() {
+        let range = DateRange::new(date(2017, Month::January, 1) ..= date(2017, Month::January, 10), DateStep::Days(3)).unwrap();
+        let days: Vec<_> = range.map(|d| d.day()).collect();
+        assert_eq!(days, vec![ 1, 4, 7, 10 ]);
+    }
+
+    #[test]
+    fn months_clamp_to_end() {
+        let range = DateRange::new(date(2017, Month::January, 31) .. date(2018, Month::January, 1), DateStep::Months(1)).unwrap();
+        let dates: Vec<_> = range.collect();
+        assert_eq!(dates.len(), 12);
+        assert_eq!(dates[1], date(2017, Month::February, 28));
+        assert_eq!(dates[2], date(2017, Month::March, 31));
+        assert_eq!(dates[3], date(2017, Month::April, 30));
+    }
+
+    #[test]
+    fn months_end_bound() {
+        let start = date(2017, Month::January, 15);
+        assert_eq!(DateRange::new(start .. date(2017, Month::March, 15), DateStep::Months(1)).unwrap().count(), 2);
+        assert_eq!(DateRange::new(start ..= date(2017, Month::March, 15), DateStep::Months(1)).unwrap().count(), 3);
+        assert_eq!(DateRange::new(start ..= date(2017, Month::March, 14), DateStep::Months(1)).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn years_from_leap_day() {
+        let range = DateRange::new(date(2016, Month::February, 29) ..= date(2020, Month::December, 31), DateStep::Years(1)).unwrap();
+        let dates: Vec<_> = range.collect();
+        assert_eq!(dates.len(), 5);
+        assert_eq!(dates[1], date(2017, Month::February, 28));
+        assert_eq!(dates[4], date(2020, Month::February, 29));
+    }
+
+    #[test]
+    fn backwards() {
+        let range = DateRange::new(date(2010, Month::March, 1) .. date(2017, Month::March, 1), DateStep::Years(2)).unwrap();
+        let years: Vec<_> = range.rev().map(|d| d.year()).collect();
+        assert_eq!(years, vec![ 2016, 2014, 2012, 2010 ]);
+    }
+
+    #[test]
+    fn year_days() {
+        let mut days = Year(2016).days();
+        assert_eq!(days.next(), Some(date(2016, Month::January, 1)));
+        assert_eq!(days.next_back(), Some(date(2016, Month::December, 31)));
+        assert_eq!(days.count(), 364);
+    }
+}
@@ -0,0 +1,211 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, CalendarEvent, TimeZone, TimePiece};
+use datetime::recur::calendar::Error;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource};
+use std::borrow::Cow;
+
+
+fn at(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hms(hour, minute, second).unwrap())
+}
+
+fn event(input: &str) -> CalendarEvent {
+    input.parse().unwrap()
+}
+
+fn next(input: &str, after: LocalDateTime) -> Option<LocalDateTime> {
+    event(input).next_elapse(after)
+}
+
+
+mod parsing {
+    use super::*;
+
+    #[test]
+    fn weekday_lists() {
+        assert_eq!(event("Sat,Thu,Mon..Wed,Sat..Sun"), event("Mon..Thu,Sat,Sun *-*-* 00:00:00"));
+        assert_eq!(event("Wed..Wed,Wed *-1"), event("Wed *-*-01 00:00:00"));
+        assert_eq!(event("monday"), event("Mon"));
+    }
+
+    #[test]
+    fn omitted_parts() {
+        assert_eq!(event("2003-03-05"), event("2003-03-05 00:00:00"));
+        assert_eq!(event("05-05"), event("*-05-05 00:00:00"));
+        assert_eq!(event("*:2/3"), event("*-*-* *:02/3:00"));
+        assert_eq!(event("12:00"), event("*-*-* 12:00:00"));
+    }
+
+    #[test]
+    fn shorthands() {
+        assert_eq!(event("minutely"), event("*-*-* *:*:00"));
+        assert_eq!(event("hourly"), event("*-*-* *:00:00"));
+        assert_eq!(event("daily"), event("*-*-* 00:00:00"));
+        assert_eq!(event("weekly"), event("Mon *-*-* 00:00:00"));
+        assert_eq!(event("monthly"), event("*-*-01 00:00:00"));
+        assert_eq!(event("quarterly"), event("*-01,04,07,10-01 00:00:00"));
+        assert_eq!(event("semiannually"), event("*-01,07-01 00:00:00"));
+        assert_eq!(event("annually"), event("yearly"));
+    }
+
+    #[test]
+    fn time_zones() {
+        assert_eq!(event("daily").time_zone(), None);
+        assert_eq!(event("daily UTC").time_zone(), Some("UTC"));
+        assert_eq!(event("Mon..Fri 09:00 Europe/London").time_zone(), Some("Europe/London"));
+        assert_eq!(event("*-*-* 09:00 America/Port-au-Prince").time_zone(), Some("America/Port-au-Prince"));
+        assert_eq!(event("daily Etc/GMT+5").time_zone(), Some("Etc/GMT+5"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<CalendarEvent>(), Err(Error::Empty));
+        assert_eq!("Mon..Fry".parse::<CalendarEvent>(), Err(Error::InvalidWord("Mon..Fry".into())));
+        assert_eq!("Fri..Mon".parse::<CalendarEvent>(), Err(Error::InvalidWord("Fri..Mon".into())));
+        assert_eq!("*-13-01".parse::<CalendarEvent>(), Err(Error::InvalidWord("*-13-01".into())));
+        assert_eq!("25:00".parse::<CalendarEvent>(), Err(Error::InvalidWord("25:00".into())));
+        assert_eq!("daily UTC extra".parse::<CalendarEvent>(), Err(Error::InvalidWord("extra".into())));
+        assert_eq!("Mon..Fri 09:00 UTc".parse::<CalendarEvent>(), Err(Error::InvalidWord("UTc".into())));
+        assert!("daily Europe/".parse::<CalendarEvent>().is_err());
+        assert!("daily /London".parse::<CalendarEvent>().is_err());
+        assert!("daily 0.5".parse::<CalendarEvent>().is_err());
+        assert!("*-*-* 00:00:00.5".parse::<CalendarEvent>().is_err());
+        assert!("*-*-* *:*/0".parse::<CalendarEvent>().is_err());
+        assert!("1969-01-01".parse::<CalendarEvent>().is_err());
+    }
+}
+
+
+mod elapsing {
+    use super::*;
+
+    #[test]
+    fn weekdays_only() {
+        let friday = at(2017, Month::August, 18, 10, 0, 0);
+        assert_eq!(next("Mon..Fri *-*-* 09:00:00", friday), Some(at(2017, Month::August, 21, 9, 0, 0)));
+    }
+
+    #[test]
+    fn strictly_after() {
+        let midnight = at(2017, Month::August, 1, 0, 0, 0);
+        assert_eq!(next("daily", midnight), Some(at(2017, Month::August, 2, 0, 0, 0)));
+    }
+
+    #[test]
+    fn first_of_the_month() {
+        assert_eq!(next("*-*-01 00:00:00", at(2017, Month::January, 31, 12, 0, 0)), Some(at(2017, Month::February, 1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn quarterly() {
+        assert_eq!(next("quarterly", at(2017, Month::August, 19, 12, 0, 0)), Some(at(2017, Month::October, 1, 0, 0, 0)));
+        assert_eq!(next("quarterly", at(2017, Month::December, 19, 12, 0, 0)), Some(at(2018, Month::January, 1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn repetitions() {
+        assert_eq!(next("*:0/15", at(2017, Month::August, 1, 10, 7, 0)), Some(at(2017, Month::August, 1, 10, 15, 0)));
+        assert_eq!(next("*:*:30", at(2017, Month::August, 1, 10, 7, 30)), Some(at(2017, Month::August, 1, 10, 8, 30)));
+        assert_eq!(next("*-*-1/10", at(2017, Month::August, 22, 0, 0, 0)), Some(at(2017, Month::August, 31, 0, 0, 0)));
+    }
+
+    #[test]
+    fn first_saturday() {
+        assert_eq!(next("Sat *-*-1..7 18:00", at(2017, Month::August, 6, 0, 0, 0)), Some(at(2017, Month::September, 2, 18, 0, 0)));
+    }
+
+    #[test]
+    fn days_from_the_end() {
+        assert_eq!(next("*-02~03", at(2017, Month::January, 1, 0, 0, 0)), Some(at(2017, Month::February, 26, 0, 0, 0)));
+        assert_eq!(next("*-02~03", at(2016, Month::January, 1, 0, 0, 0)), Some(at(2016, Month::February, 27, 0, 0, 0)));
+        assert_eq!(next("*-*~01", at(2017, Month::April, 2, 0, 0, 0)), Some(at(2017, Month::April, 30, 0, 0, 0)));
+    }
+
+    #[test]
+    fn last_monday_in_may() {
+        let event = event("Mon *-05~07/1");
+        assert_eq!(event.next_elapse(at(2017, Month::January, 1, 0, 0, 0)), Some(at(2017, Month::May, 29, 0, 0, 0)));
+        assert_eq!(event.next_elapse(at(2017, Month::June, 1, 0, 0, 0)), Some(at(2018, Month::May, 28, 0, 0, 0)));
+    }
+
+    #[test]
+    fn leap_day() {
+        assert_eq!(next("*-02-29", at(2017, Month::January, 1, 0, 0, 0)), Some(at(2020, Month::February, 29, 0, 0, 0)));
+    }
+
+    #[test]
+    fn fixed_years() {
+        assert_eq!(next("2017,2019-*-01", at(2017, Month::December, 15, 0, 0, 0)), Some(at(2019, Month::January, 1, 0, 0, 0)));
+        assert_eq!(next("2017-*-01", at(2017, Month::December, 15, 0, 0, 0)), None);
+    }
+
+    #[test]
+    fn matching() {
+        let event = event("Mon..Fri *-*-* 09:00");
+        assert!(event.matches(at(2017, Month::August, 18, 9, 0, 0)));
+        assert!(!event.matches(at(2017, Month::August, 19, 9, 0, 0)));
+        assert!(!event.matches(at(2017, Month::August, 18, 9, 0, 1)));
+    }
+}
+
+
+/// A zone that moves its clocks forward at 01:00 on the 28th of March
+/// 2010, and back again at 02:00 on the 31st of October.
+const TEST_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Test Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1269738000, FixedTimespan {
+                offset: 3600,
+                is_dst: true,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+            (1288486800, FixedTimespan {
+                offset: 0,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_A"),
+            }),
+        ]
+    }
+};
+
+mod zoned {
+    use super::*;
+
+    fn zone() -> TimeZone {
+        TimeZone(TimeZoneSource::Static(TEST_ZONESET))
+    }
+
+    #[test]
+    fn in_gap() {
+        let now = zone().zoned_at(at(2010, Month::March, 27, 12, 0, 0).to_instant());
+        let next = event("*-*-* 01:30").next_elapse_zoned(&now).unwrap();
+        assert_eq!(next.hour(), 2);
+        assert_eq!(next.to_instant(), at(2010, Month::March, 28, 1, 30, 0).to_instant());
+    }
+
+    #[test]
+    fn in_overlap() {
+        let event = event("*-*-* 01:30");
+
+        let now = zone().zoned_at(at(2010, Month::October, 30, 12, 0, 0).to_instant());
+        let first = event.next_elapse_zoned(&now).unwrap();
+        assert_eq!(first.to_instant(), at(2010, Month::October, 31, 0, 30, 0).to_instant());
+
+        let second = event.next_elapse_zoned(&first).unwrap();
+        assert_eq!(second.to_instant(), at(2010, Month::November, 1, 1, 30, 0).to_instant());
+    }
+
+    #[test]
+    fn utc() {
+        let now = zone().zoned_at(at(2010, Month::June, 1, 12, 0, 0).to_instant());
+        let next = event("daily UTC").next_elapse_zoned(&now).unwrap();
+        assert_eq!(next.hour(), 1);
+        assert_eq!(next.to_instant(), at(2010, Month::June, 2, 0, 0, 0).to_instant());
+    }
+}
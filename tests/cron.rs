@@ -0,0 +1,278 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Instant, CronSchedule, DstPolicy};
+use datetime::recur::{GapPolicy, OverlapPolicy};
+use datetime::recur::cron::Error;
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+
+fn at(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hms(hour, minute, second).unwrap())
+}
+
+fn next(expression: &str, after: LocalDateTime) -> Option<LocalDateTime> {
+    expression.parse::<CronSchedule>().unwrap().next_local_after(after)
+}
+
+
+mod fields {
+    use super::*;
+
+    #[test]
+    fn steps() {
+        assert_eq!(next("*/15 * * * *", at(2017, Month::August, 1, 10, 7, 0)), Some(at(2017, Month::August, 1, 10, 15, 0)));
+        assert_eq!(next("*/15 * * * *", at(2017, Month::August, 1, 23, 50, 0)), Some(at(2017, Month::August, 2, 0, 0, 0)));
+    }
+
+    #[test]
+    fn strictly_after() {
+        assert_eq!(next("0 12 * * *", at(2017, Month::August, 1, 12, 0, 0)), Some(at(2017, Month::August, 2, 12, 0, 0)));
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!(next("30 * * * * *", at(2017, Month::August, 1, 10, 7, 30)), Some(at(2017, Month::August, 1, 10, 8, 30)));
+        assert_eq!(next("5/20 0 9 * * *", at(2017, Month::August, 1, 9, 0, 30)), Some(at(2017, Month::August, 1, 9, 0, 45)));
+    }
+
+    #[test]
+    fn names_and_ranges() {
+        let schedule: CronSchedule = "0 9 * jan-mar,OCT MON-FRI".parse().unwrap();
+        assert_eq!(schedule.next_local_after(at(2017, Month::March, 31, 10, 0, 0)), Some(at(2017, Month::October, 2, 9, 0, 0)));
+        assert!(!schedule.matches(at(2017, Month::October, 1, 9, 0, 0)));
+        assert!(schedule.matches(at(2017, Month::October, 2, 9, 0, 0)));
+    }
+
+    #[test]
+    fn sunday_as_seven() {
+        assert_eq!("0 0 * * 7".parse::<CronSchedule>(), "0 0 * * SUN".parse::<CronSchedule>());
+        assert_eq!("0 0 * * 5-7".parse::<CronSchedule>(), "0 0 * * 0,5,6".parse::<CronSchedule>());
+    }
+
+    #[test]
+    fn either_day_field() {
+        let schedule = "0 0 13 * FRI";
+        assert_eq!(next(schedule, at(2017, Month::August, 1, 0, 0, 0)), Some(at(2017, Month::August, 4, 0, 0, 0)));
+        assert_eq!(next(schedule, at(2017, Month::August, 11, 0, 0, 0)), Some(at(2017, Month::August, 13, 0, 0, 0)));
+    }
+
+    #[test]
+    fn both_day_fields_when_one_is_a_star() {
+        // Days 1, 11, 21, and 31, but only when they’re Fridays.
+        assert_eq!(next("0 0 */10 * FRI", at(2017, Month::August, 1, 0, 0, 0)), Some(at(2017, Month::August, 11, 0, 0, 0)));
+        assert_eq!(next("0 0 * * ?", at(2017, Month::August, 1, 0, 0, 0)), Some(at(2017, Month::August, 2, 0, 0, 0)));
+    }
+
+    #[test]
+    fn shorthands() {
+        assert_eq!("@daily".parse::<CronSchedule>(), "0 0 * * *".parse::<CronSchedule>());
+        assert_eq!("@annually".parse::<CronSchedule>(), "0 0 1 1 *".parse::<CronSchedule>());
+        assert_eq!(next("@hourly", at(2017, Month::August, 1, 10, 7, 0)), Some(at(2017, Month::August, 1, 11, 0, 0)));
+    }
+
+    #[test]
+    fn never() {
+        assert_eq!(next("0 0 30 2 *", at(2017, Month::August, 1, 0, 0, 0)), None);
+    }
+
+    #[test]
+    fn leap_day() {
+        assert_eq!(next("0 0 29 2 *", at(2017, Month::August, 1, 0, 0, 0)), Some(at(2020, Month::February, 29, 0, 0, 0)));
+    }
+
+    #[test]
+    fn previous() {
+        let schedule: CronSchedule = "*/15 9-17 * * MON-FRI".parse().unwrap();
+        assert_eq!(schedule.prev_local_before(at(2017, Month::August, 21, 9, 0, 0)), Some(at(2017, Month::August, 18, 17, 45, 0)));
+        assert_eq!(schedule.prev_local_before(at(2017, Month::August, 21, 9, 20, 0)), Some(at(2017, Month::August, 21, 9, 15, 0)));
+    }
+}
+
+
+mod special_days {
+    use super::*;
+
+    #[test]
+    fn last_day() {
+        assert_eq!(next("0 0 L * *", at(2016, Month::February, 1, 0, 0, 0)), Some(at(2016, Month::February, 29, 0, 0, 0)));
+        assert_eq!(next("0 0 L-2 * *", at(2017, Month::January, 1, 0, 0, 0)), Some(at(2017, Month::January, 29, 0, 0, 0)));
+    }
+
+    #[test]
+    fn nearest_weekday() {
+        // The 15th of January 2017 was a Sunday.
+        assert_eq!(next("0 0 15W * *", at(2017, Month::January, 1, 0, 0, 0)), Some(at(2017, Month::January, 16, 0, 0, 0)));
+
+        // The 1st of July 2017 was a Saturday, but the Friday before is in June.
+        assert_eq!(next("0 0 1W * *", at(2017, Month::June, 15, 0, 0, 0)), Some(at(2017, Month::July, 3, 0, 0, 0)));
+
+        // There’s no 31st of June.
+        assert_eq!(next("0 0 31W * *", at(2017, Month::June, 1, 0, 0, 0)), Some(at(2017, Month::July, 31, 0, 0, 0)));
+    }
+
+    #[test]
+    fn last_weekday() {
+        // The 30th of September 2017 was a Saturday.
+        assert_eq!(next("0 0 LW * *", at(2017, Month::September, 1, 0, 0, 0)), Some(at(2017, Month::September, 29, 0, 0, 0)));
+    }
+
+    #[test]
+    fn last_of_weekday() {
+        assert_eq!(next("0 0 * * 5L", at(2017, Month::August, 1, 0, 0, 0)), Some(at(2017, Month::August, 25, 0, 0, 0)));
+        assert_eq!(next("0 0 * * FRIL", at(2017, Month::August, 1, 0, 0, 0)), Some(at(2017, Month::August, 25, 0, 0, 0)));
+    }
+
+    #[test]
+    fn nth_weekday() {
+        assert_eq!(next("0 0 * * FRI#3", at(2017, Month::August, 1, 0, 0, 0)), Some(at(2017, Month::August, 18, 0, 0, 0)));
+
+        // Not every month has a fifth Monday.
+        assert_eq!(next("0 0 * * 1#5", at(2017, Month::August, 1, 0, 0, 0)), Some(at(2017, Month::October, 30, 0, 0, 0)));
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn field_count() {
+        assert_eq!("* * * *".parse::<CronSchedule>(), Err(Error::FieldCount(4)));
+        assert_eq!("* * * * * * *".parse::<CronSchedule>(), Err(Error::FieldCount(7)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!("60 * * * *".parse::<CronSchedule>(), Err(Error::InvalidField("60".into())));
+        assert!("* 24 * * *".parse::<CronSchedule>().is_err());
+        assert!("* * 0 * *".parse::<CronSchedule>().is_err());
+        assert!("* * * 13 *".parse::<CronSchedule>().is_err());
+        assert!("* * * * 8".parse::<CronSchedule>().is_err());
+        assert!("* * * * 5#6".parse::<CronSchedule>().is_err());
+        assert!("* * L-31 * *".parse::<CronSchedule>().is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        assert!("*/0 * * * *".parse::<CronSchedule>().is_err());
+        assert!("5-1 * * * *".parse::<CronSchedule>().is_err());
+        assert!("? * * * *".parse::<CronSchedule>().is_err());
+        assert!("* * * * L".parse::<CronSchedule>().is_err());
+        assert!("@fortnightly".parse::<CronSchedule>().is_err());
+    }
+}
+
+
+/// A zone that moves its clocks forward at 01:00 on the 28th of March
+/// 2010, and back again at 02:00 on the 31st of October.
+const TEST_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Test Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1269738000, FixedTimespan {
+                offset: 3600,
+                is_dst: true,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+            (1288486800, FixedTimespan {
+                offset: 0,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_A"),
+            }),
+        ]
+    }
+};
+
+mod time_zones {
+    use super::*;
+
+    fn zone() -> TimeZone {
+        TimeZone(TimeZoneSource::Static(TEST_ZONESET))
+    }
+
+    fn instants(expression: &str, from: Instant, policy: DstPolicy, count: usize) -> Vec<i64> {
+        let schedule: CronSchedule = expression.parse().unwrap();
+        let mut instant = from;
+        let mut result = Vec::new();
+
+        for _ in 0 .. count {
+            instant = schedule.next_after(instant, &zone(), policy).unwrap().to_instant();
+            result.push(instant.seconds());
+        }
+
+        result
+    }
+
+    #[test]
+    fn gap_shifted_forward() {
+        let start = at(2010, Month::March, 27, 12, 0, 0).to_instant();
+        let schedule: CronSchedule = "30 1 * * *".parse().unwrap();
+
+        // 01:30 doesn’t exist on the 28th, so it runs at 02:30 instead.
+        let run = schedule.next_after(start, &zone(), DstPolicy::once()).unwrap();
+        assert_eq!(run.to_instant(), at(2010, Month::March, 28, 1, 30, 0).to_instant());
+    }
+
+    #[test]
+    fn gap_skipped() {
+        let start = at(2010, Month::March, 27, 12, 0, 0).to_instant();
+        let schedule: CronSchedule = "30 1 * * *".parse().unwrap();
+
+        let run = schedule.next_after(start, &zone(), DstPolicy::wall_clock()).unwrap();
+        assert_eq!(run.to_instant(), at(2010, Month::March, 29, 0, 30, 0).to_instant());
+    }
+
+    #[test]
+    fn gap_shifted_into_existing_time() {
+        // 01:00 and 01:30 are shifted to 02:00 and 02:30, which are already
+        // in the schedule, so they must not run twice.
+        let start = at(2010, Month::March, 28, 0, 0, 0).to_instant();
+        let base = at(2010, Month::March, 28, 1, 0, 0).to_instant().seconds();
+        assert_eq!(instants("*/30 * * * *", start, DstPolicy::once(), 3), vec![ base - 1800, base, base + 1800 ]);
+    }
+
+    #[test]
+    fn overlap_runs_once() {
+        let start = at(2010, Month::October, 30, 12, 0, 0).to_instant();
+        let earlier = at(2010, Month::October, 31, 0, 30, 0).to_instant().seconds();
+        let tomorrow = at(2010, Month::November, 1, 1, 30, 0).to_instant().seconds();
+        assert_eq!(instants("30 1 * * *", start, DstPolicy::once(), 2), vec![ earlier, tomorrow ]);
+    }
+
+    #[test]
+    fn overlap_runs_later() {
+        let start = at(2010, Month::October, 30, 12, 0, 0).to_instant();
+        let policy = DstPolicy { gap: GapPolicy::Skip, overlap: OverlapPolicy::Later };
+        let later = at(2010, Month::October, 31, 1, 30, 0).to_instant().seconds();
+        assert_eq!(instants("30 1 * * *", start, policy, 1), vec![ later ]);
+    }
+
+    #[test]
+    fn overlap_runs_twice() {
+        let start = at(2010, Month::October, 30, 23, 50, 0).to_instant();
+        let base = at(2010, Month::October, 31, 0, 0, 0).to_instant().seconds();
+
+        assert_eq!(instants("*/30 * * * *", start, DstPolicy::wall_clock(), 5),
+                   vec![ base, base + 1800, base + 3600, base + 5400, base + 7200 ]);
+
+        assert_eq!(instants("*/30 * * * *", start, DstPolicy::once(), 3),
+                   vec![ base, base + 1800, base + 7200 ]);
+    }
+
+    #[test]
+    fn previous_in_overlap() {
+        let schedule: CronSchedule = "*/30 * * * *".parse().unwrap();
+        let before = at(2010, Month::October, 31, 2, 0, 0).to_instant();
+
+        let run = schedule.prev_before(before, &zone(), DstPolicy::wall_clock()).unwrap();
+        assert_eq!(run.to_instant(), at(2010, Month::October, 31, 1, 30, 0).to_instant());
+
+        let run = schedule.prev_before(before, &zone(), DstPolicy::once()).unwrap();
+        assert_eq!(run.to_instant(), at(2010, Month::October, 31, 0, 30, 0).to_instant());
+    }
+}
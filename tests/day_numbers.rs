@@ -0,0 +1,124 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month};
+use datetime::Month::*;
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+
+mod julian_day_numbers {
+    use super::*;
+
+    #[test]
+    fn j2000() {
+        assert_eq!(date(2000, January, 1).to_julian_day_number(), 2_451_545);
+    }
+
+    #[test]
+    fn unix_epoch() {
+        assert_eq!(date(1970, January, 1).to_julian_day_number(), 2_440_588);
+    }
+
+    #[test]
+    fn day_zero() {
+        // Day zero is the 1st of January 4713 BC in the Julian calendar,
+        // which is the year -4713 in the proleptic Gregorian calendar.
+        assert_eq!(LocalDate::from_julian_day_number(0), date(-4713, November, 24));
+    }
+
+    #[test]
+    fn gregorian_reform() {
+        assert_eq!(date(1582, October, 15).to_julian_day_number(), 2_299_161);
+    }
+}
+
+
+mod modified_julian_days {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(date(1858, November, 17).to_modified_julian_day(), 0);
+        assert_eq!(LocalDate::from_modified_julian_day(-1), date(1858, November, 16));
+    }
+
+    #[test]
+    fn recent() {
+        assert_eq!(date(2017, August, 21).to_modified_julian_day(), 57_986);
+        assert_eq!(LocalDate::from_modified_julian_day(51_544), date(2000, January, 1));
+    }
+}
+
+
+mod rata_die {
+    use super::*;
+
+    #[test]
+    fn first_day() {
+        assert_eq!(date(1, January, 1).to_rata_die(), 1);
+        assert_eq!(date(0, December, 31).to_rata_die(), 0);
+    }
+
+    #[test]
+    fn recent() {
+        assert_eq!(date(2000, January, 1).to_rata_die(), 730_120);
+        assert_eq!(LocalDate::from_rata_die(719_163), date(1970, January, 1));
+    }
+}
+
+
+mod lilian_day_numbers {
+    use super::*;
+
+    #[test]
+    fn first_day() {
+        assert_eq!(date(1582, October, 15).to_lilian_day_number(), 1);
+        assert_eq!(LocalDate::from_lilian_day_number(0), date(1582, October, 14));
+    }
+
+    #[test]
+    fn unix_epoch() {
+        assert_eq!(date(1970, January, 1).to_lilian_day_number(), 141_428);
+    }
+}
+
+
+#[test]
+fn round_trips() {
+    let mut day = date(1500, January, 1);
+    while day < date(2500, January, 1) {
+        assert_eq!(LocalDate::from_julian_day_number(day.to_julian_day_number()), day);
+        assert_eq!(LocalDate::from_modified_julian_day(day.to_modified_julian_day()), day);
+        assert_eq!(LocalDate::from_rata_die(day.to_rata_die()), day);
+        assert_eq!(LocalDate::from_lilian_day_number(day.to_lilian_day_number()), day);
+        day = day.add_days(97);
+    }
+}
+
+
+mod julian_dates {
+    use super::*;
+
+    #[test]
+    fn midnight_is_half_a_day() {
+        let datetime = LocalDateTime::new(date(2000, January, 1), LocalTime::midnight());
+        assert_eq!(datetime.to_julian_date(), 2_451_544.5);
+        assert_eq!(datetime.to_modified_julian_date(), 51_544.0);
+    }
+
+    #[test]
+    fn from_modified_julian_date() {
+        let datetime = LocalDateTime::new(date(2017, August, 21), LocalTime::hm(6, 0).unwrap());
+        assert_eq!(LocalDateTime::at_modified_julian_date(57_986.25), Some(datetime));
+        assert_eq!(LocalDateTime::at_modified_julian_date(f64::NAN), None);
+    }
+
+    #[test]
+    fn agrees_with_day_numbers() {
+        let day = date(2017, August, 21);
+        let noon = LocalDateTime::new(day, LocalTime::hm(12, 0).unwrap());
+        assert_eq!(noon.to_julian_date(), day.to_julian_day_number() as f64);
+    }
+}
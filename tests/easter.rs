@@ -0,0 +1,118 @@
+extern crate datetime;
+use datetime::{Year, LocalDate, Month, Weekday, DatePiece, Computus, MovableFeast, BusinessCalendar, Holiday};
+use datetime::Month::*;
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+
+mod gregorian {
+    use super::*;
+
+    #[test]
+    fn known_dates() {
+        let dates = [
+            date(1818, March, 22), date(1943, April, 25), date(2000, April, 23), date(2008, March, 23),
+            date(2011, April, 24), date(2017, April, 16), date(2018, April, 1),  date(2019, April, 21),
+            date(2024, March, 31), date(2025, April, 20), date(2038, April, 25), date(2285, March, 22),
+        ];
+
+        for expected in dates.iter() {
+            assert_eq!(Year(expected.year()).easter(Computus::Gregorian), *expected);
+        }
+    }
+
+    #[test]
+    fn always_a_spring_sunday() {
+        for year in -1000 .. 5000 {
+            let easter = Year(year).easter(Computus::Gregorian);
+            assert_eq!(easter.weekday(), Weekday::Sunday, "{}", year);
+            assert!(easter >= date(year, March, 22) && easter <= date(year, April, 25), "{}", year);
+        }
+    }
+}
+
+
+mod julian {
+    use super::*;
+
+    #[test]
+    fn known_dates() {
+        let dates = [
+            date(2017, April, 16), date(2018, April, 8),  date(2019, April, 28), date(2020, April, 19),
+            date(2021, May, 2),    date(2022, April, 24), date(2023, April, 16), date(2024, May, 5),
+            date(2025, April, 20),
+        ];
+
+        for expected in dates.iter() {
+            assert_eq!(Year(expected.year()).easter(Computus::Julian), *expected);
+        }
+    }
+
+    #[test]
+    fn always_a_sunday_no_earlier_than_western() {
+        for year in 1583 .. 4000 {
+            let easter = Year(year).easter(Computus::Julian);
+            assert_eq!(easter.weekday(), Weekday::Sunday, "{}", year);
+            assert!(easter >= Year(year).easter(Computus::Gregorian), "{}", year);
+        }
+    }
+
+    #[test]
+    fn drift_after_2100() {
+        // The calendars drift apart by another day from March 2100, so the
+        // 18th of April in the Julian calendar is 14 days later, not 13.
+        assert_eq!(Year(2100).easter(Computus::Julian), date(2100, May, 2));
+    }
+}
+
+
+mod feasts {
+    use super::*;
+
+    #[test]
+    fn western_2017() {
+        let feast = |f| Year(2017).feast(f, Computus::Gregorian);
+        assert_eq!(feast(MovableFeast::AshWednesday), date(2017, March, 1));
+        assert_eq!(feast(MovableFeast::PalmSunday), date(2017, April, 9));
+        assert_eq!(feast(MovableFeast::GoodFriday), date(2017, April, 14));
+        assert_eq!(feast(MovableFeast::EasterMonday), date(2017, April, 17));
+        assert_eq!(feast(MovableFeast::Ascension), date(2017, May, 25));
+        assert_eq!(feast(MovableFeast::Pentecost), date(2017, June, 4));
+        assert_eq!(feast(MovableFeast::WhitMonday), date(2017, June, 5));
+        assert_eq!(feast(MovableFeast::TrinitySunday), date(2017, June, 11));
+        assert_eq!(feast(MovableFeast::CorpusChristi), date(2017, June, 15));
+    }
+
+    #[test]
+    fn orthodox_2021() {
+        let feast = |f| Year(2021).feast(f, Computus::Julian);
+        assert_eq!(feast(MovableFeast::CleanMonday), date(2021, March, 15));
+        assert_eq!(feast(MovableFeast::GoodFriday), date(2021, April, 30));
+        assert_eq!(feast(MovableFeast::Pentecost), date(2021, June, 20));
+    }
+
+    #[test]
+    fn weekdays() {
+        for year in 1900 .. 2100 {
+            let feast = |f| Year(year).feast(f, Computus::Gregorian).weekday();
+            assert_eq!(feast(MovableFeast::AshWednesday), Weekday::Wednesday);
+            assert_eq!(feast(MovableFeast::GoodFriday), Weekday::Friday);
+            assert_eq!(feast(MovableFeast::Ascension), Weekday::Thursday);
+            assert_eq!(feast(MovableFeast::Pentecost), Weekday::Sunday);
+            assert_eq!(feast(MovableFeast::CorpusChristi), Weekday::Thursday);
+        }
+    }
+
+    #[test]
+    fn as_holidays() {
+        let mut calendar = BusinessCalendar::new();
+        calendar.add_holiday(Holiday::feast(MovableFeast::GoodFriday, Computus::Gregorian));
+        calendar.add_holiday(Holiday::feast(MovableFeast::EasterMonday, Computus::Gregorian));
+
+        assert_eq!(calendar.holidays_in(2017), vec![ date(2017, April, 14), date(2017, April, 17) ]);
+        assert_eq!(calendar.next_business_day(date(2017, April, 13)), date(2017, April, 18));
+    }
+}
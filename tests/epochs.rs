@@ -0,0 +1,213 @@
+extern crate datetime;
+use datetime::Instant;
+
+
+mod unix_units {
+    use super::*;
+
+    #[test]
+    fn millis() {
+        assert_eq!(Instant::at_unix_millis(1_234_567_890_123), Instant::at_ms(1_234_567_890, 123))
+    }
+
+    #[test]
+    fn micros_round_down() {
+        assert_eq!(Instant::at_unix_micros(-1), Instant::at_ms(-1, 999))
+    }
+
+    #[test]
+    fn nanos() {
+        assert_eq!(Instant::at_unix_nanos(1_500_999_999), Instant::at_ms(1, 500))
+    }
+
+    #[test]
+    fn accessors() {
+        let instant = Instant::at_ms(-2, 250);
+        assert_eq!(instant.unix_millis(), Some(-1_750));
+        assert_eq!(instant.unix_micros(), Some(-1_750_000));
+        assert_eq!(instant.unix_nanos(), Some(-1_750_000_000));
+    }
+
+    #[test]
+    fn nanos_overflow() {
+        assert_eq!(Instant::at(10_000_000_000).unix_nanos(), None)
+    }
+}
+
+
+mod ntp {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(Instant::at_ntp(0), Instant::at(-2_208_988_800))
+    }
+
+    #[test]
+    fn round_trip() {
+        for ms in 0 .. 1000 {
+            let instant = Instant::at_ms(1_577_836_800, ms);
+            assert_eq!(Instant::at_ntp(instant.to_ntp().unwrap()), instant);
+        }
+    }
+
+    #[test]
+    fn before_era() {
+        assert_eq!(Instant::at(-2_208_988_801).to_ntp(), None)
+    }
+
+    #[test]
+    fn after_era() {
+        assert_eq!(Instant::at(2_085_978_496).to_ntp(), None)
+    }
+}
+
+
+mod filetime {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(Instant::at_filetime(0), Instant::at(-11_644_473_600))
+    }
+
+    #[test]
+    fn recently() {
+        assert_eq!(Instant::at_filetime(132_223_104_001_234_567), Instant::at_ms(1_577_836_800, 123))
+    }
+
+    #[test]
+    fn to_filetime() {
+        assert_eq!(Instant::at_ms(1_577_836_800, 123).to_filetime(), Some(132_223_104_001_230_000))
+    }
+
+    #[test]
+    fn before_epoch() {
+        assert_eq!(Instant::at(-11_644_473_601).to_filetime(), None)
+    }
+}
+
+
+mod dotnet {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(Instant::at_dotnet_ticks(0), Instant::at(-62_135_596_800))
+    }
+
+    #[test]
+    fn round_trip() {
+        let instant = Instant::at_ms(1_577_836_800, 456);
+        assert_eq!(instant.to_dotnet_ticks(), Some(637_134_336_004_560_000));
+        assert_eq!(Instant::at_dotnet_ticks(637_134_336_004_560_000), instant);
+    }
+}
+
+
+mod cocoa {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(Instant::at_cocoa(0.0), Some(Instant::at(978_307_200)))
+    }
+
+    #[test]
+    fn round_trip() {
+        let instant = Instant::at_ms(1_577_836_800, 500);
+        assert_eq!(instant.to_cocoa(), 599_529_600.5);
+        assert_eq!(Instant::at_cocoa(599_529_600.5), Some(instant));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(Instant::at_cocoa(f64::NAN), None);
+        assert_eq!(Instant::at_cocoa(f64::INFINITY), None);
+        assert_eq!(Instant::at_cocoa(-1e300), None);
+        assert_eq!(Instant::at_cocoa(9.3e15), None);
+    }
+
+    #[test]
+    fn far_from_epoch() {
+        assert_eq!(Instant::at_cocoa(-978_307_200.0 * 1000.0), Some(Instant::at(978_307_200 - 978_307_200_000)));
+        assert_eq!(Instant::at_ms(i64::MIN, 0).to_cocoa(), i64::MIN as f64 - 978_307_200.0);
+    }
+}
+
+
+mod gps {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(Instant::at_seconds_since_gps_epoch(0), Some(Instant::at(315_964_800)))
+    }
+
+    #[test]
+    fn round_trip() {
+        let instant = Instant::at_ms(1_577_836_800, 500);
+        assert_eq!(instant.seconds_since_gps_epoch(), Some((1_261_872_000, 500)));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(Instant::at_seconds_since_gps_epoch(i64::MAX), None);
+        assert_eq!(Instant::at(i64::MIN).seconds_since_gps_epoch(), None);
+        assert_eq!(Instant::at(i64::MAX).seconds_since_gps_epoch(), Some((i64::MAX - 315_964_800, 0)));
+    }
+}
+
+
+mod julian_dates {
+    use super::*;
+
+    #[test]
+    fn unix_epoch() {
+        assert_eq!(Instant::at_epoch().to_julian_date(), 2_440_587.5);
+        assert_eq!(Instant::at_epoch().to_modified_julian_date(), 40_587.0);
+    }
+
+    #[test]
+    fn from_julian_date() {
+        assert_eq!(Instant::at_julian_date(2_440_587.5), Some(Instant::at_epoch()));
+        assert_eq!(Instant::at_julian_date(2_440_587.0), Some(Instant::at(-43_200)));
+    }
+
+    #[test]
+    fn fractional() {
+        assert_eq!(Instant::at_julian_date(2_451_545.125), Some(Instant::at(946_728_000 + 10_800)));
+        assert_eq!(Instant::at_ms(946_728_000, 500).to_julian_date(), 2_451_545.0 + 0.5 / 86_400.0);
+    }
+
+    #[test]
+    fn modified() {
+        assert_eq!(Instant::at_modified_julian_date(51_544.5), Some(Instant::at(946_728_000)));
+        assert_eq!(Instant::at(946_728_000).to_modified_julian_date(), 51_544.5);
+    }
+
+    #[test]
+    fn not_finite_or_too_large() {
+        assert_eq!(Instant::at_julian_date(f64::NAN), None);
+        assert_eq!(Instant::at_julian_date(f64::INFINITY), None);
+        assert_eq!(Instant::at_modified_julian_date(f64::NEG_INFINITY), None);
+        assert_eq!(Instant::at_modified_julian_date(1e300), None);
+    }
+
+    #[test]
+    fn round_to_nearest() {
+        assert_eq!(Instant::at_modified_julian_date(-0.1 / 86_400_000.0), Some(Instant::at(-3_506_716_800)));
+        assert_eq!(Instant::at_modified_julian_date(-0.6 / 86_400_000.0), Some(Instant::at_ms(-3_506_716_800 - 1, 999)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut milliseconds = -10_000_000_000_000_i64;
+        while milliseconds < 10_000_000_000_000 {
+            let instant = Instant::at_unix_millis(milliseconds);
+            assert_eq!(Instant::at_julian_date(instant.to_julian_date()), Some(instant), "{:?}", instant);
+            assert_eq!(Instant::at_modified_julian_date(instant.to_modified_julian_date()), Some(instant), "{:?}", instant);
+            milliseconds += 999_999_937;
+        }
+    }
+}
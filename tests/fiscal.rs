@@ -0,0 +1,168 @@
+extern crate datetime;
+use datetime::{LocalDate, Month, Weekday, Year, Quarter, DatePiece};
+use datetime::{FiscalCalendar, FiscalPeriod, FiscalYear, FiscalYearLabel};
+use datetime::{RetailCalendar, RetailPattern, RetailYearEnd};
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+fn period(year: i64, period: i8) -> FiscalPeriod {
+    FiscalPeriod::new(year, period).unwrap()
+}
+
+
+mod quarters {
+    use super::*;
+
+    #[test]
+    fn month_quarters() {
+        assert_eq!(Month::March.quarter(), Quarter::Q1);
+        assert_eq!(Month::April.quarter(), Quarter::Q2);
+        assert_eq!(Month::December.quarter(), Quarter::Q4);
+    }
+
+    #[test]
+    fn date_quarter() {
+        assert_eq!(date(2017, Month::September, 30).quarter(), Quarter::Q3);
+    }
+
+    #[test]
+    fn first_and_last_days() {
+        let q = Year(2016).quarter(Quarter::Q1);
+        assert_eq!(q.first_day(), date(2016, Month::January, 1));
+        assert_eq!(q.last_day(), date(2016, Month::March, 31));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let q = Year(2016).quarter(Quarter::Q3);
+        assert_eq!(q + 2, Year(2017).quarter(Quarter::Q1));
+        assert_eq!(q - 3, Year(2015).quarter(Quarter::Q4));
+    }
+}
+
+
+mod fiscal_years {
+    use super::*;
+
+    #[test]
+    fn calendar_year() {
+        let fy = FiscalYear::new(Month::January, FiscalYearLabel::EndYear);
+        let day = date(2017, Month::May, 5);
+        assert_eq!(fy.period_of(day), period(2017, 5));
+        assert_eq!(fy.quarter_of(day), Quarter::Q2);
+    }
+
+    #[test]
+    fn us_federal() {
+        let fy = FiscalYear::new(Month::October, FiscalYearLabel::EndYear);
+        assert_eq!(fy.year_of(date(2023, Month::September, 30)), 2023);
+        assert_eq!(fy.year_of(date(2023, Month::October, 1)), 2024);
+        assert_eq!(fy.quarter_start(2024, Quarter::Q2), date(2024, Month::January, 1));
+        assert_eq!(fy.quarter_end(2024, Quarter::Q2), date(2024, Month::March, 31));
+        assert_eq!(fy.year_end(2024), date(2024, Month::September, 30));
+    }
+
+    #[test]
+    fn named_by_start_year() {
+        let fy = FiscalYear::new(Month::April, FiscalYearLabel::StartYear);
+        assert_eq!(fy.period_of(date(2018, Month::March, 31)), period(2017, 12));
+        assert_eq!(fy.period_of(date(2018, Month::April, 1)), period(2018, 1));
+    }
+
+    #[test]
+    fn invalid_periods() {
+        assert!(FiscalPeriod::new(2023, 0).is_err());
+        assert!(FiscalPeriod::new(2023, 13).is_err());
+        assert!(FiscalPeriod::new(2023, -1).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for &start in &[ Month::January, Month::February, Month::July, Month::December ] {
+            for &label in &[ FiscalYearLabel::StartYear, FiscalYearLabel::EndYear ] {
+                let fy = FiscalYear::new(start, label);
+                let first = date(2015, Month::January, 1);
+                for days in 0 .. 365 * 3 {
+                    let day = first.add_days(days);
+                    let period = fy.period_of(day);
+                    assert!(fy.period_start(period) <= day && day <= fy.period_end(period), "{:?}", day);
+                }
+            }
+        }
+    }
+}
+
+
+mod retail {
+    use super::*;
+
+    fn nrf() -> RetailCalendar {
+        RetailCalendar::new(RetailPattern::FourFiveFour,
+                            RetailYearEnd::Nearest(Weekday::Saturday, Month::January),
+                            FiscalYearLabel::StartYear)
+    }
+
+    fn apple() -> RetailCalendar {
+        RetailCalendar::new(RetailPattern::FourFourFive,
+                            RetailYearEnd::Last(Weekday::Saturday, Month::September),
+                            FiscalYearLabel::EndYear)
+    }
+
+    #[test]
+    fn nrf_years() {
+        assert_eq!(nrf().year_start(2022), date(2022, Month::January, 30));
+        assert_eq!(nrf().year_end(2022), date(2023, Month::January, 28));
+        assert_eq!(nrf().weeks_in_year(2022), 52);
+        assert_eq!(nrf().weeks_in_year(2023), 53);
+    }
+
+    #[test]
+    fn nrf_periods() {
+        let cal = nrf();
+        assert_eq!(cal.period_end(period(2023, 1)), date(2023, Month::February, 25));
+        assert_eq!(cal.period_start(period(2023, 2)), date(2023, Month::February, 26));
+        assert_eq!(cal.period_start(period(2023, 3)), date(2023, Month::April, 2));
+        assert_eq!(cal.period_of(date(2024, Month::February, 3)), period(2023, 12));
+        assert_eq!(cal.period_start(period(2023, 12)), date(2023, Month::December, 31));
+    }
+
+    #[test]
+    fn last_saturday() {
+        assert_eq!(apple().year_start(2023), date(2022, Month::September, 25));
+        assert_eq!(apple().year_end(2023), date(2023, Month::September, 30));
+        assert_eq!(apple().year_end(2024), date(2024, Month::September, 28));
+        assert_eq!(apple().weeks_in_year(2023), 53);
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(apple().week_of(date(2022, Month::September, 25)), 1);
+        assert_eq!(apple().week_of(date(2023, Month::September, 30)), 53);
+    }
+
+    #[test]
+    fn patterns() {
+        let cal = RetailCalendar::new(RetailPattern::FiveFourFour,
+                                      RetailYearEnd::Last(Weekday::Saturday, Month::December),
+                                      FiscalYearLabel::EndYear);
+        let start = cal.year_start(2017);
+        assert_eq!(cal.period_start(period(2017, 2)), start.add_weeks(5));
+        assert_eq!(cal.quarter_start(2017, Quarter::Q2), start.add_weeks(13));
+    }
+
+    #[test]
+    fn round_trip() {
+        for cal in &[ nrf(), apple() ] {
+            let first = date(2010, Month::January, 1);
+            for days in 0 .. 365 * 10 {
+                let day = first.add_days(days);
+                let period = cal.period_of(day);
+                assert!(cal.period_start(period) <= day && day <= cal.period_end(period), "{:?}", day);
+                assert_eq!(cal.year_start(period.year()).weekday(), Weekday::Sunday);
+            }
+        }
+    }
+}
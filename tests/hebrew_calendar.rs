@@ -0,0 +1,194 @@
+extern crate datetime;
+use datetime::{HebrewDate, HebrewMonth, JulianDate, LocalDate, Month, Weekday};
+use datetime::HebrewMonth::*;
+use datetime::Month::*;
+
+
+fn gregorian(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+fn hebrew(year: i64, month: HebrewMonth, day: i8) -> HebrewDate {
+    HebrewDate::ymd(year, month, day).unwrap()
+}
+
+
+mod conversions {
+    use super::*;
+
+    #[test]
+    fn rosh_hashanah() {
+        assert_eq!(hebrew(5783, Tishrei, 1).to_local_date(), gregorian(2022, September, 26));
+        assert_eq!(hebrew(5784, Tishrei, 1).to_local_date(), gregorian(2023, September, 16));
+        assert_eq!(hebrew(5785, Tishrei, 1).to_local_date(), gregorian(2024, October, 3));
+    }
+
+    #[test]
+    fn yom_kippur() {
+        assert_eq!(hebrew(5785, Tishrei, 10).to_local_date(), gregorian(2024, October, 12));
+    }
+
+    #[test]
+    fn hanukkah() {
+        assert_eq!(hebrew(5785, Kislev, 25).to_local_date(), gregorian(2024, December, 26));
+    }
+
+    #[test]
+    fn purim_in_a_leap_year() {
+        assert_eq!(hebrew(5784, AdarI, 14).to_local_date(), gregorian(2024, February, 23));
+        assert_eq!(hebrew(5784, AdarII, 14).to_local_date(), gregorian(2024, March, 24));
+    }
+
+    #[test]
+    fn purim_in_a_common_year() {
+        assert_eq!(hebrew(5785, Adar, 14).to_local_date(), gregorian(2025, March, 14));
+    }
+
+    #[test]
+    fn passover() {
+        assert_eq!(hebrew(5784, Nisan, 15).to_local_date(), gregorian(2024, April, 23));
+        assert_eq!(HebrewDate::from_local_date(gregorian(2024, April, 23)), hebrew(5784, Nisan, 15));
+    }
+
+    #[test]
+    fn epoch() {
+        let julian = JulianDate::ymd(-3760, October, 7).unwrap();
+        assert_eq!(hebrew(1, Tishrei, 1).to_local_date(), julian.to_local_date());
+        assert_eq!(hebrew(1, Tishrei, 1).weekday(), Weekday::Monday);
+    }
+
+    #[test]
+    fn end_of_year() {
+        assert_eq!(HebrewDate::from(gregorian(2024, October, 2)), hebrew(5784, Elul, 29));
+        assert_eq!(LocalDate::from(hebrew(5784, Elul, 29)).add_days(1), gregorian(2024, October, 3));
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut date = gregorian(-4000, January, 1);
+        while date < gregorian(3000, January, 1) {
+            assert_eq!(HebrewDate::from_local_date(date).to_local_date(), date);
+            date = date.add_days(29);
+        }
+    }
+
+    #[test]
+    fn consecutive_days() {
+        let mut date = gregorian(2020, January, 1);
+        let mut previous = HebrewDate::from_local_date(date);
+        while date < gregorian(2040, January, 1) {
+            date = date.add_days(1);
+            let next = HebrewDate::from_local_date(date);
+            assert!(next > previous);
+            previous = next;
+        }
+    }
+}
+
+
+mod years {
+    use super::*;
+
+    #[test]
+    fn leap_years() {
+        let leap_years: Vec<i64> = (5777 ..= 5795).filter(|&y| HebrewDate::is_leap_year(y)).collect();
+        assert_eq!(leap_years, vec![ 5779, 5782, 5784, 5787, 5790, 5793, 5795 ]);
+    }
+
+    #[test]
+    fn year_lengths() {
+        assert_eq!(HebrewDate::days_in_year(5783), 355);
+        assert_eq!(HebrewDate::days_in_year(5784), 383);
+        assert_eq!(HebrewDate::days_in_year(5785), 355);
+
+        for year in 1 .. 10_000 {
+            let days = HebrewDate::days_in_year(year);
+            if HebrewDate::is_leap_year(year) {
+                assert!((383 ..= 385).contains(&days), "year {} has {} days", year, days);
+            }
+            else {
+                assert!((353 ..= 355).contains(&days), "year {} has {} days", year, days);
+            }
+        }
+    }
+
+    #[test]
+    fn rosh_hashanah_weekdays() {
+        for year in 1 .. 10_000 {
+            let weekday = hebrew(year, Tishrei, 1).weekday();
+            assert!(weekday != Weekday::Sunday && weekday != Weekday::Wednesday && weekday != Weekday::Friday,
+                    "year {} starts on a {:?}", year, weekday);
+        }
+    }
+
+    #[test]
+    fn month_lengths_add_up() {
+        for year in 5700 .. 5900 {
+            let total: i64 = HebrewMonth::months_of_year(year).iter()
+                .map(|month| i64::from(month.days_in_month(year).unwrap()))
+                .sum();
+            assert_eq!(total, HebrewDate::days_in_year(year));
+        }
+    }
+
+    #[test]
+    fn variable_months() {
+        // 5784 was a deficient year, with both months short.
+        assert_eq!(Cheshvan.days_in_month(5784), Some(29));
+        assert_eq!(Kislev.days_in_month(5784), Some(29));
+
+        // 5785 was a complete year, with both months long.
+        assert_eq!(Cheshvan.days_in_month(5785), Some(30));
+        assert_eq!(Kislev.days_in_month(5785), Some(30));
+    }
+}
+
+
+mod validation {
+    use super::*;
+
+    #[test]
+    fn adar_in_a_leap_year() {
+        assert!(HebrewDate::ymd(5784, Adar, 1).is_err());
+        assert_eq!(Adar.days_in_month(5784), None);
+    }
+
+    #[test]
+    fn adar_i_in_a_common_year() {
+        assert!(HebrewDate::ymd(5785, AdarI, 1).is_err());
+        assert!(HebrewDate::ymd(5785, AdarII, 1).is_err());
+    }
+
+    #[test]
+    fn day_out_of_range() {
+        assert!(HebrewDate::ymd(5785, Tevet, 30).is_err());
+        assert!(HebrewDate::ymd(5784, Cheshvan, 30).is_err());
+        assert!(HebrewDate::ymd(5785, Cheshvan, 30).is_ok());
+        assert!(HebrewDate::ymd(5785, Nisan, 0).is_err());
+    }
+}
+
+
+mod names {
+    use super::*;
+
+    #[test]
+    fn month_names() {
+        assert_eq!(Cheshvan.name(), "Cheshvan");
+        assert_eq!(AdarI.to_string(), "Adar I");
+        assert_eq!(AdarII.to_string(), "Adar II");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(hebrew(5785, Tishrei, 1).to_string(), "1 Tishrei 5785");
+        assert_eq!(format!("{:?}", hebrew(5784, AdarII, 14)), "HebrewDate(14 Adar II 5784)");
+    }
+
+    #[test]
+    fn months_of_year() {
+        let names: Vec<&str> = HebrewMonth::months_of_year(5784).iter().map(|m| m.name()).collect();
+        assert_eq!(names, vec![ "Tishrei", "Cheshvan", "Kislev", "Tevet", "Shevat", "Adar I", "Adar II",
+                                "Nisan", "Iyar", "Sivan", "Tammuz", "Av", "Elul" ]);
+    }
+}
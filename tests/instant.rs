@@ -23,3 +23,156 @@ fn sanity() {
     // If this fails then you have gone back in time, or something?
     assert!(Instant::now().seconds() != 0)
 }
+
+#[test]
+fn display() {
+    assert_eq!(Instant::at_ms(1234567890, 5).to_string(), "2009-02-13T23:31:30.005Z")
+}
+
+#[test]
+fn display_before_epoch() {
+    assert_eq!(Instant::at_ms(0, -1).to_string(), "1969-12-31T23:59:59.999Z")
+}
+
+#[test]
+fn display_expanded_years() {
+    assert_eq!(Instant::at(253_402_300_800).to_string(), "+10000-01-01T00:00:00.000Z");
+    assert_eq!(Instant::at(-62_167_219_201).to_string(), "-0001-12-31T23:59:59.000Z");
+    assert_eq!(Instant::at(-62_167_219_200).to_string(), "0000-01-01T00:00:00.000Z");
+}
+
+
+mod arithmetic {
+    use super::*;
+    use datetime::Duration;
+
+    #[test]
+    fn carrying() {
+        assert_eq!(Instant::at_ms(1, 600) + Duration::of_ms(0, 600), Instant::at_ms(2, 200))
+    }
+
+    #[test]
+    fn borrowing() {
+        assert_eq!(Instant::at_ms(1, 200) - Duration::of_ms(0, 600), Instant::at_ms(0, 600))
+    }
+
+    #[test]
+    fn borrowing_before_epoch() {
+        assert_eq!(Instant::at_epoch() - Duration::of_ms(0, 1), Instant::at_ms(-1, 999))
+    }
+
+    #[test]
+    fn difference() {
+        assert_eq!(Instant::at_ms(5, 100) - Instant::at_ms(3, 900), Duration::of_ms(1, 200))
+    }
+
+    #[test]
+    fn negative_difference() {
+        assert_eq!(Instant::at_ms(3, 900) - Instant::at_ms(5, 100), Duration::of_ms(-2, 800))
+    }
+
+    #[test]
+    fn checked_overflow() {
+        assert_eq!(Instant::at_ms(i64::MAX, 999).checked_add(Duration::of_ms(0, 1)), None);
+        assert_eq!(Instant::at_ms(i64::MIN, 0).checked_sub(Duration::of_ms(0, 1)), None);
+    }
+
+    #[test]
+    fn checked_difference() {
+        assert_eq!(Instant::at_ms(i64::MAX, 0).checked_duration_since(Instant::at_ms(0, 1)), Some(Duration::of_ms(i64::MAX - 1, 999)));
+        assert_eq!(Instant::at_ms(i64::MAX, 0).checked_duration_since(Instant::at_ms(-1, 0)), None);
+        assert_eq!(Instant::at_ms(i64::MIN, 500).checked_duration_since(Instant::at_ms(0, 600)), None);
+        assert_eq!(Instant::at_ms(i64::MIN, 500).checked_duration_since(Instant::at_ms(i64::MIN, 0)), Some(Duration::of_ms(0, 500)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when subtracting instants")]
+    fn difference_overflow() {
+        let _ = Instant::at(i64::MAX) - Instant::at(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when carrying milliseconds into seconds")]
+    fn carrying_overflow() {
+        let _ = Instant::at_ms(i64::MAX, 1000);
+    }
+
+    #[test]
+    fn carrying_at_the_limits() {
+        assert_eq!(Instant::at_ms(i64::MAX, 999).seconds(), i64::MAX);
+        assert_eq!(Instant::at_ms(i64::MIN + 1, -1), Instant::at_ms(i64::MIN, 999));
+    }
+}
+
+
+// These check that the invariants hold for a spread of values either side
+// of the epoch, rather than for a few hand-picked examples.
+mod properties {
+    use super::*;
+    use datetime::Duration;
+
+    fn instants() -> Vec<Instant> {
+        let mut instants = Vec::new();
+        for &s in &[ -86_401, -3_601, -2, -1, 0, 1, 2, 59, 3_600, 1_234_567_890 ] {
+            for &ms in &[ -1_500, -999, -1, 0, 1, 499, 500, 999, 1_000, 1_999 ] {
+                instants.push(Instant::at_ms(s, ms));
+            }
+        }
+        instants
+    }
+
+    fn durations() -> Vec<Duration> {
+        let mut durations = Vec::new();
+        for &s in &[ -90_000, -61, -1, 0, 1, 59, 3_600 ] {
+            for &ms in &[ 0, 1, 250, 999 ] {
+                durations.push(Duration::of_ms(s, ms));
+            }
+        }
+        durations
+    }
+
+    #[test]
+    fn milliseconds_are_normalised() {
+        for instant in instants() {
+            assert!(instant.milliseconds() >= 0 && instant.milliseconds() <= 999, "{:?}", instant);
+        }
+    }
+
+    #[test]
+    fn add_then_subtract() {
+        for instant in instants() {
+            for duration in durations() {
+                assert_eq!(instant + duration - duration, instant);
+            }
+        }
+    }
+
+    #[test]
+    fn add_then_difference() {
+        for instant in instants() {
+            for duration in durations() {
+                assert_eq!((instant + duration) - instant, duration);
+                assert_eq!((instant + duration).duration_since(instant), duration);
+            }
+        }
+    }
+
+    #[test]
+    fn difference_then_add() {
+        for a in instants() {
+            for b in instants() {
+                assert_eq!(b + (a - b), a);
+            }
+        }
+    }
+
+    #[test]
+    fn ordering_matches_difference() {
+        for a in instants() {
+            for b in instants() {
+                let (seconds, _) = (a - b).lengths();
+                assert_eq!(a < b, seconds < 0);
+            }
+        }
+    }
+}
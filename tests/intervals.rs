@@ -0,0 +1,191 @@
+extern crate datetime;
+use datetime::{Interval, IntervalSet, Instant, LocalDate, LocalDateTime, LocalTime, Month, Duration, DateRange};
+
+
+fn at(start: i64, end: i64) -> Interval<Instant> {
+    Interval::new(Instant::at(start), Instant::at(end)).unwrap()
+}
+
+fn intervals(set: &IntervalSet<Instant>) -> Vec<Interval<Instant>> {
+    set.iter().cloned().collect()
+}
+
+
+mod interval {
+    use super::*;
+
+    #[test]
+    fn backwards() {
+        assert!(Interval::new(Instant::at(10), Instant::at(5)).is_err());
+    }
+
+    #[test]
+    fn empty() {
+        let empty = at(5, 5);
+        assert!(empty.is_empty());
+        assert!(!empty.contains(Instant::at(5)));
+        assert!(!empty.overlaps(&at(0, 10)));
+    }
+
+    #[test]
+    fn contains_start_but_not_end() {
+        let interval = at(10, 20);
+        assert!(!interval.contains(Instant::at(9)));
+        assert!(interval.contains(Instant::at(10)));
+        assert!(interval.contains(Instant::at(19)));
+        assert!(!interval.contains(Instant::at(20)));
+    }
+
+    #[test]
+    fn encloses() {
+        assert!(at(10, 20).encloses(&at(10, 20)));
+        assert!(at(10, 20).encloses(&at(12, 15)));
+        assert!(!at(10, 20).encloses(&at(5, 15)));
+    }
+
+    #[test]
+    fn touching_intervals_do_not_overlap() {
+        assert!(!at(10, 20).overlaps(&at(20, 30)));
+        assert_eq!(at(10, 20).intersection(&at(20, 30)), None);
+        assert_eq!(at(10, 20).gap(&at(20, 30)), None);
+        assert_eq!(at(10, 20).union(&at(20, 30)), Some(at(10, 30)));
+    }
+
+    #[test]
+    fn overlapping() {
+        assert!(at(10, 20).overlaps(&at(15, 30)));
+        assert_eq!(at(10, 20).intersection(&at(15, 30)), Some(at(15, 20)));
+        assert_eq!(at(15, 30).union(&at(10, 20)), Some(at(10, 30)));
+    }
+
+    #[test]
+    fn gap() {
+        assert_eq!(at(10, 20).gap(&at(25, 30)), Some(at(20, 25)));
+        assert_eq!(at(25, 30).gap(&at(10, 20)), Some(at(20, 25)));
+        assert_eq!(at(10, 20).union(&at(25, 30)), None);
+        assert_eq!(at(10, 20).span(&at(25, 30)), at(10, 30));
+    }
+
+    #[test]
+    fn instant_duration() {
+        assert_eq!(at(10, 70).duration(), Duration::of(60));
+    }
+
+    #[test]
+    fn datetime_duration() {
+        let date = LocalDate::ymd(2017, Month::August, 21).unwrap();
+        let start = LocalDateTime::new(date, LocalTime::hm(22, 0).unwrap());
+        let end = LocalDateTime::new(date.add_days(1), LocalTime::hm(1, 30).unwrap());
+        assert_eq!(Interval::new(start, end).unwrap().duration(), Duration::of(3 * 60 * 60 + 30 * 60));
+    }
+
+    #[test]
+    fn date_days() {
+        let start = LocalDate::ymd(2016, Month::February, 1).unwrap();
+        let end = LocalDate::ymd(2016, Month::March, 1).unwrap();
+        assert_eq!(Interval::new(start, end).unwrap().days(), 29);
+    }
+
+    #[test]
+    fn date_range() {
+        let start = LocalDate::ymd(2017, Month::August, 30).unwrap();
+        let end = LocalDate::ymd(2017, Month::September, 2).unwrap();
+        let dates: Vec<_> = DateRange::days(Interval::new(start, end).unwrap()).collect();
+        assert_eq!(dates, vec![ start, start.add_days(1), start.add_days(2) ]);
+    }
+}
+
+
+mod set {
+    use super::*;
+
+    #[test]
+    fn merges_overlapping() {
+        let set: IntervalSet<_> = vec![ at(30, 40), at(10, 20), at(15, 25) ].into_iter().collect();
+        assert_eq!(intervals(&set), vec![ at(10, 25), at(30, 40) ]);
+    }
+
+    #[test]
+    fn merges_touching() {
+        let set: IntervalSet<_> = vec![ at(10, 20), at(20, 30) ].into_iter().collect();
+        assert_eq!(intervals(&set), vec![ at(10, 30) ]);
+    }
+
+    #[test]
+    fn bridges_several() {
+        let mut set: IntervalSet<_> = vec![ at(10, 20), at(30, 40), at(50, 60), at(70, 80) ].into_iter().collect();
+        set.insert(at(15, 55));
+        assert_eq!(intervals(&set), vec![ at(10, 60), at(70, 80) ]);
+    }
+
+    #[test]
+    fn ignores_empty() {
+        let mut set = IntervalSet::new();
+        set.insert(at(10, 10));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn contains() {
+        let set: IntervalSet<_> = vec![ at(10, 20), at(30, 40) ].into_iter().collect();
+        assert!(!set.contains(Instant::at(5)));
+        assert!(set.contains(Instant::at(10)));
+        assert!(!set.contains(Instant::at(20)));
+        assert!(!set.contains(Instant::at(25)));
+        assert!(set.contains(Instant::at(39)));
+        assert!(!set.contains(Instant::at(40)));
+    }
+
+    #[test]
+    fn conflicts() {
+        let set: IntervalSet<_> = vec![ at(10, 20), at(30, 40) ].into_iter().collect();
+        assert!(!set.overlaps(&at(20, 30)));
+        assert!(set.overlaps(&at(19, 30)));
+        assert!(set.overlaps(&at(0, 100)));
+        assert!(!set.overlaps(&at(40, 100)));
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut set = IntervalSet::from(at(10, 40));
+        set.remove(at(20, 30));
+        assert_eq!(intervals(&set), vec![ at(10, 20), at(30, 40) ]);
+    }
+
+    #[test]
+    fn remove_across_several() {
+        let mut set: IntervalSet<_> = vec![ at(10, 20), at(30, 40), at(50, 60) ].into_iter().collect();
+        set.remove(at(15, 55));
+        assert_eq!(intervals(&set), vec![ at(10, 15), at(55, 60) ]);
+    }
+
+    #[test]
+    fn remove_everything() {
+        let mut set: IntervalSet<_> = vec![ at(10, 20), at(30, 40) ].into_iter().collect();
+        set.remove(at(10, 40));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn remove_nothing() {
+        let mut set = IntervalSet::from(at(10, 20));
+        set.remove(at(20, 30));
+        set.remove(at(0, 10));
+        assert_eq!(intervals(&set), vec![ at(10, 20) ]);
+    }
+
+    #[test]
+    fn availability() {
+        let day = IntervalSet::from(at(0, 100));
+        let bookings: IntervalSet<_> = vec![ at(10, 20), at(15, 30), at(90, 120) ].into_iter().collect();
+        assert_eq!(intervals(&day.difference(&bookings)), vec![ at(0, 10), at(30, 90) ]);
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let a: IntervalSet<_> = vec![ at(0, 10), at(20, 30) ].into_iter().collect();
+        let b: IntervalSet<_> = vec![ at(5, 25) ].into_iter().collect();
+        assert_eq!(intervals(&a.union(&b)), vec![ at(0, 30) ]);
+        assert_eq!(intervals(&a.intersection(&b)), vec![ at(5, 10), at(20, 25) ]);
+    }
+}
@@ -0,0 +1,238 @@
+extern crate datetime;
+use datetime::{IsoInterval, IntervalPoint, RepeatingInterval, Period};
+use datetime::{LocalDate, LocalTime, LocalDateTime, Month, Instant, Duration};
+use datetime::Month::*;
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+fn datetime(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+    LocalDateTime::new(date(year, month, day), LocalTime::hm(hour, minute).unwrap())
+}
+
+
+mod periods {
+    use super::*;
+
+    #[test]
+    fn full() {
+        let period: Period = "P3Y6M4DT12H30M5S".parse().unwrap();
+        assert_eq!(period, Period { years: 3, months: 6, days: 4, hours: 12, minutes: 30, seconds: 5, ..Period::default() });
+    }
+
+    #[test]
+    fn months_and_minutes() {
+        assert_eq!("P1M".parse(), Ok(Period { months: 1, ..Period::default() }));
+        assert_eq!("PT1M".parse(), Ok(Period { minutes: 1, ..Period::default() }));
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!("P2W".parse(), Ok(Period { weeks: 2, ..Period::default() }));
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        assert_eq!("PT1.5S".parse(), Ok(Period { seconds: 1, milliseconds: 500, ..Period::default() }));
+        assert_eq!("PT0,25S".parse(), Ok(Period { milliseconds: 250, ..Period::default() }));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(Period { years: 1, days: 2, hours: 3, ..Period::default() }.to_string(), "P1Y2DT3H");
+        assert_eq!(Period { seconds: 1, milliseconds: 500, ..Period::default() }.to_string(), "PT1.5S");
+        assert_eq!(Period { seconds: -1, milliseconds: -500, ..Period::default() }.to_string(), "PT-1.5S");
+        assert_eq!(Period { milliseconds: -250, ..Period::default() }.to_string(), "PT-0.25S");
+        assert_eq!(Period::default().to_string(), "PT0S");
+    }
+
+    #[test]
+    fn invalid() {
+        for text in &[ "", "P", "PT", "1D", "PD", "P1D2M", "P1H", "PT1D", "P1.5D", "P1DT", "P-1D" ] {
+            assert!(text.parse::<Period>().is_err(), "{:?} parsed", text);
+        }
+    }
+
+    #[test]
+    fn too_large() {
+        assert!("P9223372036854775807Y".parse::<Period>().is_err());
+        assert!("PT4294967296S".parse::<Period>().is_err());
+    }
+
+    #[test]
+    fn add_largest() {
+        let period: Period = "P4294967295Y4294967295M4294967295W4294967295DT4294967295H4294967295M4294967295.999S".parse().unwrap();
+        assert!(datetime(2024, January, 1, 0, 0) + period > datetime(2024, January, 1, 0, 0));
+        assert!(datetime(2024, January, 1, 0, 0) - period < datetime(2024, January, 1, 0, 0));
+    }
+
+    #[test]
+    fn add_clamps_day() {
+        let period = Period { months: 1, ..Period::default() };
+        assert_eq!(datetime(2017, January, 31, 9, 0) + period, datetime(2017, February, 28, 9, 0));
+    }
+
+    #[test]
+    fn add_time_crosses_midnight() {
+        let period = Period { days: 1, hours: 20, ..Period::default() };
+        assert_eq!(datetime(2017, December, 31, 6, 0) + period, datetime(2018, January, 2, 2, 0));
+    }
+
+    #[test]
+    fn subtract() {
+        let period = Period { years: 1, minutes: 30, ..Period::default() };
+        assert_eq!(datetime(2016, February, 29, 0, 15) - period, datetime(2015, February, 27, 23, 45));
+    }
+}
+
+
+mod intervals {
+    use super::*;
+
+    #[test]
+    fn start_and_end() {
+        let interval: IsoInterval = "2024-01-01T09:00/2024-01-02T17:30".parse().unwrap();
+        assert_eq!(interval, IsoInterval::StartEnd(IntervalPoint::DateTime(datetime(2024, January, 1, 9, 0)),
+                                                   IntervalPoint::DateTime(datetime(2024, January, 2, 17, 30))));
+    }
+
+    #[test]
+    fn start_and_duration() {
+        let interval: IsoInterval = "2024-01-31/P1M".parse().unwrap();
+        assert_eq!(interval.end(), Some(IntervalPoint::Date(date(2024, February, 29))));
+    }
+
+    #[test]
+    fn date_start_with_time_duration() {
+        let interval: IsoInterval = "2024-01-01/PT36H".parse().unwrap();
+        assert_eq!(interval.end(), Some(IntervalPoint::DateTime(datetime(2024, January, 2, 12, 0))));
+    }
+
+    #[test]
+    fn duration_and_end() {
+        let interval: IsoInterval = "P1DT2H/2024-03-01T00:00".parse().unwrap();
+        assert_eq!(interval.start(), Some(IntervalPoint::DateTime(datetime(2024, February, 28, 22, 0))));
+    }
+
+    #[test]
+    fn duration_only() {
+        let interval: IsoInterval = "P1Y".parse().unwrap();
+        assert_eq!(interval, IsoInterval::Duration(Period { years: 1, ..Period::default() }));
+        assert_eq!(interval.start(), None);
+        assert_eq!(interval.end(), None);
+    }
+
+    #[test]
+    fn double_hyphen() {
+        let interval: IsoInterval = "2024-01-01--2024-01-05".parse().unwrap();
+        assert_eq!(interval.end(), Some(IntervalPoint::Date(date(2024, January, 5))));
+    }
+
+    #[test]
+    fn two_durations() {
+        assert!("P1D/P2D".parse::<IsoInterval>().is_err());
+    }
+
+    #[test]
+    fn invalid_point() {
+        assert!("2024-13-01/P1D".parse::<IsoInterval>().is_err());
+        assert!("yesterday".parse::<IsoInterval>().is_err());
+    }
+
+    #[test]
+    fn format() {
+        let interval = IsoInterval::StartDuration(IntervalPoint::DateTime(datetime(2024, January, 1, 9, 0)), Period { hours: 8, ..Period::default() });
+        assert_eq!(interval.to_string(), "2024-01-01T09:00:00.000/PT8H");
+    }
+
+    #[test]
+    fn format_round_trip() {
+        for text in &[ "2024-01-01/2024-02-01", "P1W/2024-01-01T00:00:00.000", "2024-01-01T00:00:00.000+01/P1Y2M" ] {
+            let interval: IsoInterval = text.parse().unwrap();
+            assert_eq!(interval.to_string().parse::<IsoInterval>(), Ok(interval));
+        }
+    }
+
+    #[test]
+    fn instants() {
+        let interval: IsoInterval = "2017-08-21T10:00Z/2017-08-21T12:00+01:00".parse().unwrap();
+        let instants = interval.to_instants().unwrap();
+        assert_eq!(instants.start(), Instant::at(1503309600));
+        assert_eq!(instants.duration(), Duration::of(60 * 60));
+    }
+
+    #[test]
+    fn no_instants_without_offset() {
+        let interval: IsoInterval = "2017-08-21T10:00/PT1H".parse().unwrap();
+        assert_eq!(interval.to_instants(), None);
+    }
+}
+
+
+mod reduced {
+    use super::*;
+
+    #[test]
+    fn day_only() {
+        let interval: IsoInterval = "2024-01-01/05".parse().unwrap();
+        assert_eq!(interval.end(), Some(IntervalPoint::Date(date(2024, January, 5))));
+    }
+
+    #[test]
+    fn month_and_day() {
+        let interval: IsoInterval = "2008-02-15/03-14".parse().unwrap();
+        assert_eq!(interval.end(), Some(IntervalPoint::Date(date(2008, March, 14))));
+    }
+
+    #[test]
+    fn time_only() {
+        let interval: IsoInterval = "2007-12-14T13:30/15:30".parse().unwrap();
+        assert_eq!(interval.end(), Some(IntervalPoint::DateTime(datetime(2007, December, 14, 15, 30))));
+    }
+
+    #[test]
+    fn day_and_time() {
+        let interval: IsoInterval = "2007-12-14T13:30/15T09:00".parse().unwrap();
+        assert_eq!(interval.end(), Some(IntervalPoint::DateTime(datetime(2007, December, 15, 9, 0))));
+    }
+
+    #[test]
+    fn keeps_offset() {
+        let interval: IsoInterval = "2024-01-01T09:00+02:00/17:00".parse().unwrap();
+        assert_eq!(interval.to_instants().unwrap().duration(), Duration::of(8 * 60 * 60));
+    }
+}
+
+
+mod repeating {
+    use super::*;
+
+    #[test]
+    fn count() {
+        let repeating: RepeatingInterval = "R5/2024-01-01T00:00Z/P1D".parse().unwrap();
+        assert_eq!(repeating.repetitions, Some(5));
+        assert_eq!(repeating.interval.to_instants().unwrap().duration(), Duration::of(24 * 60 * 60));
+    }
+
+    #[test]
+    fn unbounded() {
+        assert_eq!("R/P1W".parse::<RepeatingInterval>().unwrap().repetitions, None);
+        assert_eq!("R-1/P1W".parse::<RepeatingInterval>().unwrap().repetitions, None);
+    }
+
+    #[test]
+    fn format() {
+        let repeating = RepeatingInterval { repetitions: Some(3), interval: IsoInterval::Duration(Period { days: 1, ..Period::default() }) };
+        assert_eq!(repeating.to_string(), "R3/P1D");
+        assert_eq!(RepeatingInterval { repetitions: None, ..repeating }.to_string(), "R/P1D");
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("R5".parse::<RepeatingInterval>().is_err());
+        assert!("Rx/P1D".parse::<RepeatingInterval>().is_err());
+        assert!("2024-01-01/P1D".parse::<RepeatingInterval>().is_err());
+    }
+}
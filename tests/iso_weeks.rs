@@ -0,0 +1,85 @@
+extern crate datetime;
+use datetime::{LocalDate, Month, Weekday, Year, DatePiece};
+
+extern crate rustc_serialize;
+use rustc_serialize::json::Json;
+
+use std::fs::File;
+use std::io::prelude::*;
+
+
+fn open_test_file() -> String {
+    let mut file = File::open("./tests/examples.json").unwrap();
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).unwrap();
+    contents
+}
+
+
+#[test]
+fn examples() {
+    if let Json::Array(examples) = Json::from_str(&open_test_file()).unwrap() {
+        for example in examples {
+            let ywd = example[1].as_array().unwrap();
+            let ymd = example[3].as_array().unwrap();
+
+            let date = LocalDate::ymd(ymd[0].as_i64().unwrap(), Month::from_one(ymd[1].as_i64().unwrap() as i8).unwrap(), ymd[2].as_i64().unwrap() as i8).unwrap();
+            assert_eq!(date.iso_week_year(), ywd[0].as_i64().unwrap(), "{:?}", date);
+            assert_eq!(date.iso_week() as i64, ywd[1].as_i64().unwrap(), "{:?}", date);
+        }
+    }
+}
+
+#[test]
+fn start_of_previous_year() {
+    let date = LocalDate::ymd(2021, Month::January, 3).unwrap();
+    assert_eq!(date.iso_week_year(), 2020);
+    assert_eq!(date.iso_week(), 53);
+}
+
+#[test]
+fn end_of_next_year() {
+    let date = LocalDate::ymd(2024, Month::December, 30).unwrap();
+    assert_eq!(date.iso_week_year(), 2025);
+    assert_eq!(date.iso_week(), 1);
+}
+
+#[test]
+fn weeks_in_year() {
+    let long_years: Vec<_> = (2000 .. 2030).filter(|&y| Year(y).week(1).unwrap().weeks_in_year() == 53).collect();
+    assert_eq!(long_years, vec![ 2004, 2009, 2015, 2020, 2026 ]);
+}
+
+#[test]
+fn week_out_of_range() {
+    assert!(Year(2021).week(0).is_err());
+    assert!(Year(2021).week(53).is_err());
+}
+
+#[test]
+fn accessors() {
+    let week = LocalDate::ymd(2010, Month::January, 3).unwrap().year_week();
+    assert_eq!((week.year(), week.week()), (Year(2009), 53));
+}
+
+#[test]
+fn days_round_trip() {
+    for week in Year(2015).weeks() {
+        for (date, weekday) in week.days().zip(&[ Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday, Weekday::Friday, Weekday::Saturday, Weekday::Sunday ]) {
+            assert_eq!(date.weekday(), *weekday);
+            assert_eq!(date.year_week(), week);
+        }
+    }
+}
+
+#[test]
+fn entire_week_year() {
+    let count = Year(2015).weeks().flat_map(|w| w.days()).count();
+    assert_eq!(count, 371);
+}
+
+#[test]
+fn weeks_backwards() {
+    let last = Year(2015).weeks().next_back().unwrap();
+    assert_eq!(last, Year(2015).week(53).unwrap());
+}
@@ -0,0 +1,144 @@
+extern crate datetime;
+use datetime::{JulianDate, HistoricalDate, Cutover, LocalDate, Month, Weekday};
+use datetime::Month::*;
+
+
+fn gregorian(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+fn julian(year: i64, month: Month, day: i8) -> JulianDate {
+    JulianDate::ymd(year, month, day).unwrap()
+}
+
+
+mod julian_dates {
+    use super::*;
+
+    #[test]
+    fn rome_cutover() {
+        assert_eq!(julian(1582, October, 4).to_local_date(), gregorian(1582, October, 14));
+        assert_eq!(julian(1582, October, 4).weekday(), Weekday::Thursday);
+    }
+
+    #[test]
+    fn great_britain_cutover() {
+        assert_eq!(julian(1752, September, 2).to_local_date(), gregorian(1752, September, 13));
+    }
+
+    #[test]
+    fn thirteen_days_behind() {
+        assert_eq!(JulianDate::from_local_date(gregorian(2017, August, 21)), julian(2017, August, 8));
+        assert_eq!(JulianDate::from(gregorian(2000, January, 1)), julian(1999, December, 19));
+    }
+
+    #[test]
+    fn calendars_agree_in_third_century() {
+        assert_eq!(julian(200, March, 1).to_local_date(), gregorian(200, March, 1));
+        assert_eq!(julian(300, February, 28).to_local_date(), gregorian(300, February, 28));
+    }
+
+    #[test]
+    fn century_leap_years() {
+        assert!(JulianDate::ymd(1700, February, 29).is_ok());
+        assert!(LocalDate::ymd(1700, February, 29).is_err());
+        assert_eq!(julian(1700, February, 29).to_local_date(), gregorian(1700, March, 11));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(JulianDate::ymd(2017, February, 29).is_err());
+        assert!(JulianDate::ymd(2017, April, 31).is_err());
+        assert!(JulianDate::ymd(2017, April, 0).is_err());
+    }
+
+    #[test]
+    fn julian_period_start() {
+        let start = julian(-4712, January, 1);
+        assert_eq!(start.to_local_date().to_julian_day_number(), 0);
+        assert_eq!(LocalDate::from(start), gregorian(-4713, November, 24));
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut date = gregorian(-5000, January, 1);
+        while date < gregorian(3000, January, 1) {
+            assert_eq!(JulianDate::from_local_date(date).to_local_date(), date);
+            date = date.add_days(113);
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(julian(1582, October, 4) < julian(1582, October, 5));
+        assert!(julian(1581, December, 31) < julian(1582, January, 1));
+    }
+}
+
+
+mod historical_dates {
+    use super::*;
+
+    #[test]
+    fn before_cutover_is_julian() {
+        let date = HistoricalDate::ymd(1582, October, 4, Cutover::rome()).unwrap();
+        assert!(date.is_julian());
+        assert_eq!(date.to_local_date(), gregorian(1582, October, 14));
+    }
+
+    #[test]
+    fn from_cutover_is_gregorian() {
+        let date = HistoricalDate::ymd(1582, October, 15, Cutover::rome()).unwrap();
+        assert!(!date.is_julian());
+        assert_eq!(date.to_local_date(), gregorian(1582, October, 15));
+    }
+
+    #[test]
+    fn skipped_days() {
+        for day in 5 ..= 14 {
+            assert!(HistoricalDate::ymd(1582, October, day, Cutover::rome()).is_err());
+        }
+
+        assert!(HistoricalDate::ymd(1752, September, 3, Cutover::great_britain()).is_err());
+        assert!(HistoricalDate::ymd(1752, September, 13, Cutover::great_britain()).is_err());
+    }
+
+    #[test]
+    fn julian_leap_day() {
+        let date = HistoricalDate::ymd(1700, February, 29, Cutover::great_britain()).unwrap();
+        assert_eq!(date.to_local_date(), gregorian(1700, March, 11));
+        assert!(HistoricalDate::ymd(1700, February, 29, Cutover::rome()).is_err());
+    }
+
+    #[test]
+    fn fields() {
+        let date = HistoricalDate::from_local_date(gregorian(1752, September, 13), Cutover::great_britain());
+        assert_eq!((date.year(), date.month(), date.day()), (1752, September, 2));
+        assert_eq!(date.weekday(), Weekday::Wednesday);
+    }
+
+    #[test]
+    fn across_the_cutover() {
+        let date = HistoricalDate::ymd(1752, September, 2, Cutover::great_britain()).unwrap();
+        let next = date.add_days(1);
+        assert_eq!((next.year(), next.month(), next.day()), (1752, September, 14));
+        assert_eq!(next.weekday(), Weekday::Thursday);
+    }
+
+    #[test]
+    fn custom_cutover() {
+        // Russia switched in 1918, when the 31st of January was followed by
+        // the 14th of February.
+        let russia = Cutover::new(gregorian(1918, February, 14));
+        assert_eq!(russia.last_julian_day(), julian(1918, January, 31));
+
+        let revolution = HistoricalDate::ymd(1917, October, 25, russia).unwrap();
+        assert_eq!(revolution.to_local_date(), gregorian(1917, November, 7));
+    }
+
+    #[test]
+    fn default_cutover() {
+        assert_eq!(Cutover::default(), Cutover::rome());
+        assert_eq!(Cutover::rome().last_julian_day(), julian(1582, October, 4));
+    }
+}
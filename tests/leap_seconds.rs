@@ -0,0 +1,114 @@
+extern crate datetime;
+use datetime::{Instant, LeapSecondTable, LocalDate, LocalTime, LocalDateTime, Month, ISO};
+use datetime::scale::Error;
+
+use std::fs::File;
+use std::io::prelude::*;
+use std::str::FromStr;
+
+
+fn open_test_file() -> String {
+    let mut file = File::open("./tests/leap-seconds.list").unwrap();
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).unwrap();
+    contents
+}
+
+
+mod list {
+    use super::*;
+
+    #[test]
+    fn matches_builtin() {
+        let table = LeapSecondTable::parse_list(&open_test_file(), Instant::at(1_700_000_000)).unwrap();
+        assert_eq!(table.leap_seconds(), LeapSecondTable::builtin().leap_seconds());
+        assert_eq!(table.expires(), Some(Instant::at(1_798_416_000)));
+    }
+
+    #[test]
+    fn expired() {
+        let result = LeapSecondTable::parse_list(&open_test_file(), Instant::at(1_798_416_000));
+        assert_eq!(result, Err(Error::Expired(Instant::at(1_798_416_000))));
+    }
+
+    #[test]
+    fn tampered() {
+        let contents = open_test_file().replace("37      # 1 Jan 2017", "38      # 1 Jan 2017");
+        let result = LeapSecondTable::parse_list(&contents, Instant::at(1_700_000_000));
+        assert_eq!(result, Err(Error::HashMismatch));
+    }
+
+    #[test]
+    fn missing_hash() {
+        let contents: String = open_test_file().lines()
+                                               .filter(|line| !line.starts_with("#h"))
+                                               .map(|line| format!("{}\n", line))
+                                               .collect();
+
+        let result = LeapSecondTable::parse_list(&contents, Instant::at(1_700_000_000));
+        assert_eq!(result, Err(Error::MissingLine('h')));
+    }
+
+    #[test]
+    fn invalid_line() {
+        let result = LeapSecondTable::parse_list("#$\t3676924800\nnonsense\n", Instant::at(0));
+        assert_eq!(result, Err(Error::InvalidLine(2)));
+    }
+}
+
+
+mod second_sixty {
+    use super::*;
+
+    #[test]
+    fn leap_second_time() {
+        assert!(LocalTime::hms(23, 59, 60).unwrap().is_leap_second());
+        assert!(LocalTime::hms_ms(23, 59, 60, 999).is_ok());
+    }
+
+    #[test]
+    fn not_at_end_of_day() {
+        assert!(LocalTime::hms(22, 59, 60).is_err());
+        assert!(LocalTime::hms_ms(23, 58, 60, 0).is_err());
+    }
+
+    #[test]
+    fn leap_second_days() {
+        let table = LeapSecondTable::builtin();
+        assert!(table.has_leap_second_on(LocalDate::ymd(1972, Month::June, 30).unwrap()));
+        assert!(table.has_leap_second_on(LocalDate::ymd(2016, Month::December, 31).unwrap()));
+        assert!(!table.has_leap_second_on(LocalDate::ymd(1971, Month::December, 31).unwrap()));
+        assert!(!table.has_leap_second_on(LocalDate::ymd(2016, Month::December, 30).unwrap()));
+    }
+
+    #[test]
+    fn ordering() {
+        let date = LocalDate::ymd(2016, Month::December, 31).unwrap();
+        let fifty_nine = LocalDateTime::new(date, LocalTime::hms(23, 59, 59).unwrap());
+        let sixty      = LocalDateTime::new(date, LocalTime::hms(23, 59, 60).unwrap());
+        assert!(fifty_nine < sixty);
+    }
+
+    #[test]
+    fn instant_repeats() {
+        let date = LocalDate::ymd(2016, Month::December, 31).unwrap();
+        let sixty = LocalDateTime::new(date, LocalTime::hms_ms(23, 59, 60, 500).unwrap());
+        assert_eq!(sixty.to_instant(), Instant::at_ms(1_483_228_799, 500));
+    }
+
+    #[test]
+    fn iso_round_trip() {
+        let date = LocalDate::ymd(2016, Month::December, 31).unwrap();
+        let sixty = LocalDateTime::new(date, LocalTime::hms_ms(23, 59, 60, 500).unwrap());
+
+        let formatted = sixty.iso().to_string();
+        assert_eq!(formatted, "2016-12-31T23:59:60.500");
+        assert_eq!(LocalDateTime::from_str(&formatted), Ok(sixty));
+    }
+
+    #[test]
+    fn parse_time() {
+        assert_eq!(LocalTime::from_str("23:59:60"), Ok(LocalTime::hms(23, 59, 60).unwrap()));
+        assert!(LocalTime::from_str("12:00:60").is_err());
+    }
+}
@@ -0,0 +1,303 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month, DatePiece, TimePiece};
+use datetime::recur::{Recurrence, RecurrenceRule, Frequency, Error};
+use datetime::zone::{StaticTimeZone, FixedTimespanSet, FixedTimespan, TimeZoneSource, TimeZone};
+use std::borrow::Cow;
+
+
+fn dates(input: &str, limit: usize) -> Vec<(i64, i8, i8)> {
+    let recurrence = Recurrence::parse(input).unwrap();
+    recurrence.dates().take(limit).map(|d| (d.year(), d.month() as i8, d.day())).collect()
+}
+
+
+mod rfc_examples {
+    use super::*;
+
+    #[test]
+    fn daily_for_ten() {
+        let result = dates("DTSTART:19970902T090000\nRRULE:FREQ=DAILY;COUNT=10", 100);
+        assert_eq!(result.len(), 10);
+        assert_eq!(result[9], (1997, 9, 11));
+    }
+
+    #[test]
+    fn weekly_tuesday_and_thursday() {
+        let result = dates("DTSTART:19970902T090000\nRRULE:FREQ=WEEKLY;COUNT=10;WKST=SU;BYDAY=TU,TH", 100);
+        assert_eq!(result, vec![
+            (1997, 9, 2), (1997, 9, 4), (1997, 9, 9), (1997, 9, 11), (1997, 9, 16),
+            (1997, 9, 18), (1997, 9, 23), (1997, 9, 25), (1997, 9, 30), (1997, 10, 2),
+        ]);
+    }
+
+    #[test]
+    fn week_start_matters() {
+        let monday = dates("DTSTART:19970805T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", 100);
+        assert_eq!(monday, vec![ (1997, 8, 5), (1997, 8, 10), (1997, 8, 19), (1997, 8, 24) ]);
+
+        let sunday = dates("DTSTART:19970805T090000\nRRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", 100);
+        assert_eq!(sunday, vec![ (1997, 8, 5), (1997, 8, 17), (1997, 8, 19), (1997, 8, 31) ]);
+    }
+
+    #[test]
+    fn first_friday() {
+        let result = dates("DTSTART:19970905T090000\nRRULE:FREQ=MONTHLY;COUNT=10;BYDAY=1FR", 100);
+        assert_eq!(result, vec![
+            (1997, 9, 5), (1997, 10, 3), (1997, 11, 7), (1997, 12, 5), (1998, 1, 2),
+            (1998, 2, 6), (1998, 3, 6), (1998, 4, 3), (1998, 5, 1), (1998, 6, 5),
+        ]);
+    }
+
+    #[test]
+    fn second_to_last_monday() {
+        let result = dates("DTSTART:19970922T090000\nRRULE:FREQ=MONTHLY;COUNT=6;BYDAY=-2MO", 100);
+        assert_eq!(result, vec![
+            (1997, 9, 22), (1997, 10, 20), (1997, 11, 17), (1997, 12, 22), (1998, 1, 19), (1998, 2, 16),
+        ]);
+    }
+
+    #[test]
+    fn last_work_day() {
+        let result = dates("DTSTART:19970930T090000\nRRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1", 7);
+        assert_eq!(result, vec![
+            (1997, 9, 30), (1997, 10, 31), (1997, 11, 28), (1997, 12, 31), (1998, 1, 30), (1998, 2, 27), (1998, 3, 31),
+        ]);
+    }
+
+    #[test]
+    fn third_weekday_instance() {
+        let result = dates("DTSTART:19970904T090000\nRRULE:FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3", 100);
+        assert_eq!(result, vec![ (1997, 9, 4), (1997, 10, 7), (1997, 11, 6) ]);
+    }
+
+    #[test]
+    fn friday_the_thirteenth() {
+        let result = dates("DTSTART:19970902T090000\nEXDATE:19970902T090000\nRRULE:FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13", 5);
+        assert_eq!(result, vec![ (1998, 2, 13), (1998, 3, 13), (1998, 11, 13), (1999, 8, 13), (2000, 10, 13) ]);
+    }
+
+    #[test]
+    fn skipping_short_months() {
+        let result = dates("DTSTART:20070115T090000\nRRULE:FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5", 100);
+        assert_eq!(result, vec![ (2007, 1, 15), (2007, 1, 30), (2007, 2, 15), (2007, 3, 15), (2007, 3, 30) ]);
+    }
+
+    #[test]
+    fn june_and_july() {
+        let result = dates("DTSTART:19970610T090000\nRRULE:FREQ=YEARLY;COUNT=10;BYMONTH=6,7", 100);
+        assert_eq!(result.len(), 10);
+        assert_eq!(&result[.. 3], &[ (1997, 6, 10), (1997, 7, 10), (1998, 6, 10) ]);
+        assert_eq!(result[9], (2001, 7, 10));
+    }
+
+    #[test]
+    fn year_days() {
+        let result = dates("DTSTART:19970101T090000\nRRULE:FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200", 100);
+        assert_eq!(&result[.. 6], &[ (1997, 1, 1), (1997, 4, 10), (1997, 7, 19), (2000, 1, 1), (2000, 4, 9), (2000, 7, 18) ]);
+    }
+
+    #[test]
+    fn twentieth_monday() {
+        let result = dates("DTSTART:19970519T090000\nRRULE:FREQ=YEARLY;BYDAY=20MO", 3);
+        assert_eq!(result, vec![ (1997, 5, 19), (1998, 5, 18), (1999, 5, 17) ]);
+    }
+
+    #[test]
+    fn week_number_twenty() {
+        let result = dates("DTSTART:19970512T090000\nRRULE:FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", 3);
+        assert_eq!(result, vec![ (1997, 5, 12), (1998, 5, 11), (1999, 5, 17) ]);
+    }
+
+    #[test]
+    fn week_number_one_crosses_years() {
+        let result = dates("DTSTART:19970101T090000\nRRULE:FREQ=YEARLY;BYWEEKNO=1;BYDAY=MO", 4);
+        assert_eq!(result, vec![ (1997, 1, 1), (1997, 12, 29), (1999, 1, 4), (2000, 1, 3) ]);
+    }
+
+    #[test]
+    fn every_three_hours() {
+        let recurrence = Recurrence::parse("DTSTART:19970902T090000\nRRULE:FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z").unwrap();
+        let hours: Vec<_> = recurrence.iter().map(|dt| dt.hour()).collect();
+        assert_eq!(hours, vec![ 9, 12, 15 ]);
+    }
+
+    #[test]
+    fn until_a_date() {
+        let result = dates("DTSTART:19971224T000000\nRRULE:FREQ=YEARLY;UNTIL=20001224", 100);
+        assert_eq!(result, vec![ (1997, 12, 24), (1998, 12, 24), (1999, 12, 24), (2000, 12, 24) ]);
+    }
+
+    #[test]
+    fn by_hour() {
+        let recurrence = Recurrence::parse("DTSTART:19970902T093000\nRRULE:FREQ=DAILY;BYHOUR=9,17;COUNT=4").unwrap();
+        let times: Vec<_> = recurrence.iter().map(|dt| (dt.day(), dt.hour(), dt.minute())).collect();
+        assert_eq!(times, vec![ (2, 9, 30), (2, 17, 30), (3, 9, 30), (3, 17, 30) ]);
+    }
+}
+
+
+mod sets {
+    use super::*;
+
+    #[test]
+    fn extra_dates() {
+        let result = dates("DTSTART:20170101T090000\nRRULE:FREQ=MONTHLY;COUNT=3\nRDATE:20170115T090000,20170101T090000", 100);
+        assert_eq!(result, vec![ (2017, 1, 1), (2017, 1, 15), (2017, 2, 1), (2017, 3, 1) ]);
+    }
+
+    #[test]
+    fn start_always_counts() {
+        let result = dates("DTSTART:20170102T090000\nRRULE:FREQ=WEEKLY;BYDAY=WE;COUNT=2", 100);
+        assert_eq!(result, vec![ (2017, 1, 2), (2017, 1, 4) ]);
+    }
+
+    #[test]
+    fn matching_start_counts_once() {
+        let result = dates("DTSTART:20170104T090000\nRRULE:FREQ=WEEKLY;BYDAY=WE;COUNT=2", 100);
+        assert_eq!(result, vec![ (2017, 1, 4), (2017, 1, 11) ]);
+    }
+
+    #[test]
+    fn count_of_one_is_just_the_start() {
+        let result = dates("DTSTART:20170102T090000\nRRULE:FREQ=WEEKLY;BYDAY=WE;COUNT=1", 100);
+        assert_eq!(result, vec![ (2017, 1, 2) ]);
+    }
+
+    #[test]
+    fn never_matches() {
+        assert_eq!(dates("DTSTART:20170101T090000\nRRULE:FREQ=DAILY;BYMONTH=2;BYMONTHDAY=30", 100), vec![ (2017, 1, 1) ]);
+        assert_eq!(dates("DTSTART:20170101T090000\nRRULE:FREQ=HOURLY;BYMONTH=2;BYMONTHDAY=30", 100), vec![ (2017, 1, 1) ]);
+    }
+
+    #[test]
+    fn built_by_hand() {
+        let mut rule = RecurrenceRule::new(Frequency::Daily);
+        rule.interval = 10;
+        rule.count = Some(3);
+
+        let start = LocalDateTime::new(LocalDate::ymd(2017, Month::January, 1).unwrap(), LocalTime::midnight());
+        let days: Vec<_> = Recurrence::new(start, rule).dates().map(|d| d.day()).collect();
+        assert_eq!(days, vec![ 1, 11, 21 ]);
+    }
+}
+
+
+/// A zone that moves its clocks forward at 01:00 on the 28th of March
+/// 2010, and back again at 02:00 on the 31st of October.
+const TEST_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Test Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 0,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1269738000, FixedTimespan {
+                offset: 3600,
+                is_dst: true,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+            (1288486800, FixedTimespan {
+                offset: 0,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_A"),
+            }),
+        ]
+    }
+};
+
+mod zoned {
+    use super::*;
+
+    #[test]
+    fn time_in_gap_moves_forward() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        let recurrence = Recurrence::parse("DTSTART:20100327T013000\nRRULE:FREQ=DAILY;COUNT=3").unwrap();
+        let times: Vec<_> = recurrence.zoned(&zone).map(|dt| (dt.day(), dt.hour(), dt.minute())).collect();
+        assert_eq!(times, vec![ (27, 1, 30), (28, 2, 30), (29, 1, 30) ]);
+    }
+
+    #[test]
+    fn ambiguous_time_is_earlier() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        let recurrence = Recurrence::parse("DTSTART:20101031T013000").unwrap();
+        let occurrence = recurrence.zoned(&zone).next().unwrap();
+
+        let utc = LocalDateTime::new(LocalDate::ymd(2010, Month::October, 31).unwrap(), LocalTime::hm(0, 30).unwrap());
+        assert_eq!(occurrence.to_instant(), utc.to_instant());
+    }
+
+    #[test]
+    fn utc_until() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        let recurrence = Recurrence::parse("DTSTART:20100601T090000\nRRULE:FREQ=HOURLY;UNTIL=20100601T100000Z").unwrap();
+        assert_eq!(recurrence.zoned(&zone).count(), 3);
+        assert_eq!(recurrence.iter().count(), 2);
+    }
+
+    #[test]
+    fn utc_start() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        let recurrence = Recurrence::parse("DTSTART:20101030T090000Z\nRRULE:FREQ=DAILY;COUNT=3").unwrap();
+
+        // The clocks go back in the early hours of the 31st, but every
+        // occurrence stays at 09:00 UTC.
+        let times: Vec<_> = recurrence.zoned(&zone).map(|dt| (dt.day(), dt.hour())).collect();
+        assert_eq!(times, vec![ (30, 10), (31, 9), (1, 9) ]);
+
+        let times: Vec<_> = recurrence.iter().map(|dt| (dt.day(), dt.hour())).collect();
+        assert_eq!(times, vec![ (30, 9), (31, 9), (1, 9) ]);
+    }
+
+    #[test]
+    fn utc_extra_dates() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        let recurrence = Recurrence::parse("DTSTART:20100601T090000\nRRULE:FREQ=DAILY;COUNT=3\nEXDATE:20100602T080000Z\nRDATE:20100610T120000Z").unwrap();
+
+        let times: Vec<_> = recurrence.zoned(&zone).map(|dt| (dt.day(), dt.hour())).collect();
+        assert_eq!(times, vec![ (1, 9), (3, 9), (10, 13) ]);
+
+        let times: Vec<_> = recurrence.iter().map(|dt| (dt.day(), dt.hour())).collect();
+        assert_eq!(times, vec![ (1, 9), (2, 9), (3, 9), (10, 12) ]);
+    }
+}
+
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn missing_frequency() {
+        assert_eq!("COUNT=3".parse::<RecurrenceRule>(), Err(Error::MissingFrequency));
+    }
+
+    #[test]
+    fn count_and_until() {
+        assert_eq!("FREQ=DAILY;COUNT=3;UNTIL=20170101".parse::<RecurrenceRule>(), Err(Error::CountAndUntil));
+    }
+
+    #[test]
+    fn unsupported_frequency() {
+        assert!("FREQ=MINUTELY".parse::<RecurrenceRule>().is_err());
+    }
+
+    #[test]
+    fn invalid_parts() {
+        assert_eq!("FREQ=DAILY;BYDAY=XY".parse::<RecurrenceRule>(), Err(Error::InvalidPart("BYDAY=XY".into())));
+        assert!("FREQ=DAILY;BYMONTHDAY=0".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;BYMONTH=13".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=DAILY;INTERVAL=0".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=MONTHLY;BYWEEKNO=3".parse::<RecurrenceRule>().is_err());
+    }
+
+    #[test]
+    fn missing_start() {
+        assert_eq!(Recurrence::parse("RRULE:FREQ=DAILY"), Err(Error::MissingStart));
+    }
+
+    #[test]
+    fn invalid_line() {
+        assert_eq!(Recurrence::parse("DTSTART:20170101\nSUMMARY:Party"), Err(Error::InvalidLine(2)));
+    }
+}
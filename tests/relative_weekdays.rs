@@ -0,0 +1,125 @@
+extern crate datetime;
+use datetime::{LocalDate, Month, Weekday, Year, DatePiece};
+
+
+fn date(year: i64, month: Month, day: i8) -> LocalDate {
+    LocalDate::ymd(year, month, day).unwrap()
+}
+
+static WEEKDAYS: &[Weekday] = &[
+    Weekday::Sunday, Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday,
+    Weekday::Thursday, Weekday::Friday, Weekday::Saturday,
+];
+
+
+mod nth_weekday {
+    use super::*;
+
+    #[test]
+    fn first() {
+        // The 1st of March 2017 was a Wednesday.
+        let ym = Year(2017).month(Month::March);
+        assert_eq!(ym.nth_weekday(Weekday::Wednesday, 1), Ok(date(2017, Month::March, 1)));
+        assert_eq!(ym.nth_weekday(Weekday::Tuesday, 1), Ok(date(2017, Month::March, 7)));
+    }
+
+    #[test]
+    fn last() {
+        // The 31st of May 2017 was a Wednesday.
+        let ym = Year(2017).month(Month::May);
+        assert_eq!(ym.nth_weekday(Weekday::Wednesday, -1), Ok(date(2017, Month::May, 31)));
+        assert_eq!(ym.nth_weekday(Weekday::Monday, -1), Ok(date(2017, Month::May, 29)));
+        assert_eq!(ym.nth_weekday(Weekday::Monday, -2), Ok(date(2017, Month::May, 22)));
+    }
+
+    #[test]
+    fn fifth() {
+        let ym = Year(2017).month(Month::May);
+        assert_eq!(ym.nth_weekday(Weekday::Monday, 5), Ok(date(2017, Month::May, 29)));
+        assert!(ym.nth_weekday(Weekday::Sunday, 5).is_err());
+        assert!(ym.nth_weekday(Weekday::Sunday, -5).is_err());
+    }
+
+    #[test]
+    fn zero() {
+        assert!(Year(2017).month(Month::May).nth_weekday(Weekday::Sunday, 0).is_err());
+    }
+
+    #[test]
+    fn far_out_of_range() {
+        let ym = Year(2017).month(Month::May);
+        assert!(ym.nth_weekday(Weekday::Sunday, 20).is_err());
+        assert!(ym.nth_weekday(Weekday::Sunday, -20).is_err());
+        assert!(ym.nth_weekday(Weekday::Sunday, i8::MAX).is_err());
+        assert!(ym.nth_weekday(Weekday::Sunday, i8::MIN).is_err());
+    }
+
+    #[test]
+    fn leap_february() {
+        let ym = Year(2016).month(Month::February);
+        assert_eq!(ym.nth_weekday(Weekday::Monday, 5), Ok(date(2016, Month::February, 29)));
+        assert_eq!(ym.nth_weekday(Weekday::Monday, -5), Ok(date(2016, Month::February, 1)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let ym = Year(2016).month(Month::October);
+        for day in ym.days(..) {
+            let n = day.weekday_of_month();
+            assert_eq!(ym.nth_weekday(day.weekday(), n), Ok(day));
+            assert_eq!(day.is_last_weekday_of_month(), ym.nth_weekday(day.weekday(), -1) == Ok(day));
+        }
+    }
+}
+
+
+mod navigation {
+    use super::*;
+
+    #[test]
+    fn next_is_always_after() {
+        let start = date(2017, Month::December, 28);
+        for days in 0 .. 14 {
+            let day = start.add_days(days);
+            for &weekday in WEEKDAYS {
+                let next = day.next(weekday);
+                assert_eq!(next.weekday(), weekday);
+                assert!((1 ..= 7).contains(&day.days_until(next)));
+
+                let same = day.next_or_same(weekday);
+                assert_eq!(same.weekday(), weekday);
+                assert!((0 .. 7).contains(&day.days_until(same)));
+            }
+        }
+    }
+
+    #[test]
+    fn previous_is_always_before() {
+        let start = date(2017, Month::December, 28);
+        for days in 0 .. 14 {
+            let day = start.add_days(days);
+            for &weekday in WEEKDAYS {
+                let previous = day.previous(weekday);
+                assert_eq!(previous.weekday(), weekday);
+                assert!((1 ..= 7).contains(&previous.days_until(day)));
+
+                let same = day.previous_or_same(weekday);
+                assert_eq!(same.weekday(), weekday);
+                assert!((0 .. 7).contains(&same.days_until(day)));
+            }
+        }
+    }
+
+    #[test]
+    fn or_same() {
+        let monday = date(2017, Month::March, 6);
+        assert_eq!(monday.next_or_same(Weekday::Monday), monday);
+        assert_eq!(monday.previous_or_same(Weekday::Monday), monday);
+    }
+
+    #[test]
+    fn across_years() {
+        let date = date(2016, Month::December, 30);
+        assert_eq!(date.next(Weekday::Tuesday), LocalDate::ymd(2017, Month::January, 3).unwrap());
+    }
+}
@@ -0,0 +1,134 @@
+extern crate datetime;
+use datetime::{LocalDate, LocalDateTime, LocalTime, Month, SpreadsheetDateSystem, SpreadsheetError};
+use datetime::SpreadsheetDateSystem::*;
+use datetime::Month::*;
+
+
+fn midnight(year: i64, month: Month, day: i8) -> LocalDateTime {
+    LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::midnight())
+}
+
+fn from(serial: f64, system: SpreadsheetDateSystem) -> LocalDateTime {
+    LocalDateTime::from_spreadsheet_serial(serial, system).unwrap()
+}
+
+
+mod excel_1900 {
+    use super::*;
+
+    #[test]
+    fn first_day() {
+        assert_eq!(from(1.0, Excel1900), midnight(1900, January, 1));
+        assert_eq!(midnight(1900, January, 1).to_spreadsheet_serial(Excel1900), Some(1.0));
+    }
+
+    #[test]
+    fn day_zero() {
+        assert_eq!(from(0.0, Excel1900), midnight(1899, December, 31));
+    }
+
+    #[test]
+    fn before_fake_leap_day() {
+        assert_eq!(from(59.0, Excel1900), midnight(1900, February, 28));
+        assert_eq!(midnight(1900, February, 28).to_spreadsheet_serial(Excel1900), Some(59.0));
+    }
+
+    #[test]
+    fn fake_leap_day() {
+        assert_eq!(LocalDateTime::from_spreadsheet_serial(60.0, Excel1900), Err(SpreadsheetError::FakeLeapDay));
+        assert_eq!(LocalDateTime::from_spreadsheet_serial(60.5, Excel1900), Err(SpreadsheetError::FakeLeapDay));
+    }
+
+    #[test]
+    fn after_fake_leap_day() {
+        assert_eq!(from(61.0, Excel1900), midnight(1900, March, 1));
+        assert_eq!(midnight(1900, March, 1).to_spreadsheet_serial(Excel1900), Some(61.0));
+    }
+
+    #[test]
+    fn recent() {
+        assert_eq!(from(43831.0, Excel1900), midnight(2020, January, 1));
+        assert_eq!(midnight(2020, January, 1).to_spreadsheet_serial(Excel1900), Some(43831.0));
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(LocalDateTime::from_spreadsheet_serial(-1.0, Excel1900), Err(SpreadsheetError::OutOfRange));
+        assert_eq!(midnight(1899, December, 30).to_spreadsheet_serial(Excel1900), None);
+    }
+}
+
+
+mod excel_1904 {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(from(0.0, Excel1904), midnight(1904, January, 1));
+    }
+
+    #[test]
+    fn differs_from_1900_by_1462_days() {
+        let datetime = midnight(2020, January, 1);
+        assert_eq!(datetime.to_spreadsheet_serial(Excel1904), Some(43831.0 - 1462.0));
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(LocalDateTime::from_spreadsheet_serial(-0.5, Excel1904), Err(SpreadsheetError::OutOfRange));
+        assert_eq!(midnight(1903, December, 31).to_spreadsheet_serial(Excel1904), None);
+    }
+}
+
+
+mod libreoffice {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(from(0.0, LibreOffice), midnight(1899, December, 30));
+    }
+
+    #[test]
+    fn agrees_with_excel_from_march_1900() {
+        assert_eq!(from(61.0, LibreOffice), from(61.0, Excel1900));
+        assert_eq!(from(59.0, LibreOffice), midnight(1900, February, 27));
+    }
+
+    #[test]
+    fn negative() {
+        let datetime = LocalDateTime::new(LocalDate::ymd(1899, December, 29).unwrap(), LocalTime::hm(18, 0).unwrap());
+        assert_eq!(from(-0.25, LibreOffice), datetime);
+        assert_eq!(datetime.to_spreadsheet_serial(LibreOffice), Some(-0.25));
+    }
+}
+
+
+mod times {
+    use super::*;
+
+    #[test]
+    fn thirds_round_to_the_hour() {
+        let datetime = from(43831.0 + 1.0 / 3.0, Excel1900);
+        assert_eq!(datetime, LocalDateTime::new(LocalDate::ymd(2020, January, 1).unwrap(), LocalTime::hm(8, 0).unwrap()));
+    }
+
+    #[test]
+    fn milliseconds() {
+        let time = LocalTime::hms_ms(12, 0, 0, 500).unwrap();
+        let datetime = LocalDateTime::new(LocalDate::ymd(2020, January, 1).unwrap(), time);
+        let serial = datetime.to_spreadsheet_serial(Excel1900).unwrap();
+        assert_eq!(from(serial, Excel1900), datetime);
+    }
+
+    #[test]
+    fn almost_midnight_rounds_up() {
+        assert_eq!(from(43_831.999_999_999, Excel1900), midnight(2020, January, 2));
+    }
+
+    #[test]
+    fn not_a_number() {
+        assert_eq!(LocalDateTime::from_spreadsheet_serial(f64::NAN, LibreOffice), Err(SpreadsheetError::OutOfRange));
+        assert_eq!(LocalDateTime::from_spreadsheet_serial(f64::INFINITY, LibreOffice), Err(SpreadsheetError::OutOfRange));
+    }
+}
@@ -0,0 +1,108 @@
+extern crate datetime;
+use datetime::{Instant, Duration, ConversionError};
+
+use std::convert::TryFrom;
+use std::time::{SystemTime, UNIX_EPOCH, Duration as StdDuration};
+
+
+mod system_time {
+    use super::*;
+
+    #[test]
+    fn epoch() {
+        assert_eq!(Instant::from(UNIX_EPOCH), Instant::at_epoch())
+    }
+
+    #[test]
+    fn after_epoch() {
+        let time = UNIX_EPOCH + StdDuration::new(1_234_567_890, 123_456_789);
+        assert_eq!(Instant::from(time), Instant::at_ms(1_234_567_890, 123))
+    }
+
+    #[test]
+    fn before_epoch() {
+        let time = UNIX_EPOCH - StdDuration::from_millis(1_500);
+        assert_eq!(Instant::from(time), Instant::at_ms(-2, 500))
+    }
+
+    #[test]
+    fn before_epoch_rounds_down() {
+        let time = UNIX_EPOCH - StdDuration::new(1, 1);
+        assert_eq!(Instant::from(time), Instant::at_ms(-2, 999))
+    }
+
+    #[test]
+    fn before_epoch_rounds_up_to_a_whole_second() {
+        let time = UNIX_EPOCH - StdDuration::new(1, 999_999_999);
+        assert_eq!(Instant::from(time), Instant::at_ms(-2, 0))
+    }
+
+    #[test]
+    fn limits() {
+        // Not every platform can represent these system times, so only
+        // check the ones that exist. Those past the latest instant get
+        // clamped to it.
+        if let Some(time) = UNIX_EPOCH.checked_add(StdDuration::from_secs(i64::MAX as u64)) {
+            assert_eq!(Instant::from(time), Instant::at(i64::MAX));
+        }
+
+        if let Some(time) = UNIX_EPOCH.checked_sub(StdDuration::from_secs(i64::MAX as u64 + 1)) {
+            assert_eq!(Instant::from(time), Instant::at(i64::MIN));
+        }
+
+        if let Some(time) = UNIX_EPOCH.checked_add(StdDuration::new(i64::MAX as u64, 999_000_000)) {
+            assert_eq!(Instant::from(time), Instant::at_ms(i64::MAX, 999));
+        }
+
+        if let Some(time) = UNIX_EPOCH.checked_add(StdDuration::from_secs(i64::MAX as u64 + 1)) {
+            assert_eq!(Instant::from(time), Instant::at_ms(i64::MAX, 999));
+        }
+    }
+
+    #[test]
+    fn to_system_time() {
+        let instant = Instant::at_ms(1_234_567_890, 123);
+        assert_eq!(SystemTime::try_from(instant), Ok(UNIX_EPOCH + StdDuration::from_millis(1_234_567_890_123)))
+    }
+
+    #[test]
+    fn to_system_time_before_epoch() {
+        let instant = Instant::at_ms(-2, 500);
+        assert_eq!(SystemTime::try_from(instant), Ok(UNIX_EPOCH - StdDuration::from_millis(1_500)))
+    }
+
+    #[test]
+    fn round_trip() {
+        for &(s, ms) in &[ (-86_400, 1), (-1, 999), (0, 0), (1, 1), (1_600_000_000, 999) ] {
+            let instant = Instant::at_ms(s, ms);
+            assert_eq!(Instant::from(SystemTime::try_from(instant).unwrap()), instant);
+        }
+    }
+}
+
+
+mod durations {
+    use super::*;
+
+    #[test]
+    fn from_std() {
+        let duration = StdDuration::new(90, 250_999_999);
+        assert_eq!(Duration::try_from(duration), Ok(Duration::of_ms(90, 250)))
+    }
+
+    #[test]
+    fn from_std_overflow() {
+        let duration = StdDuration::from_secs(u64::MAX);
+        assert_eq!(Duration::try_from(duration), Err(ConversionError::Overflow))
+    }
+
+    #[test]
+    fn to_std() {
+        assert_eq!(StdDuration::try_from(Duration::of_ms(90, 250)), Ok(StdDuration::from_millis(90_250)))
+    }
+
+    #[test]
+    fn to_std_negative() {
+        assert_eq!(StdDuration::try_from(Duration::of_ms(-1, 999)), Err(ConversionError::Negative))
+    }
+}
@@ -0,0 +1,108 @@
+extern crate datetime;
+use datetime::{Instant, Duration, LeapSecond, LeapSecondTable, TimeScale};
+use datetime::scale::Error;
+
+
+mod offsets {
+    use super::*;
+
+    #[test]
+    fn before_table() {
+        assert_eq!(LeapSecondTable::builtin().tai_offset(Instant::at(0)), 10)
+    }
+
+    #[test]
+    fn gps_epoch() {
+        assert_eq!(LeapSecondTable::builtin().tai_offset(Instant::at(315_964_800)), 19)
+    }
+
+    #[test]
+    fn recently() {
+        assert_eq!(LeapSecondTable::builtin().tai_offset(Instant::at(1_700_000_000)), 37)
+    }
+}
+
+
+mod conversions {
+    use super::*;
+
+    #[test]
+    fn gps_epoch() {
+        let table = LeapSecondTable::builtin();
+        assert_eq!(table.to_scale(Instant::at(315_964_800), TimeScale::GPS), Duration::zero());
+        assert_eq!(table.from_scale(Duration::zero(), TimeScale::GPS), Instant::at(315_964_800));
+    }
+
+    #[test]
+    fn tai() {
+        let table = LeapSecondTable::builtin();
+        assert_eq!(table.to_scale(Instant::at_ms(1_700_000_000, 250), TimeScale::TAI), Duration::of_ms(1_700_000_037, 250));
+    }
+
+    #[test]
+    fn utc_is_unchanged() {
+        let table = LeapSecondTable::builtin();
+        assert_eq!(table.to_scale(Instant::at_ms(1_700_000_000, 250), TimeScale::UTC), Duration::of_ms(1_700_000_000, 250));
+        assert_eq!(table.from_scale(Duration::of_ms(1_700_000_000, 250), TimeScale::UTC), Instant::at_ms(1_700_000_000, 250));
+    }
+
+    #[test]
+    fn across_leap_second() {
+        // The leap second at the end of 2016 happened between these two
+        // instants, so one more second of GPS time elapsed.
+        let table = LeapSecondTable::builtin();
+        let before = table.to_scale(Instant::at(1_483_228_799), TimeScale::GPS);
+        let after  = table.to_scale(Instant::at(1_483_228_800), TimeScale::GPS);
+        assert_eq!(after - before, Duration::of(2));
+    }
+
+    #[test]
+    fn during_leap_second() {
+        // 2016-12-31T23:59:60.5 UTC repeats the previous second.
+        let table = LeapSecondTable::builtin();
+        let gps = table.to_scale(Instant::at(1_483_228_799), TimeScale::GPS) + Duration::of_ms(1, 500);
+        assert_eq!(table.from_scale(gps, TimeScale::GPS), Instant::at_ms(1_483_228_799, 500));
+    }
+
+    #[test]
+    fn round_trip() {
+        let table = LeapSecondTable::builtin();
+        for seconds in (0 .. 1_600_000_000).step_by(86_399) {
+            let instant = Instant::at_ms(seconds, 999);
+            for &scale in &[ TimeScale::UTC, TimeScale::TAI, TimeScale::GPS ] {
+                assert_eq!(table.from_scale(table.to_scale(instant, scale), scale), instant);
+            }
+        }
+    }
+}
+
+
+mod tables {
+    use super::*;
+
+    #[test]
+    fn custom() {
+        let table = LeapSecondTable::new(vec![
+            LeapSecond { at: 0,   tai_offset: 10 },
+            LeapSecond { at: 100, tai_offset: 11 },
+        ]).unwrap();
+
+        assert_eq!(table.tai_offset(Instant::at(150)), 11);
+        assert_eq!(table.from_scale(Duration::of(160), TimeScale::TAI), Instant::at(149));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(LeapSecondTable::new(Vec::new()), Err(Error::Empty))
+    }
+
+    #[test]
+    fn out_of_order() {
+        let table = LeapSecondTable::new(vec![
+            LeapSecond { at: 100, tai_offset: 11 },
+            LeapSecond { at: 0,   tai_offset: 10 },
+        ]);
+
+        assert_eq!(table, Err(Error::OutOfOrder));
+    }
+}
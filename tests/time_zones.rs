@@ -94,3 +94,197 @@ fn impossible() {
     assert!(converted.is_impossible(),
         "Local time {:?} should be impossible", converted);
 }
+
+
+#[test]
+fn offset_at_transition() {
+    // A timespan starts at its transition instant, so the second the
+    // clocks change is already in the new timespan.
+    let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+    assert_eq!(zone.offset(LocalDateTime::at(1206838800 - 1)), 0);
+    assert_eq!(zone.offset(LocalDateTime::at(1206838800)), 3600);
+    assert_eq!(zone.name(LocalDateTime::at(1206838800)), "ZONE_B");
+    assert_eq!(zone.offset(LocalDateTime::at(1224982800)), 0);
+}
+
+#[test]
+fn zoned_datetime_keeps_its_zone() {
+    let test_date = LocalDateTime::new(
+        LocalDate::ymd(2010, Month::June, 9).unwrap(),
+        LocalTime::hms(15, 15, 0).unwrap(),
+    );
+
+    // The converted datetime doesn’t borrow from the zone it came from, so
+    // it can outlive it and still hand it back.
+    let zoned = {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        zone.convert_local(test_date).unwrap_precise()
+    };
+
+    assert_eq!(zoned.time_zone().offset(test_date), 3600);
+}
+
+/// A zone whose only transition changes the name of the timespan, but not
+/// its offset.
+const RENAMED_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Renamed Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: 3600,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1206838800, FixedTimespan {
+                offset: 3600,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+        ]
+    }
+};
+
+mod local_times {
+    use super::*;
+    use datetime::zone::LocalTimes;
+
+    fn local(year: i64, month: Month, day: i8, hour: i8, minute: i8, second: i8) -> LocalDateTime {
+        LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hms(hour, minute, second).unwrap())
+    }
+
+    fn is_precise(times: LocalTimes) -> bool {
+        matches!(times, LocalTimes::Precise(_))
+    }
+
+    #[test]
+    fn gap_boundaries() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        assert!(is_precise(zone.convert_local(local(2008, Month::March, 30, 0, 59, 59))));
+        assert!(zone.convert_local(local(2008, Month::March, 30, 1, 0, 0)).is_impossible());
+        assert!(zone.convert_local(local(2008, Month::March, 30, 1, 59, 59)).is_impossible());
+        assert!(is_precise(zone.convert_local(local(2008, Month::March, 30, 2, 0, 0))));
+    }
+
+    #[test]
+    fn overlap_boundaries() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        assert!(is_precise(zone.convert_local(local(2008, Month::October, 26, 0, 59, 59))));
+        assert!(zone.convert_local(local(2008, Month::October, 26, 1, 0, 0)).is_ambiguous());
+        assert!(zone.convert_local(local(2008, Month::October, 26, 1, 59, 59)).is_ambiguous());
+        assert!(is_precise(zone.convert_local(local(2008, Month::October, 26, 2, 0, 0))));
+    }
+
+    #[test]
+    fn ambiguous_offsets() {
+        let zone = TimeZone(TimeZoneSource::Static(TEST_ZONESET));
+        match zone.convert_local(local(2008, Month::October, 26, 1, 30, 0)) {
+            LocalTimes::Ambiguous { earlier, later } => {
+                assert_eq!(earlier.to_instant().seconds(), 1224982800 - 1800);
+                assert_eq!(later.to_instant().seconds(), 1224982800 + 1800);
+            },
+            other => panic!("time should be ambiguous, not {:?}", other),
+        }
+    }
+
+    #[test]
+    fn transition_without_offset_change() {
+        let zone = TimeZone(TimeZoneSource::Static(RENAMED_ZONESET));
+        for &hour in &[ 0, 1, 2, 3 ] {
+            let zoned = zone.convert_local(local(2008, Month::March, 30, hour, 30, 0)).unwrap_precise();
+            assert_eq!(zoned.to_instant().seconds(), 1206838800 - 3600 * 2 + 1800 + 3600 * hour as i64);
+        }
+    }
+}
+
+
+/// A zone that moves its clocks forward at midnight, skipping straight from
+/// 23:59:59 to 01:00, and back again at midnight, repeating 23:00 to 23:59.
+const MIDNIGHT_ZONESET: &StaticTimeZone<'static> = &StaticTimeZone {
+    name: "Midnight Zoneset",
+    fixed_timespans: FixedTimespanSet {
+        first: FixedTimespan {
+            offset: -3 * 3600,
+            is_dst: false,
+            name: Cow::Borrowed("ZONE_A"),
+        },
+        rest: &[
+            (1541300400, FixedTimespan {
+                offset: -2 * 3600,
+                is_dst: true,
+                name: Cow::Borrowed("ZONE_B"),
+            }),
+            (1550368800, FixedTimespan {
+                offset: -3 * 3600,
+                is_dst: false,
+                name: Cow::Borrowed("ZONE_A"),
+            }),
+        ]
+    }
+};
+
+mod boundaries {
+    use super::*;
+    use datetime::Unit;
+
+    fn local(year: i64, month: Month, day: i8, hour: i8, minute: i8) -> LocalDateTime {
+        LocalDateTime::new(LocalDate::ymd(year, month, day).unwrap(), LocalTime::hm(hour, minute).unwrap())
+    }
+
+    #[test]
+    fn day_starting_after_gap() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_ZONESET));
+        let noon = zone.convert_local(local(2018, Month::November, 4, 12, 0)).unwrap_precise();
+
+        let start = noon.start_of(Unit::Day);
+        assert_eq!((start.day(), start.hour(), start.minute()), (4, 1, 0));
+        assert_eq!(start.to_instant().seconds(), 1541300400);
+    }
+
+    #[test]
+    fn day_ending_before_gap() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_ZONESET));
+        let noon = zone.convert_local(local(2018, Month::November, 3, 12, 0)).unwrap_precise();
+
+        let end = noon.end_of(Unit::Day);
+        assert_eq!((end.day(), end.hour(), end.minute(), end.millisecond()), (3, 23, 59, 999));
+        assert_eq!(end.to_instant().seconds(), 1541300399);
+    }
+
+    #[test]
+    fn day_with_overlap() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_ZONESET));
+        let noon = zone.convert_local(local(2019, Month::February, 16, 12, 0)).unwrap_precise();
+
+        let start = noon.start_of(Unit::Day);
+        assert_eq!((start.day(), start.hour()), (16, 0));
+
+        // The day is 25 hours long, so it ends at the *second* 23:59.
+        let end = noon.end_of(Unit::Day);
+        assert_eq!((end.day(), end.hour(), end.minute()), (16, 23, 59));
+        assert_eq!(end.to_instant().seconds(), 1550368800 + 3600 - 1);
+    }
+
+    #[test]
+    fn hour_in_overlap() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_ZONESET));
+        let later = match zone.convert_local(local(2019, Month::February, 16, 23, 30)) {
+            datetime::zone::LocalTimes::Ambiguous { later, .. } => later,
+            _ => panic!("time should be ambiguous"),
+        };
+
+        assert_eq!(later.start_of(Unit::Hour).to_instant().seconds(), 1550368800);
+        assert_eq!(later.end_of(Unit::Hour).to_instant().seconds(), 1550368800 + 3600 - 1);
+    }
+
+    #[test]
+    fn months() {
+        let zone = TimeZone(TimeZoneSource::Static(MIDNIGHT_ZONESET));
+        let noon = zone.convert_local(local(2018, Month::November, 20, 12, 0)).unwrap_precise();
+
+        let start = noon.start_of(Unit::Month);
+        assert_eq!((start.month(), start.day(), start.hour()), (Month::November, 1, 0));
+
+        let end = noon.end_of(Unit::Month);
+        assert_eq!((end.month(), end.day(), end.hour()), (Month::November, 30, 23));
+    }
+}
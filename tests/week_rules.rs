@@ -0,0 +1,86 @@
+extern crate datetime;
+use datetime::{LocalDate, Month, Weekday, WeekRule, DatePiece};
+
+
+#[test]
+fn invalid_minimum() {
+    assert!(WeekRule::new(Weekday::Monday, 0).is_err());
+    assert!(WeekRule::new(Weekday::Monday, 8).is_err());
+}
+
+#[test]
+fn iso_matches_iso_weeks() {
+    let start = LocalDate::ymd(1999, Month::January, 1).unwrap();
+    for days in 0 .. 365 * 30 {
+        let date = start.add_days(days);
+        assert_eq!(date.week_of_year(WeekRule::iso()), date.iso_week(), "{:?}", date);
+        assert_eq!(date.week_year(WeekRule::iso()), date.iso_week_year(), "{:?}", date);
+    }
+}
+
+#[test]
+fn iso_matches_ywd() {
+    for week in 1 .. 54 {
+        assert_eq!(WeekRule::iso().date(2015, week, Weekday::Sunday), LocalDate::ywd(2015, week as i64, Weekday::Sunday));
+    }
+}
+
+#[test]
+fn sunday_start_new_year() {
+    // The 31st of December 2016 was a Saturday, so it was in the last week
+    // of 2016, and the 1st of January 2017 started week 1.
+    let rule = WeekRule::sunday_start();
+    let eve = LocalDate::ymd(2016, Month::December, 31).unwrap();
+    let day = LocalDate::ymd(2017, Month::January, 1).unwrap();
+    assert_eq!((eve.week_year(rule), eve.week_of_year(rule)), (2016, 53));
+    assert_eq!((day.week_year(rule), day.week_of_year(rule)), (2017, 1));
+}
+
+#[test]
+fn sunday_start_spills_into_next_year() {
+    // The 1st of January 2018 was a Monday, so the 31st of December 2017
+    // was in the same week, which is week 1 of 2018.
+    let rule = WeekRule::sunday_start();
+    let eve = LocalDate::ymd(2017, Month::December, 31).unwrap();
+    assert_eq!((eve.week_year(rule), eve.week_of_year(rule)), (2018, 1));
+}
+
+#[test]
+fn saturday_start() {
+    let rule = WeekRule::saturday_start();
+    let date = LocalDate::ymd(2017, Month::January, 7).unwrap();
+    assert_eq!(date.week_of_year(rule), 2);
+    assert_eq!(date.start_of_week(rule), date);
+}
+
+#[test]
+fn start_of_week_is_first_day() {
+    let start = LocalDate::ymd(2017, Month::January, 1).unwrap();
+    for &rule in &[ WeekRule::iso(), WeekRule::sunday_start(), WeekRule::saturday_start() ] {
+        for days in 0 .. 28 {
+            let date = start.add_days(days);
+            let week_start = date.start_of_week(rule);
+            assert_eq!(week_start.weekday(), rule.first_day());
+            assert!(week_start.days_until(date) >= 0 && week_start.days_until(date) < 7);
+        }
+    }
+}
+
+#[test]
+fn weeks_of_month() {
+    // March 2017 started on a Wednesday.
+    let rule = WeekRule::sunday_start();
+    let weeks: Vec<_> = (1 .. 32).map(|d| LocalDate::ymd(2017, Month::March, d).unwrap().week_of_month(rule)).collect();
+    assert_eq!(&weeks[0 .. 5], &[ 1, 1, 1, 1, 2 ]);
+    assert_eq!(weeks[30], 5);
+}
+
+#[test]
+fn round_trip() {
+    let rule = WeekRule::new(Weekday::Saturday, 4).unwrap();
+    let start = LocalDate::ymd(2010, Month::January, 1).unwrap();
+    for days in 0 .. 365 * 8 {
+        let date = start.add_days(days);
+        assert_eq!(rule.date(date.week_year(rule), date.week_of_year(rule), date.weekday()), Ok(date));
+    }
+}